@@ -1148,20 +1148,25 @@ fn codegen_regular_intrinsic_call<'tcx>(
         sym::fadd_fast | sym::fsub_fast | sym::fmul_fast | sym::fdiv_fast | sym::frem_fast => {
             intrinsic_args!(fx, args => (x, y); intrinsic);
 
-            let res = crate::num::codegen_float_binop(
-                fx,
-                match intrinsic {
-                    sym::fadd_fast => BinOp::Add,
-                    sym::fsub_fast => BinOp::Sub,
-                    sym::fmul_fast => BinOp::Mul,
-                    sym::fdiv_fast => BinOp::Div,
-                    sym::frem_fast => BinOp::Rem,
-                    _ => unreachable!(),
-                },
-                x,
-                y,
-            );
-            ret.write_cvalue(fx, res);
+            let op = match intrinsic {
+                sym::fadd_fast => BinOp::Add,
+                sym::fsub_fast => BinOp::Sub,
+                sym::fmul_fast => BinOp::Mul,
+                sym::fdiv_fast => BinOp::Div,
+                sym::frem_fast => BinOp::Rem,
+                _ => unreachable!(),
+            };
+            if x.layout().ty.is_simd() {
+                simd_pair_for_each_lane(fx, x, y, ret, &|fx, lane_ty, _ret_lane_ty, x_lane, y_lane| {
+                    let lane_layout = fx.layout_of(lane_ty);
+                    let x_lane = CValue::by_val(x_lane, lane_layout);
+                    let y_lane = CValue::by_val(y_lane, lane_layout);
+                    crate::num::codegen_float_binop(fx, op, x_lane, y_lane).load_scalar(fx)
+                });
+            } else {
+                let res = crate::num::codegen_float_binop(fx, op, x, y);
+                ret.write_cvalue(fx, res);
+            }
         }
         sym::float_to_int_unchecked => {
             intrinsic_args!(fx, args => (f); intrinsic);
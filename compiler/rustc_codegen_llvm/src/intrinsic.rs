@@ -1570,6 +1570,124 @@ fn non_ptr(t: Ty<'_>) -> Ty<'_> {
         return Ok(v);
     }
 
+    if name == sym::simd_masked_load || name == sym::simd_masked_store {
+        // simd_masked_load(mask: <N x i{M}>, pointer: *_ T, values: <N x T>) -> <N x T>
+        // simd_masked_store(mask: <N x i{M}>, pointer: *mut T, values: <N x T>) -> ()
+        // * N: number of elements in the input vectors
+        // * T: type of the element to load or store
+        // * M: any integer width is supported, will be truncated to i1
+        // Only the elements at `pointer` of the lanes enabled in `mask` are accessed; loads
+        // return the lanes of `values` for the lanes that are disabled.
+        let is_load = name == sym::simd_masked_load;
+
+        // The first argument was checked to be a vector above, and the last must be one too
+        let values_ty = arg_tys[2];
+        require_simd!(values_ty, InvalidMonomorphization::SimdThird { span, name, ty: values_ty });
+        if is_load {
+            require_simd!(ret_ty, InvalidMonomorphization::SimdReturn { span, name, ty: ret_ty });
+        }
+
+        // Of the same length:
+        let (values_len, values_elem) = values_ty.simd_size_and_type(bx.tcx());
+        require!(
+            in_len == values_len,
+            InvalidMonomorphization::ThirdArgumentLength {
+                span,
+                name,
+                in_len,
+                in_ty,
+                arg_ty: values_ty,
+                out_len: values_len
+            }
+        );
+
+        // A load returns the type of its last argument
+        if is_load {
+            require!(
+                ret_ty == values_ty,
+                InvalidMonomorphization::ExpectedReturnType {
+                    span,
+                    name,
+                    in_ty: values_ty,
+                    ret_ty
+                }
+            );
+        }
+
+        // The second argument must be a pointer to the element type of the last argument,
+        // which is mutable for stores
+        let pointer_ty = arg_tys[1];
+        require!(
+            matches!(
+                pointer_ty.kind(),
+                ty::RawPtr(p) if p.ty == values_elem && (is_load || p.mutbl.is_mut())
+            ),
+            InvalidMonomorphization::ExpectedElementType {
+                span,
+                name,
+                expected_element: pointer_ty,
+                second_arg: pointer_ty,
+                in_elem: values_elem,
+                in_ty: values_ty,
+                mutability: if is_load {
+                    ExpectedPointerMutability::Not
+                } else {
+                    ExpectedPointerMutability::Mut
+                },
+            }
+        );
+
+        // The element type of the mask must be a signed integer type of any width:
+        require!(
+            matches!(in_elem.kind(), ty::Int(_)),
+            InvalidMonomorphization::MaskType { span, name, ty: in_elem }
+        );
+
+        // Alignment of T, must be a constant integer value:
+        let alignment_ty = bx.type_i32();
+        let alignment = bx.const_i32(bx.align_of(values_elem).bytes() as i32);
+
+        // Truncate the mask vector to a vector of i1s:
+        let (mask, mask_ty) = {
+            let i1 = bx.type_i1();
+            let i1xn = bx.type_vector(i1, in_len);
+            (bx.trunc(args[0].immediate(), i1xn), i1xn)
+        };
+
+        // Type of the vector of elements, and of the pointer to it. Vectors of pointers are
+        // named as they are with opaque pointers, since their pointee type can be anything.
+        let llvm_elem_vec_ty = args[2].layout.immediate_llvm_type(bx);
+        let llvm_elem_vec_str = match values_elem.kind() {
+            ty::Int(_) | ty::Uint(_) | ty::Float(_) => {
+                llvm_vector_str(values_elem, values_len, 0, bx)
+            }
+            _ => format!("v{}p0", values_len),
+        };
+        let llvm_pointer_ty = bx.type_ptr_to(llvm_elem_vec_ty);
+        let pointer = bx.pointercast(args[1].immediate(), llvm_pointer_ty);
+
+        let v = if is_load {
+            let llvm_intrinsic =
+                format!("llvm.masked.load.{}.p0{}", llvm_elem_vec_str, llvm_elem_vec_str);
+            let fn_ty = bx.type_func(
+                &[llvm_pointer_ty, alignment_ty, mask_ty, llvm_elem_vec_ty],
+                llvm_elem_vec_ty,
+            );
+            let f = bx.declare_cfn(&llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
+            bx.call(fn_ty, None, f, &[pointer, alignment, mask, args[2].immediate()], None)
+        } else {
+            let llvm_intrinsic =
+                format!("llvm.masked.store.{}.p0{}", llvm_elem_vec_str, llvm_elem_vec_str);
+            let fn_ty = bx.type_func(
+                &[llvm_elem_vec_ty, llvm_pointer_ty, alignment_ty, mask_ty],
+                bx.type_void(),
+            );
+            let f = bx.declare_cfn(&llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
+            bx.call(fn_ty, None, f, &[args[2].immediate(), pointer, alignment, mask], None)
+        };
+        return Ok(v);
+    }
+
     macro_rules! arith_red {
         ($name:ident : $integer_reduce:ident, $float_reduce:ident, $ordered:expr, $op:ident,
          $identity:expr) => {
@@ -1630,14 +1748,14 @@ macro_rules! arith_red {
     arith_red!(simd_reduce_mul_ordered: vector_reduce_mul, vector_reduce_fmul, true, mul, 1.0);
     arith_red!(
         simd_reduce_add_unordered: vector_reduce_add,
-        vector_reduce_fadd_fast,
+        vector_reduce_fadd_reassoc,
         false,
         add,
-        0.0
+        -0.0
     );
     arith_red!(
         simd_reduce_mul_unordered: vector_reduce_mul,
-        vector_reduce_fmul_fast,
+        vector_reduce_fmul_reassoc,
         false,
         mul,
         1.0
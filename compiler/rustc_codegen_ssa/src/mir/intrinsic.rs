@@ -313,7 +313,13 @@ pub fn codegen_intrinsic_call(
                 }
             }
             sym::fadd_fast | sym::fsub_fast | sym::fmul_fast | sym::fdiv_fast | sym::frem_fast => {
-                match float_type_width(arg_tys[0]) {
+                // SIMD vectors of floats are operated on lane by lane.
+                let elem_ty = if arg_tys[0].is_simd() {
+                    arg_tys[0].simd_size_and_type(bx.tcx()).1
+                } else {
+                    arg_tys[0]
+                };
+                match float_type_width(elem_ty) {
                     Some(_width) => match name {
                         sym::fadd_fast => bx.fadd_fast(args[0].immediate(), args[1].immediate()),
                         sym::fsub_fast => bx.fsub_fast(args[0].immediate(), args[1].immediate()),
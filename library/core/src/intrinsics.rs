@@ -1613,30 +1613,40 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     /// Float addition that allows optimizations based on algebraic rules.
     /// May assume inputs are finite.
     ///
+    /// `T` may also be a SIMD vector of floats, which is operated on lane by lane.
+    ///
     /// This intrinsic does not have a stable counterpart.
     pub fn fadd_fast<T: Copy>(a: T, b: T) -> T;
 
     /// Float subtraction that allows optimizations based on algebraic rules.
     /// May assume inputs are finite.
     ///
+    /// `T` may also be a SIMD vector of floats, which is operated on lane by lane.
+    ///
     /// This intrinsic does not have a stable counterpart.
     pub fn fsub_fast<T: Copy>(a: T, b: T) -> T;
 
     /// Float multiplication that allows optimizations based on algebraic rules.
     /// May assume inputs are finite.
     ///
+    /// `T` may also be a SIMD vector of floats, which is operated on lane by lane.
+    ///
     /// This intrinsic does not have a stable counterpart.
     pub fn fmul_fast<T: Copy>(a: T, b: T) -> T;
 
     /// Float division that allows optimizations based on algebraic rules.
     /// May assume inputs are finite.
     ///
+    /// `T` may also be a SIMD vector of floats, which is operated on lane by lane.
+    ///
     /// This intrinsic does not have a stable counterpart.
     pub fn fdiv_fast<T: Copy>(a: T, b: T) -> T;
 
     /// Float remainder that allows optimizations based on algebraic rules.
     /// May assume inputs are finite.
     ///
+    /// `T` may also be a SIMD vector of floats, which is operated on lane by lane.
+    ///
     /// This intrinsic does not have a stable counterpart.
     pub fn frem_fast<T: Copy>(a: T, b: T) -> T;
 
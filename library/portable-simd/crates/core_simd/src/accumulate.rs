@@ -0,0 +1,45 @@
+use crate::simd::{LaneCount, Simd, SimdInt, SimdOrd, SimdUint, SupportedLaneCount};
+
+macro_rules! impl_accumulate {
+    { $($trait:ident: $wide:ty => $narrow:ty, $example:literal;)* } => {
+        $(
+        impl<const N: usize> Simd<$wide, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            #[doc = concat!("Adds each lane to the same lane of an accumulator of `", stringify!($narrow), "`, saturating the sum to the range of `", stringify!($narrow), "`.")]
+            ///
+            /// The sum is computed at the width of `self` and saturated once, so the result is the
+            /// exact sum clamped to the range of the accumulator, as if the accumulator were
+            /// widened, added to, saturated and narrowed again.  Fixed-point filters use this to
+            /// keep narrow accumulators, which fit twice as many lanes in a register, while adding
+            /// products that need the wider type.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            #[doc = $example]
+            /// ```
+            #[inline]
+            pub fn accumulate_saturating(self, target: &mut Simd<$narrow, N>) {
+                let min = Simd::splat(<$narrow>::MIN as $wide);
+                let max = Simd::splat(<$narrow>::MAX as $wide);
+                let sum = $trait::saturating_add(target.cast::<$wide>(), self);
+                *target = sum.simd_clamp(min, max).cast();
+            }
+        }
+        )*
+    }
+}
+
+impl_accumulate! {
+    SimdInt: i16 => i8, "let mut acc = Simd::from_array([100i8, -100, 0, 1]);\nSimd::from_array([100i16, -100, -300, 2]).accumulate_saturating(&mut acc);\nassert_eq!(acc.to_array(), [127, -128, -128, 3]);";
+    SimdInt: i32 => i16, "let mut acc = Simd::from_array([30000i16, -30000, 0, 1]);\nSimd::from_array([10000i32, -10000, 1 << 20, 2]).accumulate_saturating(&mut acc);\nassert_eq!(acc.to_array(), [i16::MAX, i16::MIN, i16::MAX, 3]);";
+    SimdInt: i64 => i32, "let mut acc = Simd::from_array([i32::MAX - 1, i32::MIN, 0, 1]);\nSimd::from_array([2i64, -1, i64::MIN, 2]).accumulate_saturating(&mut acc);\nassert_eq!(acc.to_array(), [i32::MAX, i32::MIN, i32::MIN, 3]);";
+    SimdUint: u16 => u8, "let mut acc = Simd::from_array([200u8, 0, 0, 1]);\nSimd::from_array([100u16, 300, 255, 2]).accumulate_saturating(&mut acc);\nassert_eq!(acc.to_array(), [255, 255, 255, 3]);";
+    SimdUint: u32 => u16, "let mut acc = Simd::from_array([60000u16, 0, 0, 1]);\nSimd::from_array([10000u32, 1 << 20, 65535, 2]).accumulate_saturating(&mut acc);\nassert_eq!(acc.to_array(), [u16::MAX, u16::MAX, u16::MAX, 3]);";
+    SimdUint: u64 => u32, "let mut acc = Simd::from_array([u32::MAX - 1, 0, 0, 1]);\nSimd::from_array([2u64, u64::MAX, 7, 2]).accumulate_saturating(&mut acc);\nassert_eq!(acc.to_array(), [u32::MAX, u32::MAX, 7, 3]);";
+}
@@ -0,0 +1,115 @@
+//! Finite automata stepped in every lane at once.
+//!
+//! A deterministic finite automaton with at most 16 states has, for each input byte, a
+//! transition table that fits in a vector of 16 bytes, with the next state of state `s` in lane
+//! `s`.  Stepping a vector of states through the table is a single [`Simd::swizzle_dyn`], so 16
+//! states advance as cheaply as one.
+//!
+//! Stepping a vector holding every state, from `0` to `15`, computes where the automaton ends for
+//! every possible starting state.  Those mappings can be computed for separate chunks of the input
+//! at the same time, and then composed, which breaks the dependency of each step on the last that
+//! otherwise makes automata, such as those behind regular expressions and tokenizers, serial.
+
+use crate::simd::{u8x16, Simd};
+
+/// A deterministic finite automaton with at most 16 states, stepped in each lane of a vector.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{automata::Dfa, u8x16};
+/// // Whether a quote has been opened, and if so whether the last byte was a backslash
+/// let (outside, inside, escaped) = (0, 1, 2);
+/// let dfa = Dfa::new(|state, byte| match (state, byte) {
+///     (0, b'"') => inside,
+///     (1, b'"') => outside,
+///     (1, b'\\') => escaped,
+///     (1, _) | (2, _) => inside,
+///     _ => outside,
+/// });
+///
+/// // Each half of the input is run from every state independently
+/// let (first, second) = br#"say "a \" b" and "#.split_at(8);
+/// let first = dfa.run(Dfa::all_states(), first);
+/// let second = dfa.run(Dfa::all_states(), second);
+/// // Then the halves are composed, and looked up from the real starting state
+/// let both = Dfa::compose(first, second);
+/// assert_eq!(both[outside as usize], outside);
+/// assert_eq!(first[outside as usize], escaped);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Dfa {
+    transitions: [u8x16; 256],
+}
+
+impl Dfa {
+    /// The largest number of states an automaton can have.
+    pub const STATES: usize = 16;
+
+    /// Creates an automaton whose next state, after reading `byte` in `state`, is
+    /// `transition(state, byte)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `transition` returns a state of 16 or more.
+    #[inline]
+    #[must_use]
+    pub fn new(mut transition: impl FnMut(u8, u8) -> u8) -> Self {
+        let mut transitions = [u8x16::splat(0); 256];
+        for (byte, table) in transitions.iter_mut().enumerate() {
+            for state in 0..Self::STATES {
+                let next = transition(state as u8, byte as u8);
+                assert!(
+                    (next as usize) < Self::STATES,
+                    "automata have at most 16 states"
+                );
+                table[state] = next;
+            }
+        }
+        Self { transitions }
+    }
+
+    /// Returns a vector holding every state, with state `s` in lane `s`.
+    ///
+    /// Running the automaton from these states maps each starting state to its final state.
+    #[inline]
+    #[must_use]
+    pub fn all_states() -> u8x16 {
+        let mut states = [0; Self::STATES];
+        for (i, state) in states.iter_mut().enumerate() {
+            *state = i as u8;
+        }
+        Simd::from_array(states)
+    }
+
+    /// Returns the state after reading `byte` in each of `states`.
+    ///
+    /// Lanes holding states of 16 or more, which the automaton can't reach, move to state 0.
+    #[inline]
+    #[must_use]
+    pub fn step(&self, states: u8x16, byte: u8) -> u8x16 {
+        self.transitions[byte as usize].swizzle_dyn(states)
+    }
+
+    /// Returns the state after reading all of `input` from each of `states`.
+    #[inline]
+    #[must_use]
+    pub fn run(&self, states: u8x16, input: &[u8]) -> u8x16 {
+        input
+            .iter()
+            .fold(states, |states, byte| self.step(states, *byte))
+    }
+
+    /// Composes the mappings from starting to final states of two consecutive runs.
+    ///
+    /// If `first` and `second` are the results of running the automaton from
+    /// [`Dfa::all_states`] over two consecutive pieces of input, the result is the same as
+    /// running it over both.
+    #[inline]
+    #[must_use]
+    pub fn compose(first: u8x16, second: u8x16) -> u8x16 {
+        second.swizzle_dyn(first)
+    }
+}
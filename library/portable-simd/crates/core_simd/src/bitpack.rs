@@ -0,0 +1,191 @@
+//! Packing integers into a fixed number of bits each, as in Parquet and ORC.
+//!
+//! Integers of `width` bits are stored one after another, least significant bit first, with no
+//! padding between them, so eight of them fill exactly `width` bytes.  Each group of eight is
+//! converted at once: every integer is shifted by its own distance to its position in a vector of
+//! 64-bit words, and the words are combined with reductions.
+
+use crate::simd::{u32x8, u64x8, Simd, SimdPartialEq, SimdUint};
+
+/// The number of integers in each group.
+const GROUP: usize = 8;
+
+/// The largest number of 64-bit words a group is packed into.
+const WORDS: usize = 4;
+
+/// The position of each integer of a group in its packed words.
+struct Layout {
+    /// The word each integer starts in.
+    word: u64x8,
+    /// The bit of the word each integer starts at.
+    shift: u64x8,
+    /// The bits of an integer.
+    mask: u64x8,
+}
+
+impl Layout {
+    #[inline]
+    fn new(width: u32) -> Self {
+        assert!(
+            (1..=32).contains(&width),
+            "width must be between 1 and 32 bits"
+        );
+        let mut position = [0; GROUP];
+        for (i, position) in position.iter_mut().enumerate() {
+            *position = i as u64 * width as u64;
+        }
+        let position = Simd::from_array(position);
+        Self {
+            word: position >> u64x8::splat(6),
+            shift: position & u64x8::splat(63),
+            mask: u64x8::splat(u64::MAX >> (64 - width)),
+        }
+    }
+
+    /// Packs a group of integers into words.
+    #[inline]
+    fn pack(&self, values: u32x8) -> [u64; WORDS] {
+        let values = values.cast::<u64>() & self.mask;
+        let low = values << self.shift;
+        // The bits that spill into the next word, or none if the integer starts a word
+        let high = (values >> u64x8::splat(1)) >> (u64x8::splat(63) - self.shift);
+
+        let zero = u64x8::splat(0);
+        let mut words = [0; WORDS];
+        for (i, word) in words.iter_mut().enumerate() {
+            let i = u64x8::splat(i as u64);
+            *word = self.word.simd_eq(i).select(low, zero).reduce_or()
+                | (self.word + u64x8::splat(1))
+                    .simd_eq(i)
+                    .select(high, zero)
+                    .reduce_or();
+        }
+        words
+    }
+
+    /// Unpacks a group of integers from words.
+    #[inline]
+    fn unpack(&self, words: [u64; WORDS]) -> u32x8 {
+        let idxs = self.word.cast::<usize>();
+        let low = Simd::gather_or_default(&words, idxs);
+        let high = Simd::gather_or_default(&words, idxs + Simd::splat(1));
+        let values =
+            (low >> self.shift) | ((high << u64x8::splat(1)) << (u64x8::splat(63) - self.shift));
+        (values & self.mask).cast()
+    }
+}
+
+/// Returns the number of bytes that `len` integers of `width` bits are packed into.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::bitpack;
+/// assert_eq!(bitpack::packed_len(8, 3), 3);
+/// assert_eq!(bitpack::packed_len(10, 3), 4);
+/// ```
+#[must_use]
+#[inline]
+pub const fn packed_len(len: usize, width: u32) -> usize {
+    (len * width as usize + 7) / 8
+}
+
+/// Packs the low `width` bits of each of `values` into `output`.
+///
+/// Higher bits of the values are ignored, and unused bits of the last byte are zeroed.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 32, or if `output` isn't
+/// [`packed_len(values.len(), width)`](packed_len) bytes long.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::bitpack;
+/// let values = [1, 2, 3, 4, 5, 6, 7, 0, 5];
+/// let mut packed = [0; 4];
+/// bitpack::pack(&values, 3, &mut packed);
+/// assert_eq!(packed, [0b11_010_001, 0b0_101_100_0, 0b000_111_11, 0b101]);
+///
+/// let mut unpacked = [0; 9];
+/// bitpack::unpack(&packed, 3, &mut unpacked);
+/// assert_eq!(unpacked, values);
+/// ```
+#[inline]
+pub fn pack(values: &[u32], width: u32, output: &mut [u8]) {
+    let layout = Layout::new(width);
+    assert_eq!(
+        output.len(),
+        packed_len(values.len(), width),
+        "output must be exactly as long as the packed values"
+    );
+
+    let group_bytes = width as usize;
+    let mut groups = values.chunks_exact(GROUP);
+    for (group, output) in (&mut groups).zip(output.chunks_exact_mut(group_bytes)) {
+        let words = layout.pack(u32x8::from_slice(group));
+        write_words(words, output);
+    }
+
+    let group = groups.remainder();
+    if !group.is_empty() {
+        let words = layout.pack(u32x8::load_or_default(group));
+        let start = values.len() / GROUP * group_bytes;
+        write_words(words, &mut output[start..]);
+    }
+}
+
+/// Unpacks integers of `width` bits from `input` into `output`.
+///
+/// Any bits of `input` after the last integer are ignored.
+///
+/// # Panics
+///
+/// Panics if `width` isn't between 1 and 32, or if `input` is shorter than
+/// [`packed_len(output.len(), width)`](packed_len) bytes.
+#[inline]
+pub fn unpack(input: &[u8], width: u32, output: &mut [u32]) {
+    let layout = Layout::new(width);
+    let len = packed_len(output.len(), width);
+    assert!(input.len() >= len, "input is too short for the output");
+
+    let group_bytes = width as usize;
+    let start = output.len() / GROUP * group_bytes;
+    let mut groups = output.chunks_exact_mut(GROUP);
+    for (group, input) in (&mut groups).zip(input.chunks_exact(group_bytes)) {
+        group.copy_from_slice(layout.unpack(read_words(input)).as_array());
+    }
+
+    let group = groups.into_remainder();
+    if !group.is_empty() {
+        let values = layout.unpack(read_words(&input[start..len]));
+        group.copy_from_slice(&values[..group.len()]);
+    }
+}
+
+/// Writes the first `output.len()` bytes of little-endian `words` to `output`.
+#[inline]
+fn write_words(words: [u64; WORDS], output: &mut [u8]) {
+    let mut bytes = [0; WORDS * 8];
+    for (bytes, word) in bytes.chunks_exact_mut(8).zip(words) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    output.copy_from_slice(&bytes[..output.len()]);
+}
+
+/// Reads little-endian words from `input`, padded with zeros.
+#[inline]
+fn read_words(input: &[u8]) -> [u64; WORDS] {
+    let mut bytes = [0; WORDS * 8];
+    bytes[..input.len()].copy_from_slice(input);
+    let mut words = [0; WORDS];
+    for (word, bytes) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+        *word = u64::from_le_bytes(bytes.try_into().unwrap());
+    }
+    words
+}
@@ -0,0 +1,255 @@
+//! Fixed-size sets of small integers, stored as bits in a vector.
+//!
+//! Set operations such as union and intersection are bitwise operations on the whole vector,
+//! and counting the members is a population count of every word at once.  This is the layout of
+//! the bitmap containers of compressed bitmaps such as Roaring, which switch to a bitmap once a
+//! range of integers is dense enough.
+
+use crate::simd::{LaneCount, Simd, SimdUint, SupportedLaneCount};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+
+/// A set of the integers below `64 * N`, stored as a bit per integer in `N` words of 64 bits.
+///
+/// Integer `i` is bit `i % 64` of word `i / 64`.  The bitwise operators compute the
+/// intersection, union and symmetric difference of sets.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::bitset::SimdBitSet;
+/// let evens: SimdBitSet<4> = (0..256).step_by(2).collect();
+/// let squares: SimdBitSet<4> = (0..16).map(|x| x * x).collect();
+/// assert_eq!(evens.len(), 128);
+/// assert_eq!(evens.intersection_len(&squares), 8);
+///
+/// let even_squares = evens & squares;
+/// assert!(even_squares.contains(196));
+/// assert_eq!(even_squares.iter().take(4).collect::<Vec<_>>(), [0, 4, 16, 36]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SimdBitSet<const N: usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// The bits of the set, 64 integers per word.
+    words: Simd<u64, N>,
+}
+
+impl<const N: usize> SimdBitSet<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// The number of integers the set can hold.
+    pub const CAPACITY: usize = 64 * N;
+
+    /// Creates an empty set.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_words(Simd::splat(0))
+    }
+
+    /// Creates the set with the bits of `words`.
+    #[inline]
+    #[must_use]
+    pub const fn from_words(words: Simd<u64, N>) -> Self {
+        Self { words }
+    }
+
+    /// Returns the bits of the set.
+    #[inline]
+    #[must_use]
+    pub const fn words(&self) -> Simd<u64, N> {
+        self.words
+    }
+
+    /// Returns the word and bit of `value`.
+    #[inline]
+    #[track_caller]
+    fn position(value: usize) -> (usize, u64) {
+        assert!(
+            value < Self::CAPACITY,
+            "value must be less than the capacity of the set"
+        );
+        (value / 64, 1 << (value % 64))
+    }
+
+    /// Returns whether `value` is in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` isn't less than [`CAPACITY`](Self::CAPACITY).
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn contains(&self, value: usize) -> bool {
+        let (word, bit) = Self::position(value);
+        self.words[word] & bit != 0
+    }
+
+    /// Adds `value` to the set, and returns whether it wasn't already in it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` isn't less than [`CAPACITY`](Self::CAPACITY).
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (word, bit) = Self::position(value);
+        let absent = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        absent
+    }
+
+    /// Removes `value` from the set, and returns whether it was in it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` isn't less than [`CAPACITY`](Self::CAPACITY).
+    #[inline]
+    #[track_caller]
+    pub fn remove(&mut self, value: usize) -> bool {
+        let (word, bit) = Self::position(value);
+        let present = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        present
+    }
+
+    /// Returns the number of integers in the set.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.words.count_ones().reduce_sum() as usize
+    }
+
+    /// Returns whether the set is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words == Simd::splat(0)
+    }
+
+    /// Returns the number of integers in both `self` and `other`.
+    ///
+    /// This is the length of `*self & *other`, without storing the intersection.
+    #[inline]
+    #[must_use]
+    pub fn intersection_len(&self, other: &Self) -> usize {
+        (self.words & other.words).count_ones().reduce_sum() as usize
+    }
+
+    /// Returns an iterator over the integers in the set, in increasing order.
+    #[inline]
+    pub fn iter(&self) -> Iter<N> {
+        Iter {
+            words: self.words.to_array(),
+            word: 0,
+            bits: self.words[0],
+        }
+    }
+}
+
+impl<const N: usize> Default for SimdBitSet<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for SimdBitSet<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Creates the set of the integers of `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an integer isn't less than [`CAPACITY`](Self::CAPACITY).
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+impl<const N: usize> IntoIterator for &SimdBitSet<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Item = usize;
+    type IntoIter = Iter<N>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<N> {
+        self.iter()
+    }
+}
+
+macro_rules! impl_set_op {
+    { $($trait:ident::$fn:ident, $assign_trait:ident::$assign_fn:ident;)* } => {
+        $(
+        impl<const N: usize> $trait for SimdBitSet<N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, other: Self) -> Self {
+                Self::from_words(self.words.$fn(other.words))
+            }
+        }
+
+        impl<const N: usize> $assign_trait for SimdBitSet<N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            #[inline]
+            fn $assign_fn(&mut self, other: Self) {
+                self.words.$assign_fn(other.words);
+            }
+        }
+        )*
+    }
+}
+
+impl_set_op! {
+    BitAnd::bitand, BitAndAssign::bitand_assign;
+    BitOr::bitor, BitOrAssign::bitor_assign;
+    BitXor::bitxor, BitXorAssign::bitxor_assign;
+}
+
+/// An iterator over the integers in a [`SimdBitSet`], in increasing order.
+#[derive(Clone, Debug)]
+pub struct Iter<const N: usize> {
+    /// The bits of the set.
+    words: [u64; N],
+    /// The word being iterated over.
+    word: usize,
+    /// The bits of the current word that haven't been returned yet.
+    bits: u64,
+}
+
+impl<const N: usize> Iterator for Iter<N> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while self.bits == 0 {
+            self.word += 1;
+            self.bits = *self.words.get(self.word)?;
+        }
+        let bit = self.bits.trailing_zeros() as usize;
+        // Clear the lowest set bit
+        self.bits &= self.bits - 1;
+        Some(self.word * 64 + bit)
+    }
+}
@@ -0,0 +1,123 @@
+//! Operations on byte strings, processed a vector of bytes at a time.
+
+use crate::simd::{
+    u8x32, u8x64, LaneCount, Mask, Simd, SimdPartialEq, SimdPartialOrd, SimdUint,
+    SupportedLaneCount, ToBitMask,
+};
+
+/// Converts the ASCII uppercase letters in each lane to lowercase, leaving all other bytes
+/// unchanged.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{bytes, Simd};
+/// let x = Simd::from_array(*b"Hi, [Zz]");
+/// assert_eq!(bytes::to_ascii_lowercase(x).to_array(), *b"hi, [zz]");
+/// ```
+#[inline]
+#[must_use = "method returns a new vector and does not mutate the original value"]
+pub fn to_ascii_lowercase<const LANES: usize>(bytes: Simd<u8, LANES>) -> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let is_upper = bytes.simd_ge(Simd::splat(b'A')) & bytes.simd_le(Simd::splat(b'Z'));
+    bytes | is_upper.select(Simd::splat(0x20), Simd::splat(0))
+}
+
+/// Checks that two byte strings are an ASCII case-insensitive match.
+///
+/// This is equivalent to `<[u8]>::eq_ignore_ascii_case`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::bytes;
+/// assert!(bytes::eq_ignore_ascii_case(b"Content-Length", b"content-length"));
+/// assert!(!bytes::eq_ignore_ascii_case(b"Content-Length", b"Content-Type"));
+/// ```
+#[must_use]
+#[inline]
+pub fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let a_chunks = a.chunks_exact(u8x32::LANES);
+    let b_chunks = b.chunks_exact(u8x32::LANES);
+    let (a_rest, b_rest) = (a_chunks.remainder(), b_chunks.remainder());
+
+    // Accumulate any difference across all of the chunks, so the loop doesn't branch per chunk
+    let mut diff = u8x32::splat(0);
+    for (a, b) in a_chunks.zip(b_chunks) {
+        let a = to_ascii_lowercase(u8x32::from_slice(a));
+        let b = to_ascii_lowercase(u8x32::from_slice(b));
+        diff |= a ^ b;
+    }
+
+    diff.reduce_or() == 0 && a_rest.eq_ignore_ascii_case(b_rest)
+}
+
+/// Checks that `haystack` begins with `prefix`, ignoring ASCII case.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::bytes;
+/// assert!(bytes::starts_with_ignore_case(b"Transfer-Encoding: chunked", b"transfer-encoding:"));
+/// assert!(!bytes::starts_with_ignore_case(b"Host", b"hostname"));
+/// ```
+#[must_use]
+#[inline]
+pub fn starts_with_ignore_case(haystack: &[u8], prefix: &[u8]) -> bool {
+    haystack.len() >= prefix.len() && eq_ignore_ascii_case(&haystack[..prefix.len()], prefix)
+}
+
+/// Returns a bitmask of the positions in the first 64 bytes of `haystack` where any of
+/// `needles` starts.
+///
+/// Bit `i` of the result is set if `haystack[i..]` starts with one of the needles, which must
+/// fit within `haystack`.  Each needle is compared against 64 positions at once: each of its
+/// bytes is broadcast to a vector and compared with the haystack shifted by that byte's offset.
+/// This suits many short needles, such as keywords or protocol tokens.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::bytes;
+/// let line = b"GET /index.html HTTP/1.1";
+/// let methods: [&[u8]; 3] = [b"GET ", b"POST ", b"PUT "];
+/// assert_eq!(bytes::match_any(line, &methods), 1);
+///
+/// let matches = bytes::match_any(line, &[b"HTTP/", b".html"]);
+/// assert_eq!(matches, 1 << 10 | 1 << 16);
+/// ```
+#[must_use]
+#[inline]
+pub fn match_any(haystack: &[u8], needles: &[&[u8]]) -> u64 {
+    let mut matches = 0;
+    for needle in needles {
+        if needle.len() > haystack.len() {
+            continue;
+        }
+        // Positions where the needle fits within the haystack
+        let positions = (haystack.len() + 1 - needle.len()).min(u8x64::LANES);
+        let fits = u64::MAX >> (u8x64::LANES - positions);
+
+        let mut needle_matches = Mask::splat(true);
+        for (offset, byte) in needle.iter().enumerate() {
+            let shifted = u8x64::load_or_default(&haystack[offset..]);
+            needle_matches &= shifted.simd_eq(u8x64::splat(*byte));
+        }
+        matches |= needle_matches.to_bitmask() & fits;
+    }
+    matches
+}
@@ -0,0 +1,182 @@
+//! Streaming checksums, computed a vector at a time.
+
+use crate::simd::{LaneCount, Simd, SimdElement, SimdUint, SupportedLaneCount};
+
+/// Computes the sums needed to append a block of `LANES`-sized chunks to a running checksum.
+///
+/// Returns the sum of the elements of the block, and the sum of each element weighted by its
+/// distance from the end of the block, so that the last element has a weight of 1.
+#[inline]
+fn block_sums<T, const LANES: usize>(block: &[T]) -> (u64, u64)
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    debug_assert_eq!(block.len() % LANES, 0);
+
+    // `sum` holds the sum of each lane over the chunks so far, and `prefix` the sum of `sum`
+    // before each chunk, which weights each chunk by the number of chunks after it.
+    let mut sum = Simd::<u32, LANES>::splat(0);
+    let mut prefix = Simd::<u32, LANES>::splat(0);
+    for chunk in block.chunks_exact(LANES) {
+        prefix += sum;
+        sum += Simd::<T, LANES>::from_slice(chunk).cast::<u32>();
+    }
+
+    // Within a chunk, each lane is weighted by its distance from the end of the chunk
+    let mut weights = [0; LANES];
+    for (i, weight) in weights.iter_mut().enumerate() {
+        *weight = (LANES - i) as u64;
+    }
+    let sum = sum.cast::<u64>();
+    let weighted =
+        prefix.cast::<u64>() * Simd::splat(LANES as u64) + sum * Simd::from_array(weights);
+    (sum.reduce_sum(), weighted.reduce_sum())
+}
+
+/// The streaming state of an Adler-32 checksum, as used by zlib.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::checksum::Adler32;
+/// let mut adler = Adler32::new();
+/// adler.update(b"Wiki");
+/// adler.update(b"pedia");
+/// assert_eq!(adler.finish(), 0x11e60398);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    const MODULUS: u32 = 65521;
+    const LANES: usize = 32;
+    /// The largest multiple of `LANES` within zlib's block of 5552 bytes.  The per-lane sums in
+    /// `block_sums` only overflow past 5804 chunks, so this is far from their limit.
+    const BLOCK: usize = Self::LANES * 173;
+
+    /// Creates the state for an empty input.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { a: 1, b: 0 }
+    }
+
+    /// Appends `data` to the checksummed input.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        let blocks = data.chunks_exact(Self::BLOCK);
+        let rest = blocks.remainder();
+        for block in blocks {
+            self.update_block(block);
+        }
+        let (chunks, rest) = rest.split_at(rest.len() - rest.len() % Self::LANES);
+        self.update_block(chunks);
+
+        for byte in rest {
+            self.a = (self.a + *byte as u32) % Self::MODULUS;
+            self.b = (self.b + self.a) % Self::MODULUS;
+        }
+    }
+
+    #[inline]
+    fn update_block(&mut self, block: &[u8]) {
+        let (sum, weighted) = block_sums::<u8, { Self::LANES }>(block);
+        let modulus = Self::MODULUS as u64;
+        let b = self.b as u64 + block.len() as u64 * self.a as u64 + weighted;
+        self.a = ((self.a as u64 + sum) % modulus) as u32;
+        self.b = (b % modulus) as u32;
+    }
+
+    /// Returns the checksum of the input so far.
+    #[inline]
+    #[must_use]
+    pub const fn finish(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+impl Default for Adler32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The streaming state of a Fletcher-32 checksum, over 16-bit words.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::checksum::Fletcher32;
+/// let words: Vec<u16> = b"abcdef".chunks(2).map(|w| u16::from_le_bytes([w[0], w[1]])).collect();
+/// let mut fletcher = Fletcher32::new();
+/// fletcher.update(&words);
+/// assert_eq!(fletcher.finish(), 0x56502d2a);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fletcher32 {
+    a: u32,
+    b: u32,
+}
+
+impl Fletcher32 {
+    const MODULUS: u32 = 65535;
+    const LANES: usize = 16;
+    /// The per-lane sums in `block_sums` only overflow past 362 chunks of `u16::MAX` words.
+    const BLOCK: usize = Self::LANES * 256;
+
+    /// Creates the state for an empty input.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { a: 0, b: 0 }
+    }
+
+    /// Appends `data` to the checksummed input.
+    #[inline]
+    pub fn update(&mut self, data: &[u16]) {
+        let blocks = data.chunks_exact(Self::BLOCK);
+        let rest = blocks.remainder();
+        for block in blocks {
+            self.update_block(block);
+        }
+        let (chunks, rest) = rest.split_at(rest.len() - rest.len() % Self::LANES);
+        self.update_block(chunks);
+
+        for word in rest {
+            self.a = (self.a + *word as u32) % Self::MODULUS;
+            self.b = (self.b + self.a) % Self::MODULUS;
+        }
+    }
+
+    #[inline]
+    fn update_block(&mut self, block: &[u16]) {
+        let (sum, weighted) = block_sums::<u16, { Self::LANES }>(block);
+        let modulus = Self::MODULUS as u64;
+        let b = self.b as u64 + block.len() as u64 * self.a as u64 + weighted;
+        self.a = ((self.a as u64 + sum) % modulus) as u32;
+        self.b = (b % modulus) as u32;
+    }
+
+    /// Returns the checksum of the input so far.
+    #[inline]
+    #[must_use]
+    pub const fn finish(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+impl Default for Fletcher32 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
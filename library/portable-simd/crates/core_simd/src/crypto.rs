@@ -0,0 +1,141 @@
+//! Cipher cores, computing many blocks at once.
+
+use crate::simd::{LaneCount, Simd, SupportedLaneCount};
+
+/// Rotates each lane of `x` left by `N` bits.
+#[inline]
+fn rotate_left<const N: u32, const LANES: usize>(x: Simd<u32, LANES>) -> Simd<u32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (x << Simd::splat(N)) | (x >> Simd::splat(32 - N))
+}
+
+/// The ChaCha quarter round on words `a`, `b`, `c` and `d` of the state.
+#[inline]
+fn quarter_round<const LANES: usize>(
+    x: &mut [Simd<u32, LANES>; 16],
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+) where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    x[a] += x[b];
+    x[d] = rotate_left::<16, LANES>(x[d] ^ x[a]);
+    x[c] += x[d];
+    x[b] = rotate_left::<12, LANES>(x[b] ^ x[c]);
+    x[a] += x[b];
+    x[d] = rotate_left::<8, LANES>(x[d] ^ x[a]);
+    x[c] += x[d];
+    x[b] = rotate_left::<7, LANES>(x[b] ^ x[c]);
+}
+
+/// Computes `LANES` consecutive blocks of ChaCha20, as in [RFC 8439].
+///
+/// `state` is the input of the first block: the four constant words, the eight words of the
+/// key, the block counter, and the three words of the nonce.  Each lane computes the block of
+/// the next counter, wrapping around at `u32::MAX`, so the result is the keystream of
+/// `64 * LANES` bytes starting at the block of `state`.
+///
+/// The blocks are returned transposed, so word `i` of the block in lane `j` is lane `j` of
+/// vector `i`.  The keystream is the words of each block in little-endian order, as written by
+/// [`chacha_keystream`].
+///
+/// The state of all of the blocks is kept a word per vector, so every step of the quarter rounds
+/// applies to all of the blocks at once.  Apart from being a stream cipher, ChaCha20 is a fast
+/// source of deterministic random numbers, seeded by the key.
+///
+/// [RFC 8439]: https://www.rfc-editor.org/rfc/rfc8439
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::crypto;
+/// // The block function test vector of RFC 8439, section 2.3.2
+/// let state = [
+///     0x61707865, 0x3320646e, 0x79622d32, 0x6b206574, // "expand 32-byte k"
+///     0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c, // key
+///     0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c,
+///     0x00000001, // block counter
+///     0x09000000, 0x4a000000, 0x00000000, // nonce
+/// ];
+/// let blocks = crypto::chacha_block::<4>(&state);
+/// assert_eq!(blocks[0][0], 0xe4e7f110);
+/// assert_eq!(blocks[15][0], 0x4e3c50a2);
+///
+/// // Each lane is the block of the next counter
+/// let mut next = state;
+/// next[12] += 1;
+/// assert_eq!(blocks[0][1], crypto::chacha_block::<1>(&next)[0][0]);
+/// ```
+#[must_use]
+#[inline]
+pub fn chacha_block<const LANES: usize>(state: &[u32; 16]) -> [Simd<u32, LANES>; 16]
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut input = state.map(Simd::splat);
+    let mut counters = [0; LANES];
+    for (i, counter) in counters.iter_mut().enumerate() {
+        *counter = i as u32;
+    }
+    input[12] += Simd::from_array(counters);
+
+    let mut x = input;
+    for _ in 0..10 {
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 1, 5, 9, 13);
+        quarter_round(&mut x, 2, 6, 10, 14);
+        quarter_round(&mut x, 3, 7, 11, 15);
+        quarter_round(&mut x, 0, 5, 10, 15);
+        quarter_round(&mut x, 1, 6, 11, 12);
+        quarter_round(&mut x, 2, 7, 8, 13);
+        quarter_round(&mut x, 3, 4, 9, 14);
+    }
+    for (x, input) in x.iter_mut().zip(input) {
+        *x += input;
+    }
+    x
+}
+
+/// Writes the ChaCha20 keystream starting at the block of `state` to `out`, and returns the
+/// state of the block after it.
+///
+/// The keystream is computed 8 blocks at a time with [`chacha_block`].  Encrypting or decrypting
+/// is XORing the keystream with the message.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::crypto;
+/// let mut state = [0; 16];
+/// state[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+/// let mut keystream = [0; 100];
+/// let next = crypto::chacha_keystream(&state, &mut keystream);
+/// // The keystream of the zero key and nonce, from RFC 8439, section A.1
+/// assert_eq!(keystream[..4], [0x76, 0xb8, 0xe0, 0xad]);
+/// assert_eq!(next[12], 2);
+/// ```
+#[inline]
+pub fn chacha_keystream(state: &[u32; 16], out: &mut [u8]) -> [u32; 16] {
+    const LANES: usize = 8;
+    const BLOCK: usize = 64;
+
+    let mut state = *state;
+    for chunk in out.chunks_mut(BLOCK * LANES) {
+        let blocks = chacha_block::<LANES>(&state);
+        for (i, block) in chunk.chunks_mut(BLOCK).enumerate() {
+            for (j, bytes) in block.chunks_mut(4).enumerate() {
+                bytes.copy_from_slice(&blocks[j][i].to_le_bytes()[..bytes.len()]);
+            }
+            state[12] = state[12].wrapping_add(1);
+        }
+    }
+    state
+}
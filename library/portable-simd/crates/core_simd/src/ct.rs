@@ -0,0 +1,109 @@
+//! Constant-time comparisons and selects, for cryptography.
+//!
+//! Code that handles secrets must take the same time whatever the secrets are, so it can't
+//! branch on them or index memory with them.  Vector operations are a good fit, since they
+//! process every lane the same way, but the optimizer is free to branch on a comparison or a
+//! mask when it can, so the functions of this module hide their secret operands from it with
+//! [`black_box`](core::hint::black_box).
+//!
+//! On targets with vector selects, which are x86 and x86-64 with SSE2, AArch64 with NEON, and
+//! WebAssembly with `simd128`, these functions don't branch on or index memory with the contents
+//! of their operands, only their lengths.  It's a best-effort guarantee rather than one the
+//! language makes, and other targets may not honor it.  The compiler's assembly tests only check
+//! [`cond_swap`] on x86-64 and AArch64, with optimizations enabled.
+
+use crate::simd::{u8x16, LaneCount, Mask, Simd, SimdElement, SimdUint, SupportedLaneCount};
+
+/// Returns whether the slices `a` and `b` are equal, in time that depends only on their lengths.
+///
+/// Unlike `a == b`, this doesn't stop at the first difference, so it's suitable for comparing
+/// secrets such as message authentication codes.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::ct;
+/// let tag = [0x5a; 32];
+/// assert!(ct::ct_eq(&tag, &[0x5a; 32]));
+/// assert!(!ct::ct_eq(&tag, &[0xa5; 32]));
+/// assert!(!ct::ct_eq(&tag, &[0x5a; 31]));
+/// ```
+#[must_use]
+#[inline]
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut diff = u8x16::splat(0);
+    let mut a_chunks = a.chunks_exact(16);
+    let mut b_chunks = b.chunks_exact(16);
+    for (a, b) in (&mut a_chunks).zip(&mut b_chunks) {
+        diff |= u8x16::from_slice(a) ^ u8x16::from_slice(b);
+    }
+    diff |=
+        u8x16::load_or_default(a_chunks.remainder()) ^ u8x16::load_or_default(b_chunks.remainder());
+    core::hint::black_box(diff).reduce_or() == 0
+}
+
+/// Choose lanes from `true_values` where `mask` is true and from `false_values` elsewhere,
+/// without branching on `mask`.
+///
+/// This is [`Mask::select_branchless`], as a function alongside the rest of this module.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{ct, u64x4, Mask};
+/// let mask = Mask::from_array([true, false, false, true]);
+/// let selected = ct::ct_select(mask, u64x4::splat(1), u64x4::splat(2));
+/// assert_eq!(selected.to_array(), [1, 2, 2, 1]);
+/// ```
+#[must_use]
+#[inline]
+pub fn ct_select<T, const LANES: usize>(
+    mask: Mask<T::Mask, LANES>,
+    true_values: Simd<T, LANES>,
+    false_values: Simd<T, LANES>,
+) -> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    mask.select_branchless(true_values, false_values)
+}
+
+/// Swaps the lanes of `a` and `b` where `mask` is true, without branching on `mask`.
+///
+/// This is the conditional swap of constant-time algorithms such as the Montgomery ladder.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{ct, u32x4, Mask};
+/// let mut a = u32x4::from_array([0, 1, 2, 3]);
+/// let mut b = u32x4::from_array([4, 5, 6, 7]);
+/// ct::cond_swap(Mask::from_array([true, false, true, false]), &mut a, &mut b);
+/// assert_eq!(a.to_array(), [4, 1, 6, 3]);
+/// assert_eq!(b.to_array(), [0, 5, 2, 7]);
+/// ```
+#[inline]
+pub fn cond_swap<T, const LANES: usize>(
+    mask: Mask<T::Mask, LANES>,
+    a: &mut Simd<T, LANES>,
+    b: &mut Simd<T, LANES>,
+) where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    (*a, *b) = (
+        mask.select_branchless(*b, *a),
+        mask.select_branchless(*a, *b),
+    );
+}
@@ -0,0 +1,129 @@
+//! Delta encoding of integer sequences, as used to compress time series.
+//!
+//! Slowly changing sequences, such as timestamps or sensor readings, have small differences
+//! between neighbouring elements, which pack into fewer bits than the elements themselves.
+//! Encoding subtracts each element's predecessor, and decoding adds them back with a prefix sum,
+//! computed within each vector in a logarithmic number of steps.  All arithmetic wraps, so every
+//! sequence round-trips exactly.
+
+use super::shift::{prefix_sum, ShiftUp};
+use crate::simd::{Simd, SimdElement, Swizzle2};
+use core::ops::{Add, Sub};
+
+/// The number of elements processed at once.
+const LANES: usize = 16;
+
+pub(crate) mod sealed {
+    /// Integers that can be delta encoded.
+    pub trait Integer: super::SimdElement + Default {}
+}
+use sealed::Integer;
+
+macro_rules! impl_integer {
+    { $($ty:ty),* } => {
+        $(impl Integer for $ty {})*
+    }
+}
+
+impl_integer! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+
+/// Replaces each element of `values` with its difference from the element before it.
+///
+/// The first element is left as it is, as if preceded by zero.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::delta;
+/// let mut timestamps = [1000u32, 1010, 1020, 1031, 1040];
+/// delta::encode(&mut timestamps);
+/// assert_eq!(timestamps, [1000, 10, 10, 11, 9]);
+/// delta::decode(&mut timestamps);
+/// assert_eq!(timestamps, [1000, 1010, 1020, 1031, 1040]);
+/// ```
+#[inline]
+pub fn encode<T>(values: &mut [T])
+where
+    T: Integer,
+    Simd<T, LANES>: Sub<Output = Simd<T, LANES>>,
+{
+    let mut previous = Simd::splat(T::default());
+    let mut chunks = values.chunks_exact_mut(LANES);
+    for chunk in &mut chunks {
+        let x = Simd::from_slice(chunk);
+        chunk.copy_from_slice((x - ShiftUp::<1>::swizzle2(x, previous)).as_array());
+        previous = Simd::splat(x[LANES - 1]);
+    }
+
+    let chunk = chunks.into_remainder();
+    let x = Simd::load_or_default(chunk);
+    let deltas = x - ShiftUp::<1>::swizzle2(x, previous);
+    chunk.copy_from_slice(&deltas[..chunk.len()]);
+}
+
+/// Replaces each element of `deltas` with the sum of it and every element before it, reversing
+/// [`encode`].
+#[inline]
+pub fn decode<T>(deltas: &mut [T])
+where
+    T: Integer,
+    Simd<T, LANES>: Add<Output = Simd<T, LANES>>,
+{
+    let mut carry = Simd::splat(T::default());
+    let mut chunks = deltas.chunks_exact_mut(LANES);
+    for chunk in &mut chunks {
+        let sums = prefix_sum(Simd::from_slice(chunk)) + carry;
+        chunk.copy_from_slice(sums.as_array());
+        carry = Simd::splat(sums[LANES - 1]);
+    }
+
+    let chunk = chunks.into_remainder();
+    let sums = prefix_sum(Simd::load_or_default(chunk)) + carry;
+    chunk.copy_from_slice(&sums[..chunk.len()]);
+}
+
+/// Replaces each element of `values` with its delta-of-delta, the difference between its delta
+/// and the delta before it.
+///
+/// The first element is left as it is, and the second is replaced with its delta, so sequences
+/// that change at a steady rate, such as regular timestamps, encode to mostly zeros.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::delta;
+/// let mut timestamps = [1000i64, 1010, 1020, 1031, 1040];
+/// delta::encode_delta_of_delta(&mut timestamps);
+/// assert_eq!(timestamps, [1000, 10, 0, 1, -2]);
+/// delta::decode_delta_of_delta(&mut timestamps);
+/// assert_eq!(timestamps, [1000, 1010, 1020, 1031, 1040]);
+/// ```
+#[inline]
+pub fn encode_delta_of_delta<T>(values: &mut [T])
+where
+    T: Integer,
+    Simd<T, LANES>: Sub<Output = Simd<T, LANES>>,
+{
+    encode(values);
+    if let Some(deltas) = values.get_mut(1..) {
+        encode(deltas);
+    }
+}
+
+/// Replaces each element of `values` with the value it was delta-of-delta encoded from,
+/// reversing [`encode_delta_of_delta`].
+#[inline]
+pub fn decode_delta_of_delta<T>(values: &mut [T])
+where
+    T: Integer,
+    Simd<T, LANES>: Add<Output = Simd<T, LANES>>,
+{
+    if let Some(deltas) = values.get_mut(1..) {
+        decode(deltas);
+    }
+    decode(values);
+}
@@ -0,0 +1,74 @@
+//! Dictionary decoding, as in Parquet and ORC.
+//!
+//! Columns with few distinct values are stored as a dictionary of those values and a code for
+//! each row, the index of its value in the dictionary.  Decoding checks that a whole batch of
+//! codes is in bounds with one comparison, and then gathers the batch's values from the
+//! dictionary at once.
+
+use crate::simd::{Mask, Simd, SimdElement, SimdPartialOrd};
+
+/// The number of codes decoded at once.
+const LANES: usize = 16;
+
+/// Writes the entry of `dict` for each of `codes` to `out`.
+///
+/// # Panics
+///
+/// Panics if the slices `codes` and `out` have different lengths, or if any code is out of
+/// bounds for `dict`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::dict;
+/// let dict = [1.5, -2.0, 100.0];
+/// let codes = [2, 0, 0, 1, 2];
+/// let mut out = [0.0; 5];
+/// dict::decode(&codes, &dict, &mut out);
+/// assert_eq!(out, [100.0, 1.5, 1.5, -2.0, 100.0]);
+/// ```
+#[inline]
+pub fn decode<T>(codes: &[u32], dict: &[T], out: &mut [T])
+where
+    T: SimdElement + Default,
+{
+    assert_eq!(
+        codes.len(),
+        out.len(),
+        "codes and output must have the same length"
+    );
+
+    let default = Simd::<T, LANES>::splat(T::default());
+    let decode_batch = |codes: &[u32], enable: Mask<isize, LANES>| {
+        let idxs = Simd::<u32, LANES>::load_or_default(codes).cast::<usize>();
+        let in_bounds = idxs.simd_lt(Simd::splat(dict.len()));
+        if !(in_bounds | !enable).all() {
+            out_of_bounds(codes, dict.len());
+        }
+        // Safety: every enabled index was just checked to be in bounds.
+        unsafe { Simd::gather_select_unchecked(dict, enable, idxs, default) }
+    };
+
+    let mut code_chunks = codes.chunks_exact(LANES);
+    let mut out_chunks = out.chunks_exact_mut(LANES);
+    for (codes, out) in (&mut code_chunks).zip(&mut out_chunks) {
+        out.copy_from_slice(decode_batch(codes, Mask::splat(true)).as_array());
+    }
+
+    let codes = code_chunks.remainder();
+    let out = out_chunks.into_remainder();
+    if !codes.is_empty() {
+        let enable =
+            Simd::from_array(core::array::from_fn(|i| i)).simd_lt(Simd::splat(codes.len()));
+        out.copy_from_slice(&decode_batch(codes, enable)[..codes.len()]);
+    }
+}
+
+/// Panics with the first code of `codes` that is out of bounds.
+#[cold]
+fn out_of_bounds(codes: &[u32], len: usize) -> ! {
+    let code = codes.iter().find(|code| **code as usize >= len).unwrap();
+    panic!("code {code} is out of bounds for a dictionary of {len} entries");
+}
@@ -0,0 +1,292 @@
+//! Distance metrics between slices, computed a vector at a time.
+//!
+//! The floating-point metrics keep several vectors of partial sums, so that consecutive
+//! vectors are added to different sums and the additions can run in parallel, rather than each
+//! waiting for the one before it.  The elements past the last whole vector are loaded into a
+//! vector padded with zeros, which add nothing to the sums.  Since the elements are summed in a
+//! different order, the results can differ from a sequential sum by rounding.
+
+use crate::simd::{
+    f32x8, i32x16, i8x16, intrinsics, u8x64, LaneCount, Simd, SimdElement, SimdFloat, SimdInt,
+    SimdUint, SupportedLaneCount,
+};
+use core::ops::{BitAnd, BitOr, BitXor};
+
+/// The number of vectors of partial sums.
+const UNROLL: usize = 4;
+
+/// Returns the number of bits that differ between `a` and `b`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::distance;
+/// assert_eq!(distance::hamming(b"karolin", b"kathrin"), 9);
+/// assert_eq!(distance::hamming(&[0xff; 100], &[0; 100]), 800);
+/// ```
+#[must_use]
+#[inline]
+pub fn hamming(a: &[u8], b: &[u8]) -> u64 {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+
+    // Each byte differs by at most 8 bits, so 31 chunks can be counted before a u8 overflows.
+    const CHUNKS_PER_GROUP: usize = (u8::MAX / 8) as usize;
+    const GROUP: usize = CHUNKS_PER_GROUP * u8x64::LANES;
+
+    let a_groups = a.chunks(GROUP);
+    let b_groups = b.chunks(GROUP);
+    let mut total = Simd::<u32, 64>::splat(0);
+    let mut rest = 0;
+    for (a, b) in a_groups.zip(b_groups) {
+        let a_chunks = a.chunks_exact(u8x64::LANES);
+        let b_chunks = b.chunks_exact(u8x64::LANES);
+        rest += a_chunks
+            .remainder()
+            .iter()
+            .zip(b_chunks.remainder())
+            .map(|(a, b)| (a ^ b).count_ones() as u64)
+            .sum::<u64>();
+
+        let mut counts = u8x64::splat(0);
+        for (a, b) in a_chunks.zip(b_chunks) {
+            counts += (u8x64::from_slice(a) ^ u8x64::from_slice(b)).count_ones();
+        }
+        total += counts.cast();
+    }
+
+    total.cast::<u64>().reduce_sum() + rest
+}
+
+/// Adds three vectors of bits, returning the carry bits and the sum bits.
+#[inline]
+fn carry_save_add<V>(a: V, b: V, c: V) -> (V, V)
+where
+    V: Copy + BitAnd<Output = V> + BitOr<Output = V> + BitXor<Output = V>,
+{
+    let half = a ^ b;
+    ((a & b) | (half & c), half ^ c)
+}
+
+/// Adds the number of bits that differ between each lane of the vectors of `a` and the
+/// corresponding lane of the vectors of `b` to that lane of `acc`.
+///
+/// This is the inner loop of comparing bit strings, such as binary embeddings or packed
+/// k-mers, which differ by the sum of the lanes of `acc`.  Rather than counting the bits of
+/// every vector, the vectors are counted with the Harley-Seal method: each group of 16 vectors
+/// is added with bitwise carry-save adders into counters of ones, twos, fours and eights, and
+/// only the carries out of the eights are counted.  The vectors after the last whole group are
+/// counted one at a time.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{distance, u64x4, Simd};
+/// let a = [u64x4::splat(u64::MAX); 20];
+/// let b = [u64x4::from_array([0, 1, u64::MAX, 0xff]); 20];
+/// let mut acc = Simd::splat(0);
+/// distance::xor_popcount_accumulate(&a, &b, &mut acc);
+/// assert_eq!(acc, Simd::from_array([1280, 1260, 0, 1120]));
+/// ```
+#[inline]
+#[track_caller]
+pub fn xor_popcount_accumulate<T, const LANES: usize>(
+    a: &[Simd<T, LANES>],
+    b: &[Simd<T, LANES>],
+    acc: &mut Simd<u64, LANES>,
+) where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: SimdUint
+        + BitAnd<Output = Simd<T, LANES>>
+        + BitOr<Output = Simd<T, LANES>>
+        + BitXor<Output = Simd<T, LANES>>,
+{
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let count = |x: Simd<T, LANES>| x.count_ones().cast::<u64>();
+
+    // The bits of each counter are the ones, twos, fours and eights of the count of each bit
+    let mut counters = [Simd::splat(T::default()); 4];
+    let mut sixteens = Simd::splat(0);
+    let a_groups = a.chunks_exact(16);
+    let b_groups = b.chunks_exact(16);
+    let (a_rest, b_rest) = (a_groups.remainder(), b_groups.remainder());
+    for (a, b) in a_groups.zip(b_groups) {
+        // Each counter adds pairs of the carries into it, halving the number of carries
+        let mut carries: [Simd<T, LANES>; 16] = core::array::from_fn(|i| a[i] ^ b[i]);
+        let mut len = carries.len();
+        for counter in &mut counters {
+            len /= 2;
+            for i in 0..len {
+                (carries[i], *counter) =
+                    carry_save_add(*counter, carries[2 * i], carries[2 * i + 1]);
+            }
+        }
+        sixteens += count(carries[0]);
+    }
+
+    *acc += sixteens * Simd::splat(16);
+    for (weight, counter) in counters.into_iter().enumerate() {
+        *acc += count(counter) * Simd::splat(1 << weight);
+    }
+    for (a, b) in a_rest.iter().zip(b_rest) {
+        *acc += count(*a ^ *b);
+    }
+}
+
+/// Calls `step` with each vector of `a` and `b`, the last padded with zeros, and one of `UNROLL`
+/// sets of partial sums in turn, and returns the total of each sum.
+#[inline]
+fn accumulate<const SUMS: usize>(
+    a: &[f32],
+    b: &[f32],
+    step: impl Fn(&mut [f32x8; SUMS], f32x8, f32x8),
+) -> [f32; SUMS] {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+
+    const BLOCK: usize = UNROLL * f32x8::LANES;
+    let mut sums = [[f32x8::splat(0.0); SUMS]; UNROLL];
+    let a_blocks = a.chunks_exact(BLOCK);
+    let b_blocks = b.chunks_exact(BLOCK);
+    let (a_rest, b_rest) = (a_blocks.remainder(), b_blocks.remainder());
+    for (a, b) in a_blocks.zip(b_blocks) {
+        let vectors = a
+            .chunks_exact(f32x8::LANES)
+            .zip(b.chunks_exact(f32x8::LANES));
+        for (sums, (a, b)) in sums.iter_mut().zip(vectors) {
+            step(sums, f32x8::from_slice(a), f32x8::from_slice(b));
+        }
+    }
+    let vectors = a_rest.chunks(f32x8::LANES).zip(b_rest.chunks(f32x8::LANES));
+    for (sums, (a, b)) in sums.iter_mut().zip(vectors) {
+        step(sums, f32x8::load_or_default(a), f32x8::load_or_default(b));
+    }
+
+    core::array::from_fn(|i| {
+        let [w, x, y, z] = sums.map(|sums| sums[i]);
+        ((w + x) + (y + z)).reduce_sum()
+    })
+}
+
+/// Returns the squared Euclidean distance between `a` and `b`, the sum of the squares of the
+/// differences of their elements.
+///
+/// Comparing squared distances orders points the same way as comparing distances, without
+/// taking square roots.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::distance;
+/// assert_eq!(distance::l2_squared(&[1.0, 2.0, 3.0], &[4.0, 6.0, 3.0]), 25.0);
+/// let a: Vec<f32> = (0..100).map(|x| x as f32).collect();
+/// assert_eq!(distance::l2_squared(&a, &vec![0.0; 100]), 328350.0);
+/// ```
+#[must_use]
+#[inline]
+pub fn l2_squared(a: &[f32], b: &[f32]) -> f32 {
+    let [sum] = accumulate(a, b, |[sum], a, b| {
+        let difference = a - b;
+        *sum += difference * difference;
+    });
+    sum
+}
+
+/// Returns the cosine distance between `a` and `b`, one minus the cosine of the angle between
+/// them.
+///
+/// The distance is 0 for vectors pointing the same way, 1 for orthogonal vectors and 2 for
+/// vectors pointing opposite ways, whatever their lengths.  The dot product and both squared
+/// lengths are summed in the same pass.  Returns NaN if either vector has length zero.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::distance;
+/// assert_eq!(distance::cosine(&[1.0, 0.0], &[0.0, 3.0]), 1.0);
+/// assert_eq!(distance::cosine(&[1.0, 1.0], &[-2.0, -2.0]), 2.0);
+/// assert!((distance::cosine(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0])).abs() < 1e-6);
+/// assert!(distance::cosine(&[0.0; 4], &[1.0; 4]).is_nan());
+/// ```
+#[must_use]
+#[inline]
+pub fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let [dot, a_squared, b_squared] = accumulate(a, b, |[dot, a_squared, b_squared], a, b| {
+        *dot += a * b;
+        *a_squared += a * a;
+        *b_squared += b * b;
+    });
+    // Safety: `simd_fsqrt` is safe for any vector of floats
+    let lengths = unsafe { intrinsics::simd_fsqrt(Simd::from_array([a_squared, b_squared])) };
+    1.0 - dot / (lengths[0] * lengths[1])
+}
+
+/// Returns the dot product of `a` and `b`, the sum of the products of their elements.
+///
+/// The products are summed in 32-bit lanes, which wrap on overflow, so the result is exact
+/// unless the dot product doesn't fit in an `i32`, which needs slices of at least 131072
+/// elements.  This is the distance used for embeddings quantized to bytes.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::distance;
+/// assert_eq!(distance::dot_i8(&[1, -2, 3], &[4, 5, -6]), -24);
+/// assert_eq!(distance::dot_i8(&[-128; 100], &[-128; 100]), 1638400);
+/// ```
+#[must_use]
+#[inline]
+pub fn dot_i8(a: &[i8], b: &[i8]) -> i32 {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+
+    const BLOCK: usize = UNROLL * i8x16::LANES;
+    let mut sums = [i32x16::splat(0); UNROLL];
+    let a_blocks = a.chunks_exact(BLOCK);
+    let b_blocks = b.chunks_exact(BLOCK);
+    let (a_rest, b_rest) = (a_blocks.remainder(), b_blocks.remainder());
+    let product = |a: i8x16, b: i8x16| a.cast::<i32>() * b.cast::<i32>();
+    for (a, b) in a_blocks.zip(b_blocks) {
+        let vectors = a
+            .chunks_exact(i8x16::LANES)
+            .zip(b.chunks_exact(i8x16::LANES));
+        for (sum, (a, b)) in sums.iter_mut().zip(vectors) {
+            *sum += product(i8x16::from_slice(a), i8x16::from_slice(b));
+        }
+    }
+    let vectors = a_rest.chunks(i8x16::LANES).zip(b_rest.chunks(i8x16::LANES));
+    for (sum, (a, b)) in sums.iter_mut().zip(vectors) {
+        *sum += product(i8x16::load_or_default(a), i8x16::load_or_default(b));
+    }
+
+    let [w, x, y, z] = sums;
+    ((w + x) + (y + z)).reduce_sum()
+}
@@ -0,0 +1,228 @@
+//! Digital signal processing filters.
+//!
+//! Recursive filters, whose output depends on their previous output, seem inherently serial.
+//! The filters in this module process a vector of samples at a time instead, by splitting each
+//! output into two parts: the contribution of the state before the vector, which is decayed by a
+//! known factor for each lane, and the contribution of the samples within the vector, which is a
+//! weighted prefix sum computed in a logarithmic number of steps.
+
+use super::shift::ShiftUp;
+use crate::simd::{f32x16, LaneCount, Simd, SupportedLaneCount, Swizzle2};
+
+/// The number of samples filtered at once.
+const LANES: usize = f32x16::LANES;
+
+/// Writes the exponential moving average of `signal` to `output`.
+///
+/// Each output is `y[n] = y[n - 1] + alpha * (x[n] - y[n - 1])`, a first-order low-pass filter,
+/// starting from `y[0] = x[0]`.
+///
+/// Unrolling the recurrence over a vector of samples starting at `n`, lane `i` of the output is
+/// `decay^(i + 1) * y[n - 1] + alpha * (x[n + i] + decay * x[n + i - 1] + ... + decay^i * x[n])`,
+/// where `decay = 1 - alpha`.  The sums for every lane are computed at once, by adding the
+/// vector to itself shifted by 1, 2, 4 and 8 lanes and weighted by the matching power of
+/// `decay`.  The result may differ from the serial recurrence by rounding.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::dsp;
+/// let signal = [0.0, 8.0, 8.0, 8.0];
+/// let mut average = [0.0; 4];
+/// dsp::ema(&signal, 0.5, &mut average);
+/// assert_eq!(average, [0.0, 4.0, 6.0, 7.0]);
+/// ```
+#[inline]
+pub fn ema(signal: &[f32], alpha: f32, output: &mut [f32]) {
+    assert_eq!(
+        signal.len(),
+        output.len(),
+        "slices must have the same length"
+    );
+    let Some(&first) = signal.first() else {
+        return;
+    };
+
+    // Lane `i` is `decay^(i + 1)`
+    let decay = 1.0 - alpha;
+    let mut powers = [decay; LANES];
+    for i in 1..LANES {
+        powers[i] = powers[i - 1] * decay;
+    }
+    let powers = f32x16::from_array(powers);
+
+    let zero = f32x16::splat(0.0);
+    let filter = |x: f32x16, state: f32| {
+        let mut y = x * f32x16::splat(alpha);
+        y += ShiftUp::<1>::swizzle2(y, zero) * f32x16::splat(powers[0]);
+        y += ShiftUp::<2>::swizzle2(y, zero) * f32x16::splat(powers[1]);
+        y += ShiftUp::<4>::swizzle2(y, zero) * f32x16::splat(powers[3]);
+        y += ShiftUp::<8>::swizzle2(y, zero) * f32x16::splat(powers[7]);
+        y + powers * f32x16::splat(state)
+    };
+
+    let mut state = first;
+    let mut signal_chunks = signal.chunks_exact(LANES);
+    let mut output_chunks = output.chunks_exact_mut(LANES);
+    for (signal, output) in (&mut signal_chunks).zip(&mut output_chunks) {
+        let y = filter(f32x16::from_slice(signal), state);
+        output.copy_from_slice(y.as_array());
+        state = y[LANES - 1];
+    }
+
+    let signal = signal_chunks.remainder();
+    let output = output_chunks.into_remainder();
+    let y = filter(f32x16::load_or(signal, zero), state);
+    output.copy_from_slice(&y[..signal.len()]);
+}
+
+/// The coefficients of a biquad filter for each channel of [`biquad_multi`].
+///
+/// Each lane is a separate filter, with the transfer function
+/// `(b0 + b1 z^-1 + b2 z^-2) / (1 + a1 z^-1 + a2 z^-2)`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BiquadCoefficients<const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// The gain of the current input.
+    pub b0: Simd<f32, LANES>,
+    /// The gain of the previous input.
+    pub b1: Simd<f32, LANES>,
+    /// The gain of the input before the previous one.
+    pub b2: Simd<f32, LANES>,
+    /// The gain of the previous output, subtracted from the output.
+    pub a1: Simd<f32, LANES>,
+    /// The gain of the output before the previous one, subtracted from the output.
+    pub a2: Simd<f32, LANES>,
+}
+
+/// The state of each channel of [`biquad_multi`] between blocks of samples.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BiquadState<const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    z1: Simd<f32, LANES>,
+    z2: Simd<f32, LANES>,
+}
+
+impl<const LANES: usize> BiquadState<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates the state of channels that have only seen silence.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            z1: Simd::splat(0.0),
+            z2: Simd::splat(0.0),
+        }
+    }
+}
+
+impl<const LANES: usize> Default for BiquadState<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Filters `LANES` independent channels with a biquad filter each, in place.
+///
+/// Each element of `channels` is a frame holding one sample of every channel, so each channel is
+/// in a lane, and its filter's coefficients and state are in the same lane of `coeffs` and
+/// `state`.  Unlike filtering a single channel, no lane depends on another, so every channel
+/// is filtered at the cost of one.  The filter is in transposed direct form II, and `state` is
+/// updated so that the next block continues where this one ended.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{dsp::{self, BiquadCoefficients, BiquadState}, f32x2};
+/// // The first channel passes its input through, and the second delays it by one sample
+/// let coeffs = BiquadCoefficients {
+///     b0: f32x2::from_array([1.0, 0.0]),
+///     b1: f32x2::from_array([0.0, 1.0]),
+///     b2: f32x2::splat(0.0),
+///     a1: f32x2::splat(0.0),
+///     a2: f32x2::splat(0.0),
+/// };
+/// let mut state = BiquadState::new();
+/// let mut channels = [f32x2::splat(1.0), f32x2::splat(2.0), f32x2::splat(3.0)];
+/// dsp::biquad_multi(&mut channels, &coeffs, &mut state);
+/// assert_eq!(channels.map(f32x2::to_array), [[1.0, 0.0], [2.0, 1.0], [3.0, 2.0]]);
+/// ```
+#[inline]
+pub fn biquad_multi<const LANES: usize>(
+    channels: &mut [Simd<f32, LANES>],
+    coeffs: &BiquadCoefficients<LANES>,
+    state: &mut BiquadState<LANES>,
+) where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let BiquadCoefficients { b0, b1, b2, a1, a2 } = *coeffs;
+    let BiquadState { mut z1, mut z2 } = *state;
+    for frame in channels {
+        let x = *frame;
+        let y = b0 * x + z1;
+        z1 = b1 * x - a1 * y + z2;
+        z2 = b2 * x - a2 * y;
+        *frame = y;
+    }
+    *state = BiquadState { z1, z2 };
+}
+
+/// Returns the power of `signal` at several frequencies, one in each lane, with the Goertzel
+/// algorithm.
+///
+/// Each lane of `coeffs` is `2 * cos(2 * PI * f)` for a frequency `f` in cycles per sample, such
+/// as `k / signal.len()` for bin `k` of a DFT of the whole signal.  Each lane of the result is
+/// the squared magnitude of the DFT of `signal` at that frequency.
+///
+/// Every frequency is evaluated in parallel, in a single pass over the signal, which is cheaper
+/// than a full FFT when only a few frequencies are needed, such as for detecting DTMF tones.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{dsp, f32x4};
+/// use std::f32::consts::PI;
+///
+/// // A tone at bin 3 of 32
+/// let signal: Vec<f32> = (0..32).map(|n| (2.0 * PI * 3.0 * n as f32 / 32.0).cos()).collect();
+/// let coeffs = f32x4::from_array([2.0, 3.0, 4.0, 5.0].map(|k| 2.0 * (2.0 * PI * k / 32.0).cos()));
+/// let power = dsp::goertzel(&signal, coeffs);
+/// // The tone's bin has all of its power, 16 squared
+/// assert!((power[1] - 256.0).abs() < 0.01);
+/// assert!(power[0] < 0.01 && power[2] < 0.01 && power[3] < 0.01);
+/// ```
+#[must_use]
+#[inline]
+pub fn goertzel<const LANES: usize>(signal: &[f32], coeffs: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut s1 = Simd::splat(0.0);
+    let mut s2 = Simd::splat(0.0);
+    for x in signal {
+        let s = Simd::splat(*x) + coeffs * s1 - s2;
+        s2 = s1;
+        s1 = s;
+    }
+    s1 * s1 + s2 * s2 - coeffs * s1 * s2
+}
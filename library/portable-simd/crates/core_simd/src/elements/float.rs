@@ -87,6 +87,86 @@ pub trait SimdFloat: Copy + Sealed {
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn copysign(self, sign: Self) -> Self;
 
+    /// Negates each lane enabled by `mask`, leaving the other lanes unchanged.
+    ///
+    /// Only the sign bit is flipped, so zeros and `NAN`s are negated too.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, Mask, SimdFloat};
+    /// let x = f32x4::from_array([1.0, -2.0, 0.0, 4.0]);
+    /// let mask = Mask::from_array([true, true, true, false]);
+    /// assert_eq!(x.negate_where(mask).to_array(), [-1.0, 2.0, -0.0, 4.0]);
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn negate_where(self, mask: Self::Mask) -> Self;
+
+    /// Returns each lane with the magnitude of `self`, which is negative if `negative` is set
+    /// for the lane, and positive otherwise.
+    ///
+    /// This is equivalent to [`copysign`](SimdFloat::copysign), with the signs given by a mask.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, Mask, SimdFloat};
+    /// let x = f32x4::from_array([1.0, -2.0, 3.0, -4.0]);
+    /// let negative = Mask::from_array([true, true, false, false]);
+    /// assert_eq!(x.copysign_from_mask(negative).to_array(), [-1.0, -2.0, 3.0, 4.0]);
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn copysign_from_mask(self, negative: Self::Mask) -> Self;
+
+    /// Returns the least number greater than each lane.
+    ///
+    /// * `NAN` and `INFINITY` are returned unchanged
+    /// * `NEG_INFINITY` becomes `-MAX`
+    /// * `+0.0` and `-0.0` both become the smallest positive subnormal number
+    ///
+    /// Rounding the upper bound of a result with this ensures it is greater than the exact
+    /// result, whichever way the result was rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let x = f32x4::from_array([1.0, -0.0, f32::NEG_INFINITY, f32::INFINITY]);
+    /// assert_eq!(
+    ///     x.next_up().to_array(),
+    ///     [1.0 + f32::EPSILON, f32::from_bits(1), f32::MIN, f32::INFINITY]
+    /// );
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn next_up(self) -> Self;
+
+    /// Returns the greatest number less than each lane.
+    ///
+    /// This mirrors [`next_up`](SimdFloat::next_up): `NAN` and `NEG_INFINITY` are returned
+    /// unchanged, `INFINITY` becomes `MAX`, and both zeros become the negative subnormal number
+    /// closest to zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let x = f32x4::from_array([1.0, 0.0, f32::INFINITY, f32::NEG_INFINITY]);
+    /// assert_eq!(
+    ///     x.next_down().to_array(),
+    ///     [1.0 - f32::EPSILON / 2.0, -f32::from_bits(1), f32::MAX, f32::NEG_INFINITY]
+    /// );
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn next_down(self) -> Self;
+
     /// Returns the minimum of each lane.
     ///
     /// If one of the values is `NAN`, then the other value is returned.
@@ -107,6 +187,115 @@ pub trait SimdFloat: Copy + Sealed {
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn simd_clamp(self, min: Self, max: Self) -> Self;
 
+    /// Restricts each lane to the interval `[-1.0, 1.0]` unless it is NaN.
+    ///
+    /// This is the same as [`simd_clamp`](SimdFloat::simd_clamp) with splatted bounds, but a
+    /// vector filling an SSE register compiles to just a `max` and a `min` instruction on x86.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let x = f32x4::from_array([-3.0, 0.5, 2.0, f32::NAN]);
+    /// let clamped = x.clamp_unit().to_array();
+    /// assert_eq!(clamped[..3], [-1.0, 0.5, 1.0]);
+    /// assert!(clamped[3].is_nan());
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn clamp_unit(self) -> Self;
+
+    /// Restricts each lane to the interval `[-1.0, 1.0]`, replacing NaN with `0.0`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn clamp_unit_nan_to_zero(self) -> Self;
+
+    /// Restricts each lane to the interval `[0.0, 1.0]` unless it is NaN.
+    ///
+    /// This is the same as [`simd_clamp`](SimdFloat::simd_clamp) with splatted bounds, but a
+    /// vector filling an SSE register compiles to just a `max` and a `min` instruction on x86.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let x = f32x4::from_array([-3.0, 0.5, 2.0, f32::NAN]);
+    /// let saturated = x.saturate().to_array();
+    /// assert_eq!(saturated[..3], [0.0, 0.5, 1.0]);
+    /// assert!(saturated[3].is_nan());
+    ///
+    /// assert_eq!(x.saturate_nan_to_zero().to_array(), [0.0, 0.5, 1.0, 0.0]);
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn saturate(self) -> Self;
+
+    /// Restricts each lane to the interval `[0.0, 1.0]`, replacing NaN and `-0.0` with `0.0`.
+    ///
+    /// This is the `saturate` of shading languages.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn saturate_nan_to_zero(self) -> Self;
+
+    /// Adds each lane, allowing the compiler to assume the lanes and the results are finite and
+    /// to reassociate the addition with the operations around it.
+    ///
+    /// This sets LLVM's `fast` flags on the addition, so it may be combined with other fast
+    /// operations, such as into a fused multiply-add or a sum in a different order, and the sign
+    /// of a zero result may differ from `self + rhs`.
+    ///
+    /// Unlike compiling with fast-math options in C, this never flushes subnormal numbers to
+    /// zero.  That's a mode of the floating-point environment, such as the MXCSR register on
+    /// x86, rather than a property of an operation, and LLVM compiles all code assuming the
+    /// environment is left at its default, so changing it could change the results of unrelated
+    /// code compiled without fast-math.
+    ///
+    /// # Safety
+    ///
+    /// Every lane of `self`, `rhs` and the result must be finite: neither NaN nor infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let a = f32x4::from_array([1., 2., 3., 4.]);
+    /// let b = f32x4::splat(0.5);
+    /// // Safety: every lane is finite
+    /// let sum = unsafe { a.add_fast(b) };
+    /// assert_eq!(sum, f32x4::from_array([1.5, 2.5, 3.5, 4.5]));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    unsafe fn add_fast(self, rhs: Self) -> Self;
+
+    /// Subtracts each lane, allowing the same optimizations as [`add_fast`](Self::add_fast).
+    ///
+    /// # Safety
+    ///
+    /// Every lane of `self`, `rhs` and the result must be finite: neither NaN nor infinite.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    unsafe fn sub_fast(self, rhs: Self) -> Self;
+
+    /// Multiplies each lane, allowing the same optimizations as [`add_fast`](Self::add_fast).
+    ///
+    /// # Safety
+    ///
+    /// Every lane of `self`, `rhs` and the result must be finite: neither NaN nor infinite.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    unsafe fn mul_fast(self, rhs: Self) -> Self;
+
+    /// Divides each lane, allowing the same optimizations as [`add_fast`](Self::add_fast), as
+    /// well as multiplying by the reciprocal of `rhs` instead.
+    ///
+    /// # Safety
+    ///
+    /// Every lane of `self`, `rhs` and the result must be finite: neither NaN nor infinite.  So
+    /// no lane of `rhs` may be zero.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    unsafe fn div_fast(self, rhs: Self) -> Self;
+
     /// Returns the sum of the lanes of the vector.
     ///
     /// # Examples
@@ -135,6 +324,45 @@ pub trait SimdFloat: Copy + Sealed {
     /// ```
     fn reduce_product(self) -> Self::Scalar;
 
+    /// Reducing add, with the lanes added in an unspecified order.
+    ///
+    /// [`reduce_sum`](Self::reduce_sum) adds the lanes in order, which is a chain of dependent
+    /// additions as long as the vector.  This may reassociate the additions instead, such as
+    /// adding lanes pairwise in a tree, which takes a logarithmic number of steps.  The result
+    /// may differ from `reduce_sum` by rounding, and between targets or versions of the
+    /// standard library.  Unlike [`add_fast`](Self::add_fast), it doesn't assume the lanes are
+    /// finite: NaNs and infinities are propagated as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let v = f32x4::from_array([1., 2., 3., 4.]);
+    /// assert_eq!(v.reduce_sum_fast(), 10.);
+    /// ```
+    fn reduce_sum_fast(self) -> Self::Scalar;
+
+    /// Reducing multiply, with the lanes multiplied in an unspecified order.
+    ///
+    /// Like [`reduce_sum_fast`](Self::reduce_sum_fast), this may reassociate the
+    /// multiplications, so the result may differ from
+    /// [`reduce_product`](Self::reduce_product) by rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let v = f32x4::from_array([1., 2., 3., 4.]);
+    /// assert_eq!(v.reduce_product_fast(), 24.);
+    /// ```
+    fn reduce_product_fast(self) -> Self::Scalar;
+
     /// Returns the maximum lane in the vector.
     ///
     /// Returns values based on equality, so a vector containing both `0.` and `-0.` may
@@ -292,6 +520,36 @@ fn copysign(self, sign: Self) -> Self {
                 Self::from_bits(sign_bit | magnitude)
             }
 
+            #[inline]
+            fn negate_where(self, mask: Self::Mask) -> Self {
+                let sign_bit = mask.to_int().cast::<$bits_ty>() & Self::splat(-0.).to_bits();
+                Self::from_bits(self.to_bits() ^ sign_bit)
+            }
+
+            #[inline]
+            fn copysign_from_mask(self, negative: Self::Mask) -> Self {
+                let sign_bit = negative.to_int().cast::<$bits_ty>() & Self::splat(-0.).to_bits();
+                let magnitude = self.to_bits() & !Self::splat(-0.).to_bits();
+                Self::from_bits(sign_bit | magnitude)
+            }
+
+            #[inline]
+            fn next_up(self) -> Self {
+                let bits = self.to_bits();
+                let magnitude = bits & !Self::splat(-0.).to_bits();
+                // Stepping the bits away from zero increases positive numbers, and stepping them
+                // towards zero increases negative numbers
+                let next = self.is_sign_negative().select(bits - Simd::splat(1), bits + Simd::splat(1));
+                let next = magnitude.simd_eq(Simd::splat(0)).select(Simd::splat(1), next);
+                let unchanged = self.is_nan() | self.simd_eq(Self::splat(Self::Scalar::INFINITY));
+                unchanged.select(self, Self::from_bits(next))
+            }
+
+            #[inline]
+            fn next_down(self) -> Self {
+                -(-self).next_up()
+            }
+
             #[inline]
             fn simd_min(self, other: Self) -> Self {
                 // Safety: `self` and `other` are float vectors
@@ -316,6 +574,60 @@ fn simd_clamp(self, min: Self, max: Self) -> Self {
                 x
             }
 
+            #[inline]
+            fn clamp_unit(self) -> Self {
+                // As in `simd_clamp`, but the bounds are known to be ordered.  NaN fails both
+                // comparisons and is kept.
+                let x = self.simd_lt(Self::splat(-1.0)).select(Self::splat(-1.0), self);
+                x.simd_gt(Self::splat(1.0)).select(Self::splat(1.0), x)
+            }
+
+            #[inline]
+            fn clamp_unit_nan_to_zero(self) -> Self {
+                self.is_nan().select(Self::splat(0.0), self).clamp_unit()
+            }
+
+            #[inline]
+            fn saturate(self) -> Self {
+                let x = self.simd_lt(Self::splat(0.0)).select(Self::splat(0.0), self);
+                x.simd_gt(Self::splat(1.0)).select(Self::splat(1.0), x)
+            }
+
+            #[inline]
+            fn saturate_nan_to_zero(self) -> Self {
+                // NaN and `-0.0` aren't greater than zero
+                let x = self.simd_gt(Self::splat(0.0)).select(self, Self::splat(0.0));
+                x.simd_lt(Self::splat(1.0)).select(x, Self::splat(1.0))
+            }
+
+            #[inline]
+            unsafe fn add_fast(self, rhs: Self) -> Self {
+                // Safety: `self` and `rhs` are float vectors, and the caller ensures that they and
+                // the result are finite
+                unsafe { core::intrinsics::fadd_fast(self, rhs) }
+            }
+
+            #[inline]
+            unsafe fn sub_fast(self, rhs: Self) -> Self {
+                // Safety: `self` and `rhs` are float vectors, and the caller ensures that they and
+                // the result are finite
+                unsafe { core::intrinsics::fsub_fast(self, rhs) }
+            }
+
+            #[inline]
+            unsafe fn mul_fast(self, rhs: Self) -> Self {
+                // Safety: `self` and `rhs` are float vectors, and the caller ensures that they and
+                // the result are finite
+                unsafe { core::intrinsics::fmul_fast(self, rhs) }
+            }
+
+            #[inline]
+            unsafe fn div_fast(self, rhs: Self) -> Self {
+                // Safety: `self` and `rhs` are float vectors, and the caller ensures that they and
+                // the result are finite
+                unsafe { core::intrinsics::fdiv_fast(self, rhs) }
+            }
+
             #[inline]
             fn reduce_sum(self) -> Self::Scalar {
                 // LLVM sum is inaccurate on i586
@@ -338,6 +650,28 @@ fn reduce_product(self) -> Self::Scalar {
                 }
             }
 
+            #[inline]
+            fn reduce_sum_fast(self) -> Self::Scalar {
+                // LLVM sum is inaccurate on i586
+                if cfg!(all(target_arch = "x86", not(target_feature = "sse2"))) {
+                    self.as_array().iter().sum()
+                } else {
+                    // Safety: `self` is a float vector
+                    unsafe { intrinsics::simd_reduce_add_unordered(self) }
+                }
+            }
+
+            #[inline]
+            fn reduce_product_fast(self) -> Self::Scalar {
+                // LLVM product is inaccurate on i586
+                if cfg!(all(target_arch = "x86", not(target_feature = "sse2"))) {
+                    self.as_array().iter().product()
+                } else {
+                    // Safety: `self` is a float vector
+                    unsafe { intrinsics::simd_reduce_mul_unordered(self) }
+                }
+            }
+
             #[inline]
             fn reduce_max(self) -> Self::Scalar {
                 // Safety: `self` is a float vector
@@ -1,6 +1,7 @@
 use super::sealed::Sealed;
 use crate::simd::{
-    intrinsics, LaneCount, Mask, Simd, SimdElement, SimdPartialOrd, SupportedLaneCount,
+    intrinsics, LaneCount, Mask, Simd, SimdElement, SimdPartialEq, SimdPartialOrd, SimdUint,
+    SupportedLaneCount,
 };
 
 /// Operations on SIMD vectors of signed integers.
@@ -46,6 +47,58 @@ pub trait SimdInt: Copy + Sealed {
     /// assert_eq!(sat, Simd::from_array([MIN, MIN, MIN, 0]));
     fn saturating_sub(self, second: Self) -> Self;
 
+    /// Lanewise wrapping shift left.
+    ///
+    /// Each shift amount is masked to the width of a lane, as in `i32::wrapping_shl`, so shifting
+    /// by the width of a lane or more, or by a negative amount, shifts by the remainder.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::from_array([1i8, 1, 1, -1]);
+    /// let shifted = x.wrapping_shl(Simd::from_array([1, 7, 9, -1]));
+    /// assert_eq!(shifted, Simd::from_array([2, i8::MIN, 2, i8::MIN]));
+    /// ```
+    fn wrapping_shl(self, rhs: Self) -> Self;
+
+    /// Lanewise wrapping arithmetic shift right.
+    ///
+    /// Each shift amount is masked to the width of a lane, as in `i32::wrapping_shr`, so shifting
+    /// by the width of a lane or more, or by a negative amount, shifts by the remainder.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::from_array([-128i8, -128, 64, 64]);
+    /// let shifted = x.wrapping_shr(Simd::from_array([1, 7, 9, -1]));
+    /// assert_eq!(shifted, Simd::from_array([-64, -1, 32, 0]));
+    /// ```
+    fn wrapping_shr(self, rhs: Self) -> Self;
+
+    /// Lanewise saturating shift left.
+    ///
+    /// Lanes that would overflow, including nonzero lanes shifted by the width of a lane or
+    /// more, saturate to `MAX` if positive and `MIN` if negative.  Zero lanes remain zero.
+    /// Negative shift amounts are treated as too large.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::from_array([1i8, 1, -1, 0]);
+    /// let shifted = x.saturating_shl(Simd::from_array([6, 7, 8, -1]));
+    /// assert_eq!(shifted, Simd::from_array([64, i8::MAX, i8::MIN, 0]));
+    /// ```
+    fn saturating_shl(self, rhs: Self) -> Self;
+
     /// Lanewise absolute value, implemented in Rust.
     /// Every lane becomes its absolute value.
     ///
@@ -109,6 +162,105 @@ pub trait SimdInt: Copy + Sealed {
     /// * `-1` if the number is negative
     fn signum(self) -> Self;
 
+    /// Returns the number of leading zeros in the binary representation of each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::from_array([-1i16, 0, 1, i16::MAX]);
+    /// assert_eq!(x.leading_zeros(), Simd::from_array([0, 16, 15, 1]));
+    /// ```
+    fn leading_zeros(self) -> Self;
+
+    /// Returns the base 2 logarithm of each lane, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any lane is zero or negative.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::from_array([1i32, 2, 1000, i32::MAX]);
+    /// assert_eq!(x.ilog2(), Simd::from_array([0, 1, 9, 30]));
+    /// ```
+    fn ilog2(self) -> Self;
+
+    /// Returns the base 10 logarithm of each lane, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any lane is zero or negative.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::from_array([1i32, 9, 10, i32::MAX]);
+    /// assert_eq!(x.ilog10(), Simd::from_array([0, 0, 1, 9]));
+    /// ```
+    fn ilog10(self) -> Self;
+
+    /// Reverses the order of the bytes in each lane, like `swap_bytes` on integers.
+    ///
+    /// This converts each lane between big-endian and little-endian, keeping the order of the
+    /// lanes.  See also [`reverse_bytes_whole`](Self::reverse_bytes_whole), which also reverses
+    /// the order of the lanes.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::from_array([0x0102i16, -2]);
+    /// assert_eq!(x.reverse_bytes_in_lanes(), Simd::from_array([0x0201, 0xfeffu16 as i16]));
+    /// ```
+    fn reverse_bytes_in_lanes(self) -> Self;
+
+    /// Reverses the order of the bits in each byte, keeping the order of the bytes.
+    ///
+    /// Some wire formats and CRC variants transmit the least significant bit of each byte
+    /// first, and this converts between the two bit orders.  Reversing the bits of whole lanes,
+    /// like `reverse_bits` on integers, is this combined with
+    /// [`reverse_bytes_in_lanes`](Self::reverse_bytes_in_lanes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::from_array([0x0180i16, -2]);
+    /// assert_eq!(x.reverse_bits_in_bytes(), Simd::from_array([0x8001u16 as i16, 0xff7fu16 as i16]));
+    /// ```
+    fn reverse_bits_in_bytes(self) -> Self;
+
+    /// Reverses the order of the bytes of the whole vector, rather than of each lane.
+    ///
+    /// This reverses the order of the lanes, and the order of the bytes in each lane, so the
+    /// memory representation of the result is that of `self` reversed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::from_array([0x0102i16, -2]);
+    /// let reversed = Simd::from_array([0xfeffu16 as i16, 0x0201]);
+    /// assert_eq!(x.reverse_bytes_whole(), reversed);
+    /// ```
+    fn reverse_bytes_whole(self) -> Self;
+
     /// Returns the sum of the lanes of the vector, with wrapping addition.
     ///
     /// # Examples
@@ -181,10 +333,48 @@ pub trait SimdInt: Copy + Sealed {
 
     /// Returns the cumulative bitwise "xor" across the lanes of the vector.
     fn reduce_xor(self) -> Self::Scalar;
+
+    /// Returns true if every bit of the vector is zero.
+    ///
+    /// This tests the whole vector at once, without creating a mask, so it compiles to a single
+    /// test instruction where the target has one, such as `ptest` with SSE4.1.  It is intended
+    /// for fast paths that skip work when a vector has nothing of interest.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// assert!(Simd::from_array([0i32, 0, 0, 0]).all_zeros());
+    /// assert!(!Simd::from_array([0i32, 0, 0, 8]).all_zeros());
+    /// ```
+    fn all_zeros(self) -> bool;
+
+    /// Returns true if any bit of the vector is set.
+    ///
+    /// This is the opposite of [`all_zeros`](Self::all_zeros).
+    fn any_nonzero(self) -> bool;
+
+    /// Returns true if every bit of the vector is set.
+    ///
+    /// Like [`all_zeros`](Self::all_zeros), this tests the whole vector at once, without
+    /// creating a mask.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// assert!(Simd::from_array([-1i32; 4]).all_bits_set());
+    /// assert!(!Simd::from_array([-1i32, -1, -1, 7]).all_bits_set());
+    /// ```
+    fn all_bits_set(self) -> bool;
 }
 
 macro_rules! impl_trait {
-    { $($ty:ty),* } => {
+    { $($ty:ty { unsigned: $unsigned:ty }),* } => {
         $(
         impl<const LANES: usize> Sealed for Simd<$ty, LANES>
         where
@@ -211,6 +401,30 @@ fn saturating_sub(self, second: Self) -> Self {
                 unsafe { intrinsics::simd_saturating_sub(self, second) }
             }
 
+            #[inline]
+            fn wrapping_shl(self, rhs: Self) -> Self {
+                // The shift operators already mask the shift amount
+                self << rhs
+            }
+
+            #[inline]
+            fn wrapping_shr(self, rhs: Self) -> Self {
+                self >> rhs
+            }
+
+            #[inline]
+            fn saturating_shl(self, rhs: Self) -> Self {
+                // A shift doesn't overflow if shifting back restores the original value
+                let shifted = self << rhs;
+                let bits = Simd::splat(<$ty>::BITS as $unsigned);
+                let in_range = rhs.cast::<$unsigned>().simd_lt(bits);
+                let exact = in_range & (shifted >> rhs).simd_eq(self);
+                let saturated = self
+                    .is_negative()
+                    .select(Simd::splat(<$ty>::MIN), Simd::splat(<$ty>::MAX));
+                (exact | self.simd_eq(Simd::splat(0))).select(shifted, saturated)
+            }
+
             #[inline]
             fn abs(self) -> Self {
                 const SHR: $ty = <$ty>::BITS as $ty - 1;
@@ -249,6 +463,44 @@ fn signum(self) -> Self {
                 )
             }
 
+            #[inline]
+            fn leading_zeros(self) -> Self {
+                self.cast::<$unsigned>().leading_zeros().cast()
+            }
+
+            #[inline]
+            fn ilog2(self) -> Self {
+                assert!(
+                    self.is_positive().all(),
+                    "argument of integer logarithm must be positive",
+                );
+                self.cast::<$unsigned>().ilog2().cast()
+            }
+
+            #[inline]
+            fn ilog10(self) -> Self {
+                assert!(
+                    self.is_positive().all(),
+                    "argument of integer logarithm must be positive",
+                );
+                self.cast::<$unsigned>().ilog10().cast()
+            }
+
+            #[inline]
+            fn reverse_bytes_in_lanes(self) -> Self {
+                self.cast::<$unsigned>().reverse_bytes_in_lanes().cast()
+            }
+
+            #[inline]
+            fn reverse_bits_in_bytes(self) -> Self {
+                self.cast::<$unsigned>().reverse_bits_in_bytes().cast()
+            }
+
+            #[inline]
+            fn reverse_bytes_whole(self) -> Self {
+                self.cast::<$unsigned>().reverse_bytes_whole().cast()
+            }
+
             #[inline]
             fn reduce_sum(self) -> Self::Scalar {
                 // Safety: `self` is an integer vector
@@ -290,9 +542,32 @@ fn reduce_xor(self) -> Self::Scalar {
                 // Safety: `self` is an integer vector
                 unsafe { intrinsics::simd_reduce_xor(self) }
             }
+
+            #[inline]
+            fn all_zeros(self) -> bool {
+                // Backends recognize an "or" reduction compared to zero as a test of the whole
+                // vector
+                self.reduce_or() == 0
+            }
+
+            #[inline]
+            fn any_nonzero(self) -> bool {
+                !self.all_zeros()
+            }
+
+            #[inline]
+            fn all_bits_set(self) -> bool {
+                self.reduce_and() == !0
+            }
         }
         )*
     }
 }
 
-impl_trait! { i8, i16, i32, i64, isize }
+impl_trait! {
+    i8 { unsigned: u8 },
+    i16 { unsigned: u16 },
+    i32 { unsigned: u32 },
+    i64 { unsigned: u64 },
+    isize { unsigned: usize }
+}
@@ -1,8 +1,14 @@
 use super::sealed::Sealed;
-use crate::simd::{intrinsics, LaneCount, Simd, SupportedLaneCount};
+use crate::simd::{
+    intrinsics, LaneCount, Mask, Simd, SimdElement, SimdPartialEq, SimdPartialOrd,
+    SupportedLaneCount,
+};
 
 /// Operations on SIMD vectors of unsigned integers.
 pub trait SimdUint: Copy + Sealed {
+    /// Mask type used for manipulating this SIMD vector type.
+    type Mask;
+
     /// Scalar type contained by this SIMD vector type.
     type Scalar;
 
@@ -41,6 +47,257 @@ pub trait SimdUint: Copy + Sealed {
     /// assert_eq!(sat, Simd::splat(0));
     fn saturating_sub(self, second: Self) -> Self;
 
+    /// Lanewise wrapping shift left.
+    ///
+    /// Each shift amount is masked to the width of a lane, as in `u32::wrapping_shl`, so shifting
+    /// by the width of a lane or more shifts by the remainder.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([1u8, 1, 1, 0x81]);
+    /// let shifted = x.wrapping_shl(Simd::from_array([1, 7, 9, 1]));
+    /// assert_eq!(shifted, Simd::from_array([2, 0x80, 2, 2]));
+    /// ```
+    fn wrapping_shl(self, rhs: Self) -> Self;
+
+    /// Lanewise wrapping shift right.
+    ///
+    /// Each shift amount is masked to the width of a lane, as in `u32::wrapping_shr`, so shifting
+    /// by the width of a lane or more shifts by the remainder.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0x80u8, 0x80, 0x80, 0x80]);
+    /// let shifted = x.wrapping_shr(Simd::from_array([1, 7, 9, 8]));
+    /// assert_eq!(shifted, Simd::from_array([0x40, 1, 0x40, 0x80]));
+    /// ```
+    fn wrapping_shr(self, rhs: Self) -> Self;
+
+    /// Lanewise saturating shift left.
+    ///
+    /// Lanes that would lose a set bit, including nonzero lanes shifted by the width of a lane
+    /// or more, saturate to all ones.  Zero lanes remain zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([1u8, 1, 0, 0x41]);
+    /// let shifted = x.saturating_shl(Simd::from_array([7, 8, 200, 2]));
+    /// assert_eq!(shifted, Simd::from_array([0x80, u8::MAX, 0, u8::MAX]));
+    /// ```
+    fn saturating_shl(self, rhs: Self) -> Self;
+
+    /// Returns the number of ones in the binary representation of each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0u16, 1, 0x00ff, u16::MAX]);
+    /// assert_eq!(x.count_ones(), Simd::from_array([0, 1, 8, 16]));
+    /// ```
+    fn count_ones(self) -> Self;
+
+    /// Returns the number of leading zeros in the binary representation of each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0u16, 1, 0x00ff, u16::MAX]);
+    /// assert_eq!(x.leading_zeros(), Simd::from_array([16, 15, 8, 0]));
+    /// ```
+    fn leading_zeros(self) -> Self;
+
+    /// Returns the base 2 logarithm of each lane, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any lane is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([1u32, 2, 1000, u32::MAX]);
+    /// assert_eq!(x.ilog2(), Simd::from_array([0, 1, 9, 31]));
+    /// ```
+    fn ilog2(self) -> Self;
+
+    /// Returns the base 10 logarithm of each lane, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any lane is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([1u32, 9, 10, u32::MAX]);
+    /// assert_eq!(x.ilog10(), Simd::from_array([0, 0, 1, 9]));
+    /// ```
+    fn ilog10(self) -> Self;
+
+    /// Returns the smallest power of two greater than or equal to each lane.
+    ///
+    /// Lanes whose next power of two is greater than the maximum value of the lane type wrap to
+    /// zero, like other SIMD integer arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0u8, 3, 64, 200]);
+    /// assert_eq!(x.next_power_of_two(), Simd::from_array([1, 4, 64, 0]));
+    /// ```
+    fn next_power_of_two(self) -> Self;
+
+    /// Returns the smallest power of two greater than or equal to each lane, along with a mask
+    /// of the lanes where that power of two would overflow the lane type.
+    ///
+    /// Overflowing lanes are zero in the returned vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0u8, 3, 128, 200]);
+    /// let (pow, overflow) = x.checked_next_power_of_two();
+    /// assert_eq!(pow, Simd::from_array([1, 4, 128, 0]));
+    /// assert_eq!(overflow.to_array(), [false, false, false, true]);
+    /// ```
+    fn checked_next_power_of_two(self) -> (Self, Self::Mask);
+
+    /// Returns true for each lane that is a power of two.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0u32, 1, 6, 1 << 31]);
+    /// assert_eq!(x.is_power_of_two().to_array(), [false, true, false, true]);
+    /// ```
+    fn is_power_of_two(self) -> Self::Mask;
+
+    /// Deposits the low bits of each lane into the positions of the set bits of `mask`,
+    /// from lowest to highest, clearing every other bit.
+    ///
+    /// This is the lane-wise equivalent of the x86 BMI2 `pdep` instruction, which is used for
+    /// each lane if the `bmi2` target feature is enabled when the standard library is built, such
+    /// as with `-Zbuild-std`.  The standard library distributed with Rust doesn't enable it, so
+    /// its lanes are computed with a loop over the set bits of `mask`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0b101u32, 0b111, u32::MAX, 0]);
+    /// let mask = Simd::from_array([0b11010, 0b1000_0001, 0xf0f0, u32::MAX]);
+    /// assert_eq!(x.bit_deposit(mask), Simd::from_array([0b10010, 0b1000_0001, 0xf0f0, 0]));
+    /// ```
+    fn bit_deposit(self, mask: Self) -> Self;
+
+    /// Extracts the bits of each lane at the positions of the set bits of `mask`,
+    /// packing them into the low bits of the result.
+    ///
+    /// This is the lane-wise equivalent of the x86 BMI2 `pext` instruction, which is used for
+    /// each lane if the `bmi2` target feature is enabled when the standard library is built, such
+    /// as with `-Zbuild-std`.  The standard library distributed with Rust doesn't enable it, so
+    /// its lanes are computed with a loop over the set bits of `mask`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0b10010u32, 0b1000_0001, u32::MAX, 0]);
+    /// let mask = Simd::from_array([0b11010, 0b1000_0001, 0xf0f0, u32::MAX]);
+    /// assert_eq!(x.bit_extract(mask), Simd::from_array([0b101, 0b11, 0xff, 0]));
+    /// ```
+    fn bit_extract(self, mask: Self) -> Self;
+
+    /// Reverses the order of the bytes in each lane, like `swap_bytes` on integers.
+    ///
+    /// This converts each lane between big-endian and little-endian, keeping the order of the
+    /// lanes.  See also [`reverse_bytes_whole`](Self::reverse_bytes_whole), which also reverses
+    /// the order of the lanes.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0x0102u16, 0x0304, 0x00ff, 0]);
+    /// assert_eq!(x.reverse_bytes_in_lanes(), Simd::from_array([0x0201, 0x0403, 0xff00, 0]));
+    /// ```
+    fn reverse_bytes_in_lanes(self) -> Self;
+
+    /// Reverses the order of the bits in each byte, keeping the order of the bytes.
+    ///
+    /// Some wire formats and CRC variants transmit the least significant bit of each byte
+    /// first, and this converts between the two bit orders.  Reversing the bits of whole lanes,
+    /// like `reverse_bits` on integers, is this combined with
+    /// [`reverse_bytes_in_lanes`](Self::reverse_bytes_in_lanes).
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0x0180u16, 0x0f01, 0x00ff, 0]);
+    /// assert_eq!(x.reverse_bits_in_bytes(), Simd::from_array([0x8001, 0xf080, 0x00ff, 0]));
+    /// ```
+    fn reverse_bits_in_bytes(self) -> Self;
+
+    /// Reverses the order of the bytes of the whole vector, rather than of each lane.
+    ///
+    /// This reverses the order of the lanes, and the order of the bytes in each lane, so the
+    /// memory representation of the result is that of `self` reversed.  It converts a vector
+    /// loaded from a big-endian stream to native order on little-endian targets, and the reverse.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::from_array([0x0102u16, 0x0304, 0x0506, 0x0708]);
+    /// let reversed = Simd::from_array([0x0807, 0x0605, 0x0403, 0x0201]);
+    /// assert_eq!(x.reverse_bytes_whole(), reversed);
+    /// ```
+    fn reverse_bytes_whole(self) -> Self;
+
     /// Returns the sum of the lanes of the vector, with wrapping addition.
     fn reduce_sum(self) -> Self::Scalar;
 
@@ -61,6 +318,63 @@ pub trait SimdUint: Copy + Sealed {
 
     /// Returns the cumulative bitwise "xor" across the lanes of the vector.
     fn reduce_xor(self) -> Self::Scalar;
+
+    /// Returns true if every bit of the vector is zero.
+    ///
+    /// This tests the whole vector at once, without creating a mask, so it compiles to a single
+    /// test instruction where the target has one, such as `ptest` with SSE4.1.  It is intended
+    /// for fast paths that skip work when a vector has nothing of interest.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// assert!(Simd::from_array([0u32, 0, 0, 0]).all_zeros());
+    /// assert!(!Simd::from_array([0u32, 0, 0, 8]).all_zeros());
+    /// ```
+    fn all_zeros(self) -> bool;
+
+    /// Returns true if any bit of the vector is set.
+    ///
+    /// This is the opposite of [`all_zeros`](Self::all_zeros).
+    fn any_nonzero(self) -> bool;
+
+    /// Returns true if every bit of the vector is set.
+    ///
+    /// Like [`all_zeros`](Self::all_zeros), this tests the whole vector at once, without
+    /// creating a mask.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// assert!(Simd::from_array([u32::MAX; 4]).all_bits_set());
+    /// assert!(!Simd::from_array([u32::MAX, u32::MAX, u32::MAX, 7]).all_bits_set());
+    /// ```
+    fn all_bits_set(self) -> bool;
+}
+
+// Target features are fixed when the standard library is built, so this is only used by
+// standard libraries built with `bmi2` enabled, such as with `-Zbuild-std`
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+mod bmi2 {
+    use core::arch::x86_64::{_pdep_u64, _pext_u64};
+
+    #[inline]
+    pub(super) fn deposit(x: u64, mask: u64) -> u64 {
+        // Safety: the `bmi2` target feature is enabled
+        unsafe { _pdep_u64(x, mask) }
+    }
+
+    #[inline]
+    pub(super) fn extract(x: u64, mask: u64) -> u64 {
+        // Safety: the `bmi2` target feature is enabled
+        unsafe { _pext_u64(x, mask) }
+    }
 }
 
 macro_rules! impl_trait {
@@ -76,6 +390,7 @@ impl<const LANES: usize> SimdUint for Simd<$ty, LANES>
         where
             LaneCount<LANES>: SupportedLaneCount,
         {
+            type Mask = Mask<<$ty as SimdElement>::Mask, LANES>;
             type Scalar = $ty;
 
             #[inline]
@@ -90,6 +405,202 @@ fn saturating_sub(self, second: Self) -> Self {
                 unsafe { intrinsics::simd_saturating_sub(self, second) }
             }
 
+            #[inline]
+            fn wrapping_shl(self, rhs: Self) -> Self {
+                // The shift operators already mask the shift amount
+                self << rhs
+            }
+
+            #[inline]
+            fn wrapping_shr(self, rhs: Self) -> Self {
+                self >> rhs
+            }
+
+            #[inline]
+            fn saturating_shl(self, rhs: Self) -> Self {
+                // A shift loses no bits if shifting back restores the original value
+                let shifted = self << rhs;
+                let in_range = rhs.simd_lt(Simd::splat(<$ty>::BITS as $ty));
+                let exact = in_range & (shifted >> rhs).simd_eq(self);
+                (exact | self.simd_eq(Simd::splat(0))).select(shifted, Simd::splat(<$ty>::MAX))
+            }
+
+            #[inline]
+            fn count_ones(self) -> Self {
+                // SWAR population count: sum adjacent bits, then pairs, then nibbles,
+                // then add up the bytes with a multiply.
+                let x = self;
+                let x = x - ((x >> Simd::splat(1)) & Simd::splat(<$ty>::MAX / 3));
+                let x = (x & Simd::splat(<$ty>::MAX / 5))
+                    + ((x >> Simd::splat(2)) & Simd::splat(<$ty>::MAX / 5));
+                let x = (x + (x >> Simd::splat(4))) & Simd::splat(<$ty>::MAX / 17);
+                (x * Simd::splat(<$ty>::MAX / 255)) >> Simd::splat(<$ty>::BITS as $ty - 8)
+            }
+
+            #[inline]
+            fn leading_zeros(self) -> Self {
+                // Smear the highest set bit into every bit below it,
+                // then the leading zeros are exactly the bits that remain clear.
+                let mut x = self;
+                let mut shift = 1;
+                while shift < <$ty>::BITS {
+                    x |= x >> Simd::splat(shift as $ty);
+                    shift *= 2;
+                }
+                (!x).count_ones()
+            }
+
+            #[inline]
+            fn ilog2(self) -> Self {
+                assert!(
+                    self.simd_ne(Self::splat(0)).all(),
+                    "argument of integer logarithm must be positive",
+                );
+                Self::splat(<$ty>::BITS as $ty - 1) - self.leading_zeros()
+            }
+
+            #[inline]
+            fn ilog10(self) -> Self {
+                // Powers of ten, saturating once they no longer fit in the lane type
+                const POW10: [$ty; 20] = {
+                    let mut table = [<$ty>::MAX; 20];
+                    let mut power: $ty = 1;
+                    let mut i = 0;
+                    while i < table.len() {
+                        table[i] = power;
+                        power = match power.checked_mul(10) {
+                            Some(power) => power,
+                            None => break,
+                        };
+                        i += 1;
+                    }
+                    table
+                };
+
+                // log10(2) is approximately 1233 / 4096, which gives either the right answer
+                // or one too many for every lane width.
+                // Compute in u32 so the multiply can't overflow narrow lanes.
+                let log2 = self.ilog2().cast::<u32>();
+                let guess = ((log2 + Simd::splat(1)) * Simd::splat(1233)) >> Simd::splat(12);
+                let guess_pow = Self::gather_or_default(&POW10, guess.cast());
+                let guess = guess.cast::<$ty>();
+                self.simd_lt(guess_pow).select(guess - Self::splat(1), guess)
+            }
+
+            #[inline]
+            fn next_power_of_two(self) -> Self {
+                // Smear the highest set bit of `self - 1` into every bit below it,
+                // which leaves one less than the next power of two.
+                let mut x = self.saturating_sub(Self::splat(1));
+                let mut shift = 1;
+                while shift < <$ty>::BITS {
+                    x |= x >> Simd::splat(shift as $ty);
+                    shift *= 2;
+                }
+                x + Self::splat(1)
+            }
+
+            #[inline]
+            fn checked_next_power_of_two(self) -> (Self, Self::Mask) {
+                const HIGHEST_POWER: $ty = 1 << (<$ty>::BITS - 1);
+                (self.next_power_of_two(), self.simd_gt(Self::splat(HIGHEST_POWER)))
+            }
+
+            #[inline]
+            fn is_power_of_two(self) -> Self::Mask {
+                let zero = Self::splat(0);
+                self.simd_ne(zero) & (self & (self - Self::splat(1))).simd_eq(zero)
+            }
+
+            #[inline]
+            fn bit_deposit(self, mask: Self) -> Self {
+                #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+                {
+                    let mut x = self.to_array();
+                    for (x, mask) in x.iter_mut().zip(mask.to_array()) {
+                        *x = bmi2::deposit(*x as u64, mask as u64) as $ty;
+                    }
+                    Self::from_array(x)
+                }
+
+                // Walk the set bits of the mask from lowest to highest,
+                // consuming one bit of `self` for each.
+                #[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+                {
+                    let zero = Self::splat(0);
+                    let mut mask = mask;
+                    let mut bit = Self::splat(1);
+                    let mut deposited = zero;
+                    while mask.simd_ne(zero).any() {
+                        let lowest = mask & (zero - mask);
+                        deposited |= (self & bit).simd_ne(zero).select(lowest, zero);
+                        mask ^= lowest;
+                        bit <<= Self::splat(1);
+                    }
+                    deposited
+                }
+            }
+
+            #[inline]
+            fn bit_extract(self, mask: Self) -> Self {
+                #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+                {
+                    let mut x = self.to_array();
+                    for (x, mask) in x.iter_mut().zip(mask.to_array()) {
+                        *x = bmi2::extract(*x as u64, mask as u64) as $ty;
+                    }
+                    Self::from_array(x)
+                }
+
+                // Walk the set bits of the mask from lowest to highest,
+                // producing one bit of the result for each.
+                #[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+                {
+                    let zero = Self::splat(0);
+                    let mut mask = mask;
+                    let mut bit = Self::splat(1);
+                    let mut extracted = zero;
+                    while mask.simd_ne(zero).any() {
+                        let lowest = mask & (zero - mask);
+                        extracted |= (self & lowest).simd_ne(zero).select(bit, zero);
+                        mask ^= lowest;
+                        bit <<= Self::splat(1);
+                    }
+                    extracted
+                }
+            }
+
+            #[inline]
+            fn reverse_bytes_in_lanes(self) -> Self {
+                // Swap adjacent bytes, then adjacent pairs of bytes, and so on
+                let mut x = self;
+                let mut shift = 8;
+                while shift < <$ty>::BITS {
+                    let low = Simd::splat(<$ty>::MAX / ((1 << shift) + 1));
+                    let shift_by = Simd::splat(shift as $ty);
+                    x = ((x >> shift_by) & low) | ((x & low) << shift_by);
+                    shift *= 2;
+                }
+                x
+            }
+
+            #[inline]
+            fn reverse_bits_in_bytes(self) -> Self {
+                // Swap the nibbles of each byte, then adjacent pairs of bits, then adjacent bits
+                let mut x = self;
+                for (shift, low) in [(4, 0x0f), (2, 0x33), (1, 0x55)] {
+                    let low = Simd::splat(<$ty>::MAX / 0xff * low);
+                    let shift_by = Simd::splat(shift);
+                    x = ((x >> shift_by) & low) | ((x & low) << shift_by);
+                }
+                x
+            }
+
+            #[inline]
+            fn reverse_bytes_whole(self) -> Self {
+                self.reverse().reverse_bytes_in_lanes()
+            }
+
             #[inline]
             fn reduce_sum(self) -> Self::Scalar {
                 // Safety: `self` is an integer vector
@@ -131,6 +642,23 @@ fn reduce_xor(self) -> Self::Scalar {
                 // Safety: `self` is an integer vector
                 unsafe { intrinsics::simd_reduce_xor(self) }
             }
+
+            #[inline]
+            fn all_zeros(self) -> bool {
+                // Backends recognize an "or" reduction compared to zero as a test of the whole
+                // vector
+                self.reduce_or() == 0
+            }
+
+            #[inline]
+            fn any_nonzero(self) -> bool {
+                !self.all_zeros()
+            }
+
+            #[inline]
+            fn all_bits_set(self) -> bool {
+                self.reduce_and() == !0
+            }
         }
         )*
     }
@@ -0,0 +1,180 @@
+//! Butterflies for fast Fourier transforms.
+//!
+//! Complex numbers are stored interleaved, with the real part of each number in an even lane
+//! and the imaginary part in the following odd lane, so a `Simd<f32, N>` holds `N / 2` complex
+//! numbers, and `N` must be at least 2.  Each butterfly operates on every number in its input
+//! vectors independently, so a transform can be vectorized over several butterflies of the same
+//! stage, or over several transforms at once.
+//!
+//! The butterflies use decimation in time, with twiddle factors applied to their inputs, and
+//! compute forward transforms, with twiddle factors `exp(-2 * PI * i * k / n)`.  Inverse
+//! transforms can conjugate their twiddle factors, and swap the second and fourth outputs of
+//! [`butterfly4`].
+
+use crate::simd::{LaneCount, Simd, SupportedLaneCount, Swizzle};
+
+/// Copies the real part of each complex number to its imaginary part.
+struct DuplicateReal;
+
+impl<const N: usize> Swizzle<N, N> for DuplicateReal {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = i & !1;
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Copies the imaginary part of each complex number to its real part.
+struct DuplicateImaginary;
+
+impl<const N: usize> Swizzle<N, N> for DuplicateImaginary {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = i | 1;
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Swaps the real and imaginary parts of each complex number.
+struct SwapParts;
+
+impl<const N: usize> Swizzle<N, N> for SwapParts {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = i ^ 1;
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Returns `-1` in the real lanes and `1` in the imaginary lanes.
+#[inline]
+fn negate_real<const N: usize>() -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let mut signs = [1.0; N];
+    for sign in signs.iter_mut().step_by(2) {
+        *sign = -1.0;
+    }
+    Simd::from_array(signs)
+}
+
+/// Returns the product of each complex number in `a` and `b`.
+///
+/// The real parts of `a` are multiplied by `b`, and the imaginary parts of `a` by `b` with its
+/// parts swapped, so the product only needs shuffles, multiplications and additions.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{fft, f32x4};
+/// // (1 + 2i)(3 + 4i) and i * i
+/// let a = f32x4::from_array([1.0, 2.0, 0.0, 1.0]);
+/// let b = f32x4::from_array([3.0, 4.0, 0.0, 1.0]);
+/// assert_eq!(fft::complex_mul(a, b).to_array(), [-5.0, 10.0, -1.0, 0.0]);
+/// ```
+#[must_use]
+#[inline]
+pub fn complex_mul<const N: usize>(a: Simd<f32, N>, b: Simd<f32, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let real = DuplicateReal::swizzle(a);
+    let imaginary = DuplicateImaginary::swizzle(a);
+    real * b + imaginary * SwapParts::swizzle(b) * negate_real()
+}
+
+/// Returns each complex number in `x` multiplied by `-i`.
+#[inline]
+fn mul_neg_i<const N: usize>(x: Simd<f32, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    -SwapParts::swizzle(x) * negate_real()
+}
+
+/// Returns the radix-2 butterfly of `x`, after multiplying `x[1]` by `twiddle`.
+///
+/// The outputs are `x[0] + twiddle * x[1]` and `x[0] - twiddle * x[1]`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{fft, f32x2};
+/// let x = [f32x2::from_array([1.0, 2.0]), f32x2::from_array([3.0, 4.0])];
+/// // Multiplying by i
+/// let [sum, difference] = fft::butterfly2(x, f32x2::from_array([0.0, 1.0]));
+/// assert_eq!(sum.to_array(), [-3.0, 5.0]);
+/// assert_eq!(difference.to_array(), [5.0, -1.0]);
+/// ```
+#[must_use]
+#[inline]
+pub fn butterfly2<const N: usize>(x: [Simd<f32, N>; 2], twiddle: Simd<f32, N>) -> [Simd<f32, N>; 2]
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let [x0, x1] = x;
+    let x1 = complex_mul(x1, twiddle);
+    [x0 + x1, x0 - x1]
+}
+
+/// Returns the radix-4 butterfly of `x`, after multiplying `x[1]`, `x[2]` and `x[3]` by
+/// `twiddles`.
+///
+/// Output `k` is `x[0] + (-i)^k * x[1] + (-1)^k * x[2] + i^k * x[3]`, the 4-point DFT of the
+/// twiddled inputs.  Multiplying by `i` and `-i` only swaps and negates parts, so a radix-4
+/// butterfly needs fewer multiplications than the four radix-2 butterflies it replaces.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{fft, f32x2};
+/// // The DFT of [1, i, -1, -i], a single cycle, without twiddling
+/// let one = f32x2::from_array([1.0, 0.0]);
+/// let x = [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]].map(f32x2::from_array);
+/// let y = fft::butterfly4(x, [one; 3]);
+/// assert_eq!(y.map(f32x2::to_array), [[0.0, 0.0], [4.0, 0.0], [0.0, 0.0], [0.0, 0.0]]);
+/// ```
+#[must_use]
+#[inline]
+pub fn butterfly4<const N: usize>(
+    x: [Simd<f32, N>; 4],
+    twiddles: [Simd<f32, N>; 3],
+) -> [Simd<f32, N>; 4]
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let [x0, x1, x2, x3] = x;
+    let x1 = complex_mul(x1, twiddles[0]);
+    let x2 = complex_mul(x2, twiddles[1]);
+    let x3 = complex_mul(x3, twiddles[2]);
+
+    let even_sum = x0 + x2;
+    let even_difference = x0 - x2;
+    let odd_sum = x1 + x3;
+    let odd_difference = mul_neg_i(x1 - x3);
+    [
+        even_sum + odd_sum,
+        even_difference + odd_difference,
+        even_sum - odd_sum,
+        even_difference - odd_difference,
+    ]
+}
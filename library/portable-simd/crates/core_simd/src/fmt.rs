@@ -1,4 +1,6 @@
-use crate::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+//! Formatting of vectors, and vectorized formatting of integers.
+
+use crate::simd::{u32x8, LaneCount, Simd, SimdElement, SupportedLaneCount};
 use core::fmt;
 
 macro_rules! impl_fmt_trait {
@@ -37,3 +39,152 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     LowerHex,
     UpperHex,
 }
+
+/// The largest number of decimal digits in a `u64`.
+const U64_DIGITS: usize = 20;
+
+/// Converts `value` to 20 ASCII decimal digits, padded with leading zeros.
+///
+/// The value is split into 4-digit groups, each group into 2-digit pairs, and each pair into
+/// its two digits, with each step performed on every group or pair at once.
+#[inline]
+fn u64_digits(value: u64) -> [u8; U64_DIGITS] {
+    let high = (value / 1_0000_0000_0000_0000) as u32;
+    let low = value % 1_0000_0000_0000_0000;
+    let (mid, low) = ((low / 1_0000_0000) as u32, (low % 1_0000_0000) as u32);
+    let groups = u32x8::from_array([
+        high,
+        mid / 1_0000,
+        mid % 1_0000,
+        low / 1_0000,
+        low % 1_0000,
+        0,
+        0,
+        0,
+    ]);
+
+    // x / 100 == (x * 5243) >> 19 for all x < 10000
+    let high_pairs = (groups * u32x8::splat(5243)) >> u32x8::splat(19);
+    let low_pairs = groups - high_pairs * u32x8::splat(100);
+    let (pairs, last_pairs) = high_pairs.interleave(low_pairs);
+
+    let (first, second) = two_digits(pairs.cast());
+    let (last, _) = two_digits(last_pairs.cast());
+
+    let mut digits = [0; U64_DIGITS];
+    digits[..8].copy_from_slice(first.as_array());
+    digits[8..16].copy_from_slice(second.as_array());
+    digits[16..].copy_from_slice(&last[..4]);
+    digits
+}
+
+/// Converts each lane to two ASCII decimal digits.
+///
+/// The digits are interleaved, so the two results together hold the digits of every lane in
+/// order, with the digits of the first half of the lanes in the first result.  Each lane is
+/// split into its digits with a multiplication and a shift rather than a division, and the
+/// digits are interleaved with a swizzle, so this is the building block of formatting larger
+/// numbers two digits at a time.
+///
+/// Lanes must be less than 100, and the digits of larger lanes are unspecified.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{fmt, u8x4};
+/// let (first, second) = fmt::two_digits(u8x4::from_array([7, 42, 0, 99]));
+/// assert_eq!(first.as_array(), b"0742");
+/// assert_eq!(second.as_array(), b"0099");
+/// ```
+#[must_use]
+#[inline]
+pub fn two_digits<const LANES: usize>(values: Simd<u8, LANES>) -> (Simd<u8, LANES>, Simd<u8, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // x / 10 == (x * 103) >> 10 for all x < 100
+    let x = values.cast::<u16>();
+    let tens = (x * Simd::splat(103)) >> Simd::splat(10);
+    let ones = x - tens * Simd::splat(10);
+    let (first, second) = tens.cast::<u8>().interleave(ones.cast::<u8>());
+    (first + Simd::splat(b'0'), second + Simd::splat(b'0'))
+}
+
+/// Writes the decimal representation of each of `values` into `buf`, separated by `separator`.
+///
+/// Returns the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if `buf` is too small to hold the formatted values.
+/// Each value takes at most 20 digits, plus one byte for each separator.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::fmt;
+/// let mut buf = [0; 64];
+/// let len = fmt::write_u64(&mut buf, &[0, 42, 1234567890, u64::MAX], b',');
+/// assert_eq!(&buf[..len], b"0,42,1234567890,18446744073709551615");
+/// ```
+#[inline]
+pub fn write_u64(buf: &mut [u8], values: &[u64], separator: u8) -> usize {
+    let mut len = 0;
+    for (i, value) in values.iter().copied().enumerate() {
+        if i != 0 {
+            buf[len] = separator;
+            len += 1;
+        }
+        let digits = u64_digits(value);
+        let value_len = value.checked_ilog10().unwrap_or(0) as usize + 1;
+        buf[len..len + value_len].copy_from_slice(&digits[U64_DIGITS - value_len..]);
+        len += value_len;
+    }
+    len
+}
+
+/// Writes each of `values` as exactly `width` decimal digits, padded with leading zeros, into
+/// `buf`, separated by `separator`.
+///
+/// Returns the number of bytes written.  This renders the fields of timestamps and the
+/// fractional parts of fixed-point decimals.
+///
+/// # Panics
+///
+/// Panics if `width` is 0 or more than 20, if any value has more than `width` digits, or if
+/// `buf` is too small to hold the formatted values.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::fmt;
+/// let mut buf = [0; 16];
+/// let len = fmt::format_fixed_width(&mut buf, &[9, 5, 30], 2, b':');
+/// assert_eq!(&buf[..len], b"09:05:30");
+/// ```
+#[inline]
+pub fn format_fixed_width(buf: &mut [u8], values: &[u64], width: usize, separator: u8) -> usize {
+    assert!(
+        (1..=U64_DIGITS).contains(&width),
+        "width must be between 1 and 20 digits"
+    );
+    let mut len = 0;
+    for (i, value) in values.iter().copied().enumerate() {
+        if i != 0 {
+            buf[len] = separator;
+            len += 1;
+        }
+        let value_len = value.checked_ilog10().unwrap_or(0) as usize + 1;
+        assert!(value_len <= width, "{value} has more than {width} digits");
+        let digits = u64_digits(value);
+        buf[len..len + width].copy_from_slice(&digits[U64_DIGITS - width..]);
+        len += width;
+    }
+    len
+}
@@ -0,0 +1,252 @@
+//! 3D geometry of many objects at once.
+//!
+//! Objects are stored as a structure of arrays, with each coordinate of `LANES` objects in a
+//! vector, so operating on all of them costs about as much as operating on one.  Quaternions
+//! are stored as `[x, y, z, w]`, with the scalar part last, and matrices as arrays of rows.
+//!
+//! The intersection tests are the inner loop of traversing a bounding volume hierarchy with
+//! `LANES` children per node.  A ray starts at `origin` and extends in `direction`, which
+//! doesn't need to be normalized.  Only the half of the line in front of the origin is tested,
+//! so a shape hit behind the origin isn't.
+
+use crate::simd::{LaneCount, Mask, Simd, SimdFloat, SimdPartialOrd, SupportedLaneCount};
+
+/// Returns which of the axis-aligned boxes from `min` to `max` the ray hits.
+///
+/// Each lane of `min` and `max` holds the corners of one box.  A ray that only touches the
+/// surface of a box hits it, as does a ray starting inside it.
+///
+/// This is the slab test: the distances along the ray to the planes bounding each axis are
+/// intersected, and the ray hits if the intersection isn't empty.  A distance is NaN when the ray
+/// is parallel to a plane and lies on it, in which case that axis doesn't limit the distances.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{geom, f32x2};
+/// // A unit cube at the origin, and one 5 units up
+/// let min = [f32x2::splat(0.0), f32x2::from_array([0.0, 5.0]), f32x2::splat(0.0)];
+/// let max = min.map(|x| x + f32x2::splat(1.0));
+/// // A ray along the x axis, through the first cube
+/// let hits = geom::ray_aabb([-1.0, 0.5, 0.5], [1.0, 0.0, 0.0], min, max);
+/// assert_eq!(hits.to_array(), [true, false]);
+/// ```
+#[must_use]
+#[inline]
+pub fn ray_aabb<const LANES: usize>(
+    origin: [f32; 3],
+    direction: [f32; 3],
+    min: [Simd<f32, LANES>; 3],
+    max: [Simd<f32, LANES>; 3],
+) -> Mask<i32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut near = Simd::splat(0.0);
+    let mut far = Simd::splat(f32::INFINITY);
+    for axis in 0..3 {
+        let origin = Simd::splat(origin[axis]);
+        let inverse = Simd::splat(1.0 / direction[axis]);
+        let t0 = (min[axis] - origin) * inverse;
+        let t1 = (max[axis] - origin) * inverse;
+        let on_plane = t0.is_nan() | t1.is_nan();
+        let t_near = on_plane.select(Simd::splat(f32::NEG_INFINITY), t0.simd_min(t1));
+        let t_far = on_plane.select(Simd::splat(f32::INFINITY), t0.simd_max(t1));
+        near = near.simd_max(t_near);
+        far = far.simd_min(t_far);
+    }
+    near.simd_le(far)
+}
+
+/// Returns which of the spheres at `center` with `radius` the ray hits.
+///
+/// Each lane of `center` and `radius` holds one sphere.  A ray that only touches the surface of
+/// a sphere hits it, as does a ray starting inside it.
+///
+/// The ray hits if the quadratic for the distances along the ray to the surface has a real,
+/// non-negative root.  That is decided from the signs of its coefficients, without a square root.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{geom, f32x4};
+/// let center = [
+///     f32x4::from_array([5.0, 5.0, -5.0, 0.0]),
+///     f32x4::from_array([0.0, 3.0, 0.0, 0.0]),
+///     f32x4::splat(0.0),
+/// ];
+/// let radius = f32x4::from_array([1.0, 1.0, 1.0, 0.5]);
+/// // A ray along the x axis hits the first sphere, misses the second, is pointing away from
+/// // the third, and starts inside the fourth
+/// let hits = geom::ray_sphere([0.0; 3], [2.0, 0.0, 0.0], center, radius);
+/// assert_eq!(hits.to_array(), [true, false, false, true]);
+/// ```
+#[must_use]
+#[inline]
+pub fn ray_sphere<const LANES: usize>(
+    origin: [f32; 3],
+    direction: [f32; 3],
+    center: [Simd<f32, LANES>; 3],
+    radius: Simd<f32, LANES>,
+) -> Mask<i32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // The distances `t` solve `a t^2 + 2 b t + c = 0`
+    let zero = Simd::splat(0.0);
+    let (mut b, mut c) = (zero, -radius * radius);
+    let mut a = 0.0;
+    for axis in 0..3 {
+        let offset = Simd::splat(origin[axis]) - center[axis];
+        let direction = direction[axis];
+        a += direction * direction;
+        b += offset * Simd::splat(direction);
+        c += offset * offset;
+    }
+
+    // The roots are real if the discriminant isn't negative, and the larger one isn't negative
+    // if the origin is inside the sphere, or the sphere is in front of it
+    let real = (b * b - Simd::splat(a) * c).simd_ge(zero);
+    real & (c.simd_le(zero) | b.simd_le(zero))
+}
+
+/// Returns the cross product of `a` and `b`.
+#[inline]
+fn cross<const LANES: usize>(
+    a: [Simd<f32, LANES>; 3],
+    b: [Simd<f32, LANES>; 3],
+) -> [Simd<f32, LANES>; 3]
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Returns the Hamilton product of the quaternions `a` and `b`.
+///
+/// Rotating by the product is rotating by `b`, then by `a`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{geom, f32x2};
+/// // i * j = k, and j * i = -k
+/// let a = [[1.0, 0.0], [0.0, 1.0], [0.0; 2], [0.0; 2]].map(f32x2::from_array);
+/// let b = [[0.0, 1.0], [1.0, 0.0], [0.0; 2], [0.0; 2]].map(f32x2::from_array);
+/// let product = geom::quat_mul(a, b);
+/// assert_eq!(product.map(f32x2::to_array), [[0.0; 2], [0.0; 2], [1.0, -1.0], [0.0; 2]]);
+/// ```
+#[must_use]
+#[inline]
+pub fn quat_mul<const LANES: usize>(
+    a: [Simd<f32, LANES>; 4],
+    b: [Simd<f32, LANES>; 4],
+) -> [Simd<f32, LANES>; 4]
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+/// Returns `v` rotated by the unit quaternion `q`.
+///
+/// This is `q * v * q^-1`, computed as `v + w t + u × t` where `u` is the vector part of `q`,
+/// `w` its scalar part, and `t = 2 u × v`, which takes fewer multiplications than two
+/// quaternion products.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{geom, f32x2};
+/// // Half turns around the z and x axes
+/// let q = [[0.0, 1.0], [0.0; 2], [1.0, 0.0], [0.0; 2]].map(f32x2::from_array);
+/// let v = [f32x2::splat(1.0), f32x2::splat(2.0), f32x2::splat(3.0)];
+/// let rotated = geom::quat_rotate(q, v);
+/// assert_eq!(rotated.map(f32x2::to_array), [[-1.0, 1.0], [-2.0, -2.0], [3.0, -3.0]]);
+/// ```
+#[must_use]
+#[inline]
+pub fn quat_rotate<const LANES: usize>(
+    q: [Simd<f32, LANES>; 4],
+    v: [Simd<f32, LANES>; 3],
+) -> [Simd<f32, LANES>; 3]
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let [x, y, z, w] = q;
+    let u = [x, y, z];
+    let t = cross(u, v).map(|t| t + t);
+    let ut = cross(u, t);
+    [0, 1, 2].map(|i| v[i] + w * t[i] + ut[i])
+}
+
+/// Returns the product of each 4x4 matrix in `matrix` and the column vector `v`.
+///
+/// Each lane holds a separate matrix and vector.  With `v[3]` set to 1, this applies an affine
+/// transformation to a point, and with `v[3]` set to 0, to a direction.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{geom, f32x2};
+/// // Translations by 1 and by 2 along the x axis
+/// let mut matrix = [[f32x2::splat(0.0); 4]; 4];
+/// for i in 0..4 {
+///     matrix[i][i] = f32x2::splat(1.0);
+/// }
+/// matrix[0][3] = f32x2::from_array([1.0, 2.0]);
+/// let point = [f32x2::splat(5.0), f32x2::splat(0.0), f32x2::splat(0.0), f32x2::splat(1.0)];
+/// let transformed = geom::mat4_transform(matrix, point);
+/// assert_eq!(transformed[0].to_array(), [6.0, 7.0]);
+/// ```
+#[must_use]
+#[inline]
+pub fn mat4_transform<const LANES: usize>(
+    matrix: [[Simd<f32, LANES>; 4]; 4],
+    v: [Simd<f32, LANES>; 4],
+) -> [Simd<f32, LANES>; 4]
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    matrix.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2] + row[3] * v[3])
+}
+
+/// Returns the product of each pair of 4x4 matrices in `a` and `b`.
+///
+/// Each lane holds a separate pair of matrices.  Transforming by the product is transforming by
+/// `b`, then by `a`.
+#[must_use]
+#[inline]
+pub fn mat4_mul<const LANES: usize>(
+    a: [[Simd<f32, LANES>; 4]; 4],
+    b: [[Simd<f32, LANES>; 4]; 4],
+) -> [[Simd<f32, LANES>; 4]; 4]
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    a.map(|row| {
+        [0, 1, 2, 3]
+            .map(|j| row[0] * b[0][j] + row[1] * b[1][j] + row[2] * b[2][j] + row[3] * b[3][j])
+    })
+}
@@ -0,0 +1,167 @@
+//! Conversions between `f32` and the 16-bit floating point formats.
+//!
+//! Neither IEEE 754 half precision (`f16`) nor bfloat16 (`bf16`) has a Rust type, so values in
+//! these formats are represented by their bits, as `u16`.
+//!
+//! Conversions to the 16-bit formats round stochastically: a value is rounded up with
+//! probability proportional to its distance from the value below it, using a vector of random
+//! bits supplied by the caller.  Unlike rounding to nearest, the rounding is unbiased on
+//! average, so small updates accumulated in low precision aren't systematically lost.
+
+use crate::simd::{
+    LaneCount, Simd, SimdFloat, SimdOrd, SimdPartialEq, SimdPartialOrd, SupportedLaneCount,
+};
+
+/// The bits of an `f32` with an exponent of the smallest normal `f16`.
+const F16_MIN_NORMAL: u32 = 0x3880_0000;
+/// The difference between the `f32` and `f16` exponent biases, in the `f32` exponent field.
+const F16_REBIAS: u32 = (127 - 15) << 23;
+/// The bits of an infinite `f16`.
+const F16_INFINITY: u32 = 0x7c00;
+/// The bits of a quiet NaN `f16`.
+const F16_NAN: u32 = 0x7e00;
+
+/// Converts each lane to bfloat16, rounding stochastically with the low 16 bits of `random`.
+///
+/// For stochastic rounding to be unbiased, the low 16 bits of each lane of `random` should be
+/// uniformly distributed.  NaNs are converted to quiet NaNs.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{f32x4, u32x4, half};
+/// // 1 + 2^-9 lies a quarter of the way from 1 to the next bfloat16, 1 + 2^-7
+/// let x = f32x4::splat(1.0 + 2f32.powi(-9));
+/// let random = u32x4::from_array([0, 0xbfff, 0xc000, 0xffff]);
+/// let rounded = half::from_bf16(half::to_bf16_stochastic(x, random));
+/// let up = 1.0 + 2f32.powi(-7);
+/// assert_eq!(rounded.to_array(), [1.0, 1.0, up, up]);
+/// ```
+#[must_use]
+#[inline]
+pub fn to_bf16_stochastic<const LANES: usize>(
+    values: Simd<f32, LANES>,
+    random: Simd<u32, LANES>,
+) -> Simd<u16, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let bits = values.to_bits();
+    // Adding to the low bits carries into the high bits with the desired probability, and
+    // finite values that carry into the exponent become infinity.
+    let rounded = (bits + (random & Simd::splat(0xffff))) >> Simd::splat(16);
+    let nan = (bits >> Simd::splat(16)) | Simd::splat(0x0040);
+    values.is_nan().select(nan, rounded).cast()
+}
+
+/// Converts each lane to IEEE 754 half precision, rounding stochastically with the low 13 bits
+/// of `random`.
+///
+/// For stochastic rounding to be unbiased, the low 13 bits of each lane of `random` should be
+/// uniformly distributed.  Values too large to be represented may round to infinity, and NaNs are
+/// converted to quiet NaNs.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{f32x4, u32x4, half};
+/// // 1 + 2^-12 lies a quarter of the way from 1 to the next f16, 1 + 2^-10
+/// let x = f32x4::splat(1.0 + 2f32.powi(-12));
+/// let random = u32x4::from_array([0, 0x17ff, 0x1800, 0x1fff]);
+/// let rounded = half::from_f16(half::to_f16_stochastic(x, random));
+/// let up = 1.0 + 2f32.powi(-10);
+/// assert_eq!(rounded.to_array(), [1.0, 1.0, up, up]);
+/// ```
+#[must_use]
+#[inline]
+pub fn to_f16_stochastic<const LANES: usize>(
+    values: Simd<f32, LANES>,
+    random: Simd<u32, LANES>,
+) -> Simd<u16, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let bits = values.to_bits();
+    let sign = (bits >> Simd::splat(16)) & Simd::splat(0x8000);
+    let magnitude = bits & Simd::splat(0x7fff_ffff);
+    let random = random & Simd::splat(0x1fff);
+
+    // Normal values are rebiased, and the 13 extra bits of mantissa rounded away.  Values that
+    // carry into the exponent become the next power of two, or infinity.
+    let normal = ((magnitude - Simd::splat(F16_REBIAS)) + random) >> Simd::splat(13);
+    let normal = normal.simd_min(Simd::splat(F16_INFINITY));
+
+    // Subnormal values are a multiple of 2^-24, so scaling by 2^37 leaves 13 bits of fraction
+    // to round away.  The largest subnormals may round up to the smallest normal value.
+    let scaled = Simd::<f32, LANES>::from_bits(magnitude) * Simd::splat((1u64 << 37) as f32);
+    let subnormal = (scaled.cast::<u32>() + random) >> Simd::splat(13);
+
+    let rounded = magnitude
+        .simd_lt(Simd::splat(F16_MIN_NORMAL))
+        .select(subnormal, normal);
+    let rounded = values.is_nan().select(Simd::splat(F16_NAN), rounded);
+    (sign | rounded).cast()
+}
+
+/// Converts each lane from bfloat16 to `f32`.
+///
+/// The conversion is exact.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{u16x4, half};
+/// let bits = u16x4::from_array([0x3f80, 0xc000, 0x7f80, 0x3c00]);
+/// let values = half::from_bf16(bits);
+/// assert_eq!(values.to_array(), [1.0, -2.0, f32::INFINITY, 0.0078125]);
+/// ```
+#[must_use]
+#[inline]
+pub fn from_bf16<const LANES: usize>(bits: Simd<u16, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    Simd::from_bits(bits.cast::<u32>() << Simd::splat(16))
+}
+
+/// Converts each lane from IEEE 754 half precision to `f32`.
+///
+/// The conversion is exact.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{u16x4, half};
+/// let bits = u16x4::from_array([0x3c00, 0xc000, 0x7c00, 0x0001]);
+/// let values = half::from_f16(bits);
+/// assert_eq!(values.to_array(), [1.0, -2.0, f32::INFINITY, 2f32.powi(-24)]);
+/// ```
+#[must_use]
+#[inline]
+pub fn from_f16<const LANES: usize>(bits: Simd<u16, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let bits = bits.cast::<u32>();
+    let sign = (bits & Simd::splat(0x8000)) << Simd::splat(16);
+    let magnitude = bits & Simd::splat(0x7fff);
+
+    // Shifting into place and scaling by 2^112, the difference in exponent bias, converts both
+    // normal and subnormal values exactly.
+    let finite = Simd::<f32, LANES>::from_bits(magnitude << Simd::splat(13))
+        * Simd::splat(f32::from_bits(F16_REBIAS + (127 << 23)));
+    let finite = finite.to_bits();
+
+    // Infinities and NaNs keep their mantissa, with the largest exponent
+    let non_finite = (magnitude << Simd::splat(13)) | Simd::splat(0x7f80_0000);
+    let is_finite = (magnitude & Simd::splat(F16_INFINITY)).simd_ne(Simd::splat(F16_INFINITY));
+    Simd::from_bits(sign | is_finite.select(finite, non_finite))
+}
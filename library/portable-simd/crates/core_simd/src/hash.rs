@@ -0,0 +1,439 @@
+//! Hashes of byte slices, computing several hash states at once.
+//!
+//! SipHash is the hash of the standard library's `HashMap` because it's keyed: without the key,
+//! an attacker can't find many keys that collide, and degrade the map to a list.  Its rounds
+//! are a chain of dependent additions, rotations and XORs on one 64-bit word at a time, so it
+//! hashes long keys slowly.  [`SimdHasher`] runs the same rounds on four independent states in
+//! the lanes of a vector, each hashing every fourth word of the input, and combines them at the
+//! end.
+//!
+//! The module also has [`Xxh64`], the 64-bit xxHash, a fast hash for when the inputs aren't
+//! chosen by an attacker, such as for checksums and deduplication.
+
+use crate::simd::{u64x4, LaneCount, Simd, SupportedLaneCount};
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+
+/// The number of SipHash states hashed at once.
+const LANES: usize = 4;
+
+/// The number of bytes hashed by a round of every state.
+const BLOCK: usize = 8 * LANES;
+
+/// Rotates each lane of `x` left by `N` bits.
+#[inline]
+fn rotate_left<const N: u64, const L: usize>(x: Simd<u64, L>) -> Simd<u64, L>
+where
+    LaneCount<L>: SupportedLaneCount,
+{
+    (x << Simd::splat(N)) | (x >> Simd::splat(64 - N))
+}
+
+/// The SipHash round, on every lane of the state.
+#[inline]
+fn sip_round<const L: usize>(v: &mut [Simd<u64, L>; 4])
+where
+    LaneCount<L>: SupportedLaneCount,
+{
+    v[0] += v[1];
+    v[1] = rotate_left::<13, L>(v[1]) ^ v[0];
+    v[0] = rotate_left::<32, L>(v[0]);
+    v[2] += v[3];
+    v[3] = rotate_left::<16, L>(v[3]) ^ v[2];
+    v[0] += v[3];
+    v[3] = rotate_left::<21, L>(v[3]) ^ v[0];
+    v[2] += v[1];
+    v[1] = rotate_left::<17, L>(v[1]) ^ v[2];
+    v[2] = rotate_left::<32, L>(v[2]);
+}
+
+/// Returns the initial SipHash state for the key `k0`, `k1` in every lane.
+#[inline]
+fn initial_state<const L: usize>(k0: u64, k1: u64) -> [Simd<u64, L>; 4]
+where
+    LaneCount<L>: SupportedLaneCount,
+{
+    [
+        k0 ^ 0x736f_6d65_7073_6575,
+        k1 ^ 0x646f_7261_6e64_6f6d,
+        k0 ^ 0x6c79_6765_6e65_7261,
+        k1 ^ 0x7465_6462_7974_6573,
+    ]
+    .map(Simd::splat)
+}
+
+/// Adds a word to each lane of the state, with two rounds, as in SipHash-2-4.
+#[inline]
+fn compress<const L: usize>(v: &mut [Simd<u64, L>; 4], m: Simd<u64, L>)
+where
+    LaneCount<L>: SupportedLaneCount,
+{
+    v[3] ^= m;
+    sip_round(v);
+    sip_round(v);
+    v[0] ^= m;
+}
+
+/// Returns the hash of each lane of the state, with four rounds, as in SipHash-2-4.
+#[inline]
+fn finalize<const L: usize>(mut v: [Simd<u64, L>; 4]) -> Simd<u64, L>
+where
+    LaneCount<L>: SupportedLaneCount,
+{
+    v[2] ^= Simd::splat(0xff);
+    for _ in 0..4 {
+        sip_round(&mut v);
+    }
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+/// A keyed 64-bit hasher for hash maps, which hashes long keys faster than SipHash.
+///
+/// Each of four lanes hashes every fourth 64-bit word of the input with SipHash-2-4, and the
+/// hashes of the lanes and the length of the input are then hashed with SipHash-2-4 too.  Long
+/// keys are hashed 32 bytes at a time, with the rounds of the four lanes running in parallel.
+/// Keys shorter than 32 bytes pay for combining the lanes, so they're better served by SipHash
+/// itself.
+///
+/// # Security
+///
+/// Like SipHash, this is meant to make collisions hard to find without the key, which protects
+/// hash maps from denial of service by inputs that collide.  Every lane and the combination of
+/// the lanes are full SipHash-2-4, but the construction as a whole hasn't been cryptanalyzed as
+/// SipHash has, so it shouldn't be used as a MAC or other cryptographic primitive.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::hash::SimdHasher;
+/// use core::hash::Hasher;
+///
+/// let mut hasher = SimdHasher::new_with_keys(0x0706050403020100, 0x0f0e0d0c0b0a0908);
+/// hasher.write(b"a long key, such as a path or a URL");
+/// let hash = hasher.finish();
+///
+/// let mut hasher = SimdHasher::new_with_keys(0x0706050403020100, 0x0f0e0d0c0b0a0908);
+/// hasher.write(b"a long key, such as ");
+/// hasher.write(b"a path or a URL");
+/// assert_eq!(hasher.finish(), hash);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SimdHasher {
+    k0: u64,
+    k1: u64,
+    /// The SipHash state of each lane.
+    v: [u64x4; 4],
+    /// The start of a block that hasn't been hashed yet.
+    buf: [u8; BLOCK],
+    buf_len: usize,
+    /// The length of the input so far.
+    len: u64,
+}
+
+impl SimdHasher {
+    /// Creates a hasher with the 128-bit key `k0`, `k1`.
+    #[inline]
+    #[must_use]
+    pub fn new_with_keys(k0: u64, k1: u64) -> Self {
+        let mut v = initial_state(k0, k1);
+        // Distinguish the lanes, so that inputs with the same words in different lanes differ
+        v[1] ^= u64x4::from_array([0, 1, 2, 3]);
+        Self {
+            k0,
+            k1,
+            v,
+            buf: [0; BLOCK],
+            buf_len: 0,
+            len: 0,
+        }
+    }
+
+    /// Hashes a block of `BLOCK` bytes, a word in each lane.
+    #[inline]
+    fn block(&mut self, block: &[u8]) {
+        let m = core::array::from_fn(|i| {
+            u64::from_le_bytes(block[8 * i..8 * i + 8].try_into().unwrap())
+        });
+        compress(&mut self.v, u64x4::from_array(m));
+    }
+}
+
+impl Hasher for SimdHasher {
+    #[inline]
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.len = self.len.wrapping_add(bytes.len() as u64);
+        if self.buf_len > 0 {
+            let len = bytes.len().min(BLOCK - self.buf_len);
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&bytes[..len]);
+            self.buf_len += len;
+            bytes = &bytes[len..];
+            if self.buf_len < BLOCK {
+                return;
+            }
+            let buf = self.buf;
+            self.block(&buf);
+            self.buf_len = 0;
+        }
+
+        let mut blocks = bytes.chunks_exact(BLOCK);
+        for block in &mut blocks {
+            self.block(block);
+        }
+        let rest = blocks.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        // The last block is padded with zeros, which is unambiguous since the length of the
+        // input is hashed too.
+        let mut hasher = self.clone();
+        if hasher.buf_len > 0 {
+            hasher.buf[hasher.buf_len..].fill(0);
+            let buf = hasher.buf;
+            hasher.block(&buf);
+        }
+
+        let mut v = initial_state::<1>(self.k0, self.k1);
+        for hash in finalize(hasher.v).to_array() {
+            compress(&mut v, Simd::splat(hash));
+        }
+        compress(&mut v, Simd::splat(self.len));
+        finalize(v)[0]
+    }
+}
+
+/// Creates [`SimdHasher`]s with the same key, for hash maps.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::hash::BuildSimdHasher;
+/// use core::hash::{BuildHasher, Hasher};
+///
+/// let state = BuildSimdHasher::new(0x0706050403020100, 0x0f0e0d0c0b0a0908);
+/// let hash = |key: &[u8]| {
+///     let mut hasher = state.build_hasher();
+///     hasher.write(key);
+///     hasher.finish()
+/// };
+/// assert_eq!(hash(b"some key"), hash(b"some key"));
+/// assert_ne!(hash(b"some key"), hash(b"another key"));
+/// ```
+#[derive(Copy, Clone)]
+pub struct BuildSimdHasher {
+    k0: u64,
+    k1: u64,
+}
+
+impl BuildSimdHasher {
+    /// Creates a builder of hashers with the 128-bit key `k0`, `k1`.
+    ///
+    /// The key should be random, and secret from anyone who chooses the keys of the map.
+    #[inline]
+    #[must_use]
+    pub const fn new(k0: u64, k1: u64) -> Self {
+        Self { k0, k1 }
+    }
+}
+
+impl BuildHasher for BuildSimdHasher {
+    type Hasher = SimdHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> SimdHasher {
+        SimdHasher::new_with_keys(self.k0, self.k1)
+    }
+}
+
+impl fmt::Debug for BuildSimdHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BuildSimdHasher").finish_non_exhaustive()
+    }
+}
+
+const PRIME64_1: u64 = 0x9e37_79b1_85eb_ca87;
+const PRIME64_2: u64 = 0xc2b2_ae3d_27d4_eb4f;
+const PRIME64_3: u64 = 0x1656_67b1_9e37_79f9;
+const PRIME64_4: u64 = 0x85eb_ca77_c2b2_ae63;
+const PRIME64_5: u64 = 0x27d4_eb2f_1656_67c5;
+
+/// The xxHash round, on every lane of the accumulators.
+#[inline]
+fn xxh64_round<const L: usize>(acc: Simd<u64, L>, input: Simd<u64, L>) -> Simd<u64, L>
+where
+    LaneCount<L>: SupportedLaneCount,
+{
+    rotate_left::<31, L>(acc + input * Simd::splat(PRIME64_2)) * Simd::splat(PRIME64_1)
+}
+
+/// The streaming state of XXH64, the 64-bit xxHash, a fast non-cryptographic hash.
+///
+/// XXH64 keeps four accumulators, each hashing every fourth 64-bit word of the input, so the
+/// accumulators are the lanes of a vector and each 32-byte stripe of the input is a single
+/// round.  The hashes are the same as those of the reference implementation.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::hash::{self, Xxh64};
+/// let mut xxh = Xxh64::new(0);
+/// xxh.update(b"Nobody inspects");
+/// xxh.update(b" the spammish repetition");
+/// assert_eq!(xxh.finish(), 0xfbcea83c8a378bf1);
+/// assert_eq!(hash::xxh64(b"Nobody inspects the spammish repetition", 0), xxh.finish());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Xxh64 {
+    seed: u64,
+    /// The accumulators, one in each lane.
+    acc: u64x4,
+    /// The start of a stripe that hasn't been hashed yet.
+    buf: [u8; BLOCK],
+    buf_len: usize,
+    /// The length of the input so far.
+    len: u64,
+}
+
+impl Xxh64 {
+    /// Creates the state for an empty input, with the seed `seed`.
+    #[inline]
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        let acc = u64x4::from_array([
+            seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2),
+            seed.wrapping_add(PRIME64_2),
+            seed,
+            seed.wrapping_sub(PRIME64_1),
+        ]);
+        Self {
+            seed,
+            acc,
+            buf: [0; BLOCK],
+            buf_len: 0,
+            len: 0,
+        }
+    }
+
+    /// Hashes a stripe of `BLOCK` bytes, a word in each lane.
+    #[inline]
+    fn stripe(&mut self, stripe: &[u8]) {
+        let m = core::array::from_fn(|i| {
+            u64::from_le_bytes(stripe[8 * i..8 * i + 8].try_into().unwrap())
+        });
+        self.acc = xxh64_round(self.acc, u64x4::from_array(m));
+    }
+
+    /// Appends `data` to the hashed input.
+    #[inline]
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.len = self.len.wrapping_add(data.len() as u64);
+        if self.buf_len > 0 {
+            let len = data.len().min(BLOCK - self.buf_len);
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&data[..len]);
+            self.buf_len += len;
+            data = &data[len..];
+            if self.buf_len < BLOCK {
+                return;
+            }
+            let buf = self.buf;
+            self.stripe(&buf);
+            self.buf_len = 0;
+        }
+
+        let mut stripes = data.chunks_exact(BLOCK);
+        for stripe in &mut stripes {
+            self.stripe(stripe);
+        }
+        let rest = stripes.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+
+    /// Returns the hash of the input so far.
+    #[inline]
+    #[must_use]
+    pub fn finish(&self) -> u64 {
+        let mut h = if self.len >= BLOCK as u64 {
+            let [v1, v2, v3, v4] = self.acc.to_array();
+            let mut h = v1
+                .rotate_left(1)
+                .wrapping_add(v2.rotate_left(7))
+                .wrapping_add(v3.rotate_left(12))
+                .wrapping_add(v4.rotate_left(18));
+            let merged = xxh64_round(u64x4::splat(0), self.acc).to_array();
+            for v in merged {
+                h = (h ^ v).wrapping_mul(PRIME64_1).wrapping_add(PRIME64_4);
+            }
+            h
+        } else {
+            self.seed.wrapping_add(PRIME64_5)
+        };
+        h = h.wrapping_add(self.len);
+
+        let mut words = self.buf[..self.buf_len].chunks_exact(8);
+        for word in &mut words {
+            let word = Simd::<u64, 1>::splat(u64::from_le_bytes(word.try_into().unwrap()));
+            h ^= xxh64_round(Simd::splat(0), word)[0];
+            h = h
+                .rotate_left(27)
+                .wrapping_mul(PRIME64_1)
+                .wrapping_add(PRIME64_4);
+        }
+        let mut rest = words.remainder();
+        if rest.len() >= 4 {
+            let word = u32::from_le_bytes(rest[..4].try_into().unwrap());
+            h ^= u64::from(word).wrapping_mul(PRIME64_1);
+            h = h
+                .rotate_left(23)
+                .wrapping_mul(PRIME64_2)
+                .wrapping_add(PRIME64_3);
+            rest = &rest[4..];
+        }
+        for byte in rest {
+            h ^= u64::from(*byte).wrapping_mul(PRIME64_5);
+            h = h.rotate_left(11).wrapping_mul(PRIME64_1);
+        }
+
+        h ^= h >> 33;
+        h = h.wrapping_mul(PRIME64_2);
+        h ^= h >> 29;
+        h = h.wrapping_mul(PRIME64_3);
+        h ^ (h >> 32)
+    }
+}
+
+impl Default for Xxh64 {
+    #[inline]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Returns the XXH64 hash of `data` with the seed `seed`.
+///
+/// This is the same as hashing `data` with a single [`Xxh64::update`].
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::hash;
+/// assert_eq!(hash::xxh64(b"", 0), 0xef46db3751d8e999);
+/// assert_eq!(hash::xxh64(b"abc", 0), 0x44bc2cf5ad770999);
+/// ```
+#[must_use]
+#[inline]
+pub fn xxh64(data: &[u8], seed: u64) -> u64 {
+    let mut xxh = Xxh64::new(seed);
+    xxh.update(data);
+    xxh.finish()
+}
@@ -0,0 +1,202 @@
+//! Parsing of hexadecimal identifiers, such as UUIDs and hashes.
+//!
+//! Every character of an identifier is validated and converted to its value at once, and the
+//! values are moved into place with swizzles, skipping the dashes of a UUID, so parsing takes
+//! a fixed number of vector instructions rather than a loop over the characters.
+
+use crate::simd::{
+    u8x16, u8x32, u8x64, LaneCount, Mask, Simd, SimdPartialEq, SimdPartialOrd, SupportedLaneCount,
+    Swizzle, ToBitMask,
+};
+use core::fmt;
+
+/// An error returned when parsing an identifier that isn't valid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseIdError(());
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid hexadecimal identifier")
+    }
+}
+
+/// Returns the value of each lane as a hexadecimal digit, and whether it is one.
+///
+/// Both lowercase and uppercase letters are accepted.
+#[inline]
+fn hex_digits<const LANES: usize>(bytes: Simd<u8, LANES>) -> (Simd<u8, LANES>, Mask<i8, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let digits = bytes - Simd::splat(b'0');
+    // Setting the bit of lowercase letters maps uppercase letters to lowercase
+    let letters = (bytes | Simd::splat(0x20)) - Simd::splat(b'a');
+    let is_digit = digits.simd_lt(Simd::splat(10));
+    let is_letter = letters.simd_lt(Simd::splat(6));
+    (
+        is_digit.select(digits, letters + Simd::splat(10)),
+        is_digit | is_letter,
+    )
+}
+
+/// Parses hexadecimal text, two digits to a byte, into `out`.
+///
+/// Both lowercase and uppercase digits are accepted.  This decodes identifiers such as SHA-1
+/// and SHA-256 hashes, or any other hexadecimal text, 16 bytes at a time.
+///
+/// # Errors
+///
+/// Returns an error if `text` isn't twice as long as `out`, or has a character that isn't a
+/// hexadecimal digit.  The contents of `out` are unspecified after an error.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::id;
+/// let mut hash = [0; 20];
+/// id::parse_hex(b"da39a3ee5e6b4b0d3255bfef95601890afd80709", &mut hash).unwrap();
+/// assert_eq!(hash[..4], [0xda, 0x39, 0xa3, 0xee]);
+///
+/// assert!(id::parse_hex(b"da39a3ee5e6b4b0d3255bfef95601890afd8070g", &mut hash).is_err());
+/// ```
+#[inline]
+pub fn parse_hex(text: &[u8], out: &mut [u8]) -> Result<(), ParseIdError> {
+    if text.len() != 2 * out.len() {
+        return Err(ParseIdError(()));
+    }
+
+    let mut valid = true;
+    for (text, out) in text.chunks(32).zip(out.chunks_mut(16)) {
+        let (digits, is_digit) = hex_digits(u8x32::load_or_default(text));
+        let in_bounds = u32::MAX >> (32 - text.len());
+        valid &= is_digit.to_bitmask() & in_bounds == in_bounds;
+        let (high, low) = digits.deinterleave(digits);
+        let bytes = (high << Simd::splat(4)) | low;
+        out.copy_from_slice(&bytes[..out.len()]);
+    }
+    if valid {
+        Ok(())
+    } else {
+        Err(ParseIdError(()))
+    }
+}
+
+/// The length of a UUID in its hyphenated form.
+const UUID_LEN: usize = 36;
+
+/// The positions of the dashes of a hyphenated UUID.
+const UUID_DASHES: u64 = 1 << 8 | 1 << 13 | 1 << 18 | 1 << 23;
+
+/// Selects the `N`th digit of each byte of a hyphenated UUID, skipping the dashes.
+struct UuidDigits<const N: usize>;
+
+impl<const N: usize> Swizzle<64, 16> for UuidDigits<N> {
+    const INDEX: [usize; 16] = {
+        let mut index = [0; 16];
+        let mut byte = 0;
+        let mut i = 0;
+        while i < UUID_LEN {
+            if UUID_DASHES & 1 << i == 0 {
+                if byte % 2 == N {
+                    index[byte / 2] = i;
+                }
+                byte += 1;
+            }
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Parses a UUID in its hyphenated form, such as `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+///
+/// Both lowercase and uppercase digits are accepted.  Returns the 16 bytes of the UUID, in the
+/// order they're written.
+///
+/// # Errors
+///
+/// Returns an error if `text` isn't 36 characters long, with groups of 8, 4, 4, 4 and 12
+/// hexadecimal digits separated by dashes.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::id;
+/// let uuid = id::parse_uuid(b"67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+/// assert_eq!(uuid, 0x67e5504410b1426f9247bb680e5fe0c8_u128.to_be_bytes());
+///
+/// assert!(id::parse_uuid(b"67e55044-10b1-426f-9247bb680e5fe0c8").is_err());
+/// assert!(id::parse_uuid(b"67e55044-10b1-426f-9247-bb680e5fe0cx").is_err());
+/// ```
+#[inline]
+pub fn parse_uuid(text: &[u8]) -> Result<[u8; 16], ParseIdError> {
+    if text.len() != UUID_LEN {
+        return Err(ParseIdError(()));
+    }
+
+    let bytes = u8x64::load_or_default(text);
+    let (digits, is_digit) = hex_digits(bytes);
+    let is_dash = bytes.simd_eq(u8x64::splat(b'-'));
+    let in_bounds = (1 << UUID_LEN) - 1;
+    if is_dash.to_bitmask() != UUID_DASHES || is_digit.to_bitmask() != in_bounds ^ UUID_DASHES {
+        return Err(ParseIdError(()));
+    }
+
+    let high = UuidDigits::<0>::swizzle(digits);
+    let low = UuidDigits::<1>::swizzle(digits);
+    Ok(((high << u8x16::splat(4)) | low).to_array())
+}
+
+/// Parses UUIDs in their hyphenated form, separated by `separator`, into `out`.
+///
+/// Returns the number of UUIDs parsed.  UUIDs are parsed as by [`parse_uuid`], and since they
+/// have a fixed length, each one is found without searching for the separators.
+///
+/// # Errors
+///
+/// Returns an error if any UUID isn't valid, or if the UUIDs aren't separated by exactly one
+/// `separator`.  The contents of `out` are unspecified after an error.
+///
+/// # Panics
+///
+/// Panics if `out` is too small to hold the parsed UUIDs.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::id;
+/// let text = b"67e55044-10b1-426f-9247-bb680e5fe0c8\n00000000-0000-0000-0000-000000000000";
+/// let mut uuids = [[0; 16]; 4];
+/// assert_eq!(id::parse_uuid_batch(text, b'\n', &mut uuids), Ok(2));
+/// assert_eq!(uuids[1], [0; 16]);
+/// ```
+#[inline]
+pub fn parse_uuid_batch(
+    text: &[u8],
+    separator: u8,
+    out: &mut [[u8; 16]],
+) -> Result<usize, ParseIdError> {
+    if text.is_empty() {
+        return Ok(0);
+    }
+    // Every UUID but the last is followed by a separator
+    let count = (text.len() + 1) / (UUID_LEN + 1);
+    if count * (UUID_LEN + 1) != text.len() + 1 {
+        return Err(ParseIdError(()));
+    }
+
+    for (record, out) in text.chunks(UUID_LEN + 1).zip(&mut out[..count]) {
+        let (uuid, rest) = record.split_at(UUID_LEN);
+        if !rest.iter().all(|byte| *byte == separator) {
+            return Err(ParseIdError(()));
+        }
+        *out = parse_uuid(uuid)?;
+    }
+    Ok(count)
+}
@@ -0,0 +1,124 @@
+use crate::simd::{LaneCount, Simd, SimdOrd, SimdPartialEq, SimdPartialOrd, SupportedLaneCount};
+
+macro_rules! impl_index {
+    { $($ty:ty),* } => {
+        $(
+        impl<const N: usize> Simd<$ty, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            /// Returns the quotient and remainder of dividing each lane by `len`, rounding the
+            /// quotient down, so the remainder is in `0..len`.
+            #[inline]
+            fn floor_div_rem(self, len: $ty) -> (Self, Self) {
+                assert!(len > 0, "length must be positive");
+                if len & (len - 1) == 0 {
+                    // An arithmetic shift rounds down, and masking leaves a positive remainder
+                    let shift = Self::splat(len.trailing_zeros() as $ty);
+                    (self >> shift, self & Self::splat(len - 1))
+                } else {
+                    let len = Self::splat(len);
+                    let (quotient, remainder) = (self / len, self % len);
+                    let negative = remainder.simd_lt(Self::splat(0));
+                    (
+                        negative.select(quotient - Self::splat(1), quotient),
+                        negative.select(remainder + len, remainder),
+                    )
+                }
+            }
+
+            /// Clamps each lane to an index of a slice of length `len`, from 0 to `len - 1`.
+            ///
+            /// This is the clamp-to-edge addressing mode of texture samplers, which repeats the
+            /// first and last elements past the ends of the slice.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `len` isn't positive.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            #[doc = concat!("let x = Simd::<", stringify!($ty), ", 4>::from_array([0, 3, 4, 100]);")]
+            /// assert_eq!(x.clamp_index(4).to_array(), [0, 3, 3, 3]);
+            /// ```
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[track_caller]
+            pub fn clamp_index(self, len: $ty) -> Self {
+                assert!(len > 0, "length must be positive");
+                self.simd_clamp(Self::splat(0), Self::splat(len - 1))
+            }
+
+            /// Wraps each lane around to an index of a slice of length `len`.
+            ///
+            /// This is the repeat addressing mode of texture samplers, which tiles the slice, and
+            /// the index of a ring buffer.  The result is the Euclidean remainder of dividing by
+            /// `len`, so negative lanes count back from the end of the slice.  Lengths that are
+            /// powers of two take a mask rather than a division.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `len` isn't positive.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            #[doc = concat!("let x = Simd::<", stringify!($ty), ", 4>::from_array([0, 3, 5, 100]);")]
+            /// assert_eq!(x.wrap_index(5).to_array(), [0, 3, 0, 0]);
+            /// assert_eq!(x.wrap_index(4).to_array(), [0, 3, 1, 0]);
+            /// ```
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[track_caller]
+            pub fn wrap_index(self, len: $ty) -> Self {
+                self.floor_div_rem(len).1
+            }
+
+            /// Reflects each lane back and forth to an index of a slice of length `len`.
+            ///
+            /// This is the mirrored repeat addressing mode of texture samplers, which tiles the
+            /// slice with every other copy reversed, so the indices past each end of the slice
+            /// count back from it, repeating the element at the end: `len - 1, len - 1, len - 2`,
+            /// and so on.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `len` isn't positive.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            /// let texels = [10, 20, 30];
+            #[doc = concat!("let x = Simd::<", stringify!($ty), ", 8>::from_array([0, 1, 2, 3, 4, 5, 6, 7]);")]
+            /// let idxs = x.mirror_index(3);
+            /// assert_eq!(idxs.to_array(), [0, 1, 2, 2, 1, 0, 0, 1]);
+            /// assert_eq!(
+            ///     Simd::gather_or_default(&texels, idxs.cast()).to_array(),
+            ///     [10, 20, 30, 30, 20, 10, 10, 20]
+            /// );
+            /// ```
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            #[track_caller]
+            pub fn mirror_index(self, len: $ty) -> Self {
+                let (quotient, remainder) = self.floor_div_rem(len);
+                // Odd copies of the slice are reversed
+                let reversed = (quotient & Self::splat(1)).simd_eq(Self::splat(1));
+                reversed.select(Self::splat(len - 1) - remainder, remainder)
+            }
+        }
+        )*
+    }
+}
+
+impl_index! { usize, i32 }
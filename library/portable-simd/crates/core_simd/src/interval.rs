@@ -0,0 +1,207 @@
+//! Interval arithmetic on many intervals at once.
+//!
+//! An interval holds the bounds of a number that isn't known exactly, and every operation on
+//! intervals returns bounds containing all of the results it could have, so a computation on
+//! intervals bounds the error of the same computation on floats.  The bounds of `LANES`
+//! intervals are kept in two vectors, so each operation computes `LANES` intervals at the cost
+//! of one.
+
+use crate::simd::{
+    LaneCount, Mask, Simd, SimdElement, SimdFloat, SimdPartialOrd, SupportedLaneCount,
+};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Intervals of floats, with the lower bound of each interval in `lo` and the upper bound in
+/// `hi`.
+///
+/// The results of addition, subtraction and multiplication are rounded outward: the lower
+/// bound is moved down to the next float with [`next_down`](SimdFloat::next_down), and the upper
+/// bound up with [`next_up`](SimdFloat::next_up).  Whichever way the operation rounded, the
+/// exact result is contained, at the cost of a bound one unit in the last place wider than it
+/// needs to be when the operation was exact.  Negation, [`min`](SimdInterval::min) and
+/// [`max`](SimdInterval::max) are exact.
+///
+/// Bounds may be infinite.  Unlike for floats, zero times infinity is zero when multiplying
+/// intervals, since every number in an interval with a zero bound can be multiplied by zero.  As
+/// for floats, adding infinities of opposite signs gives NaN bounds.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{interval::SimdInterval, f64x2, SimdFloat};
+/// // 0.1 isn't a float, so bound it by the floats on either side
+/// let tenth = SimdInterval::new(f64x2::splat(0.1).next_down(), f64x2::splat(0.1));
+/// let x = SimdInterval::new(f64x2::from_array([1.0, -2.0]), f64x2::from_array([2.0, 3.0]));
+///
+/// let y = x * tenth + x;
+/// assert!(y.contains(f64x2::from_array([1.1, -2.2])).all());
+/// assert!(y.contains(f64x2::from_array([2.2, 3.3])).all());
+/// assert!(y.lo()[1] < -2.2 && y.hi()[1] > 3.3);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SimdInterval<T, const LANES: usize>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// The lower bound of each interval.
+    lo: Simd<T, LANES>,
+    /// The upper bound of each interval.
+    hi: Simd<T, LANES>,
+}
+
+impl<T, const LANES: usize> SimdInterval<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: SimdFloat + SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    /// Creates the intervals from `lo` to `hi`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any lane of `lo` is greater than the lane of `hi`, or either is NaN.
+    #[inline]
+    #[must_use]
+    pub fn new(lo: Simd<T, LANES>, hi: Simd<T, LANES>) -> Self {
+        assert!(
+            lo.simd_le(hi).all(),
+            "each lane in `lo` must be less than or equal to the corresponding lane in `hi`",
+        );
+        Self { lo, hi }
+    }
+
+    /// Creates the intervals containing only `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any lane of `x` is NaN.
+    #[inline]
+    #[must_use]
+    pub fn point(x: Simd<T, LANES>) -> Self {
+        Self::new(x, x)
+    }
+
+    /// Returns the lower bound of each interval.
+    #[inline]
+    #[must_use]
+    pub fn lo(self) -> Simd<T, LANES> {
+        self.lo
+    }
+
+    /// Returns the upper bound of each interval.
+    #[inline]
+    #[must_use]
+    pub fn hi(self) -> Simd<T, LANES> {
+        self.hi
+    }
+
+    /// Returns which intervals contain the lane of `x`.
+    #[inline]
+    #[must_use]
+    pub fn contains(self, x: Simd<T, LANES>) -> Mask<T::Mask, LANES> {
+        self.lo.simd_le(x) & x.simd_le(self.hi)
+    }
+
+    /// Returns the intervals containing the minimum of a number in each of `self` and
+    /// `other`.
+    #[inline]
+    #[must_use = "method returns a new interval and does not mutate the original inputs"]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            lo: self.lo.simd_min(other.lo),
+            hi: self.hi.simd_min(other.hi),
+        }
+    }
+
+    /// Returns the intervals containing the maximum of a number in each of `self` and
+    /// `other`.
+    #[inline]
+    #[must_use = "method returns a new interval and does not mutate the original inputs"]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            lo: self.lo.simd_max(other.lo),
+            hi: self.hi.simd_max(other.hi),
+        }
+    }
+}
+
+macro_rules! impl_interval {
+    { $($ty:ty),* } => {
+        $(
+        impl<const LANES: usize> Add for SimdInterval<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self {
+                    lo: (self.lo + other.lo).next_down(),
+                    hi: (self.hi + other.hi).next_up(),
+                }
+            }
+        }
+
+        impl<const LANES: usize> Sub for SimdInterval<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Self {
+                    lo: (self.lo - other.hi).next_down(),
+                    hi: (self.hi - other.lo).next_up(),
+                }
+            }
+        }
+
+        impl<const LANES: usize> Mul for SimdInterval<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                // The extremes of the product are products of the bounds.  The bounds aren't NaN,
+                // so a NaN product is zero times infinity, which is zero for intervals.
+                let product = |x: Simd<$ty, LANES>, y: Simd<$ty, LANES>| {
+                    let xy = x * y;
+                    xy.is_nan().select(Simd::splat(0.0), xy)
+                };
+                let a = product(self.lo, other.lo);
+                let b = product(self.lo, other.hi);
+                let c = product(self.hi, other.lo);
+                let d = product(self.hi, other.hi);
+                Self {
+                    lo: a.simd_min(b).simd_min(c.simd_min(d)).next_down(),
+                    hi: a.simd_max(b).simd_max(c.simd_max(d)).next_up(),
+                }
+            }
+        }
+
+        impl<const LANES: usize> Neg for SimdInterval<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self {
+                    lo: -self.hi,
+                    hi: -self.lo,
+                }
+            }
+        }
+        )*
+    }
+}
+
+impl_interval! { f32, f64 }
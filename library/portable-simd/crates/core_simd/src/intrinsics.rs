@@ -64,6 +64,15 @@
     /// getelementptr (without inbounds)
     pub(crate) fn simd_arith_offset<T, U>(ptrs: T, offsets: U) -> T;
 
+    /// cast between vectors of thin pointers, like `<*const T>::cast`
+    pub(crate) fn simd_cast_ptr<T, U>(ptrs: T) -> U;
+
+    /// expose a pointer as an address
+    pub(crate) fn simd_expose_addr<T, U>(ptr: T) -> U;
+
+    /// convert an exposed address back to a pointer
+    pub(crate) fn simd_from_exposed_addr<T, U>(addr: T) -> U;
+
     /// fptoui/fptosi/uitofp/sitofp
     /// casting floats to integers is truncating, so it is safe to convert values like e.g. 1.5
     /// but the truncated value must fit in the target type or the result is poison.
@@ -82,6 +91,9 @@
     /// fabs
     pub(crate) fn simd_fabs<T>(x: T) -> T;
 
+    /// fsqrt
+    pub(crate) fn simd_fsqrt<T>(x: T) -> T;
+
     // minnum/maxnum
     pub(crate) fn simd_fmin<T>(x: T, y: T) -> T;
     pub(crate) fn simd_fmax<T>(x: T, y: T) -> T;
@@ -110,6 +122,16 @@
     /// like gather, but more spicy, as it writes instead of reads
     pub(crate) fn simd_scatter<T, U, V>(val: T, ptr: U, mask: V);
 
+    /// llvm.masked.load
+    /// like gather, but reads consecutive elements starting at a single pointer
+    /// mask: a "wide" mask of integers, selects as if simd_select(mask, read(ptr + i), val)
+    /// ptr: pointer to the first element
+    /// val: vector of values to select if a lane is masked
+    pub(crate) fn simd_masked_load<V, U, T>(mask: V, ptr: U, val: T) -> T;
+    /// llvm.masked.store
+    /// like masked_load, but writes the enabled lanes instead of reading them
+    pub(crate) fn simd_masked_store<V, U, T>(mask: V, ptr: U, val: T);
+
     // {s,u}add.sat
     pub(crate) fn simd_saturating_add<T>(x: T, y: T) -> T;
 
@@ -121,6 +143,10 @@
     pub(crate) fn simd_reduce_add_ordered<T, U>(x: T, y: U) -> U;
     // llvm.vector.reduce.{mul,fmul}
     pub(crate) fn simd_reduce_mul_ordered<T, U>(x: T, y: U) -> U;
+    // llvm.vector.reduce.{add,fadd}, with reassociation allowed
+    pub(crate) fn simd_reduce_add_unordered<T, U>(x: T) -> U;
+    // llvm.vector.reduce.{mul,fmul}, with reassociation allowed
+    pub(crate) fn simd_reduce_mul_unordered<T, U>(x: T) -> U;
     #[allow(unused)]
     pub(crate) fn simd_reduce_all<T>(x: T) -> bool;
     #[allow(unused)]
@@ -0,0 +1,160 @@
+//! Classification of JSON text into structural bitmasks, 64 bytes at a time.
+//!
+//! This is the first stage of a SIMD JSON parser in the style of [simdjson]: each 64-byte block
+//! of input is reduced to a handful of `u64` bitmasks, where bit `i` describes byte `i` of the
+//! block.  Escaped quotes and strings spanning multiple blocks are tracked by [`Scanner`], so
+//! the masks it produces can be used directly to find the structural characters of a document.
+//!
+//! [simdjson]: https://arxiv.org/abs/1902.08318
+
+use crate::simd::{u8x64, Mask, SimdPartialEq, ToBitMask};
+
+/// Bits set at odd positions, from the second bit onwards.
+const ODD_BITS: u64 = 0xaaaa_aaaa_aaaa_aaaa;
+
+/// Bitmasks describing one 64-byte block of JSON text.
+///
+/// Bit `i` of each mask corresponds to byte `i` of the block.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Block {
+    /// Quotes (`"`) that begin or end a string, excluding escaped quotes.
+    pub quote: u64,
+    /// Bytes inside of a string, including the opening quote but excluding the closing quote.
+    pub in_string: u64,
+    /// Bytes escaped by a backslash.
+    pub escaped: u64,
+    /// Structural characters (`{`, `}`, `[`, `]`, `:` and `,`) outside of strings.
+    pub structural: u64,
+    /// Whitespace (space, tab, line feed and carriage return) outside of strings.
+    pub whitespace: u64,
+}
+
+/// Bitmasks of the bytes in `block` equal to `byte`.
+#[inline]
+fn eq_bits(block: u8x64, byte: u8) -> u64 {
+    block.simd_eq(u8x64::splat(byte)).to_bitmask()
+}
+
+/// Computes the running xor of each bit and all of the bits below it.
+#[inline]
+fn prefix_xor(mut bits: u64) -> u64 {
+    let mut shift = 1;
+    while shift < u64::BITS {
+        bits ^= bits << shift;
+        shift *= 2;
+    }
+    bits
+}
+
+/// Classifies consecutive 64-byte blocks of JSON text.
+///
+/// Since a string or an escape sequence may span multiple blocks, the scanner carries state from
+/// one block to the next, and the blocks of a document must be scanned in order.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::json::Scanner;
+/// let mut block = [b' '; 64];
+/// let text = br#"{"a": "{\"}", "b": [1, 2]}"#;
+/// block[..text.len()].copy_from_slice(text);
+///
+/// let mut scanner = Scanner::new();
+/// let classified = scanner.scan(&block);
+///
+/// let structural: Vec<u8> = (0..64)
+///     .filter(|i| classified.structural & (1 << i) != 0)
+///     .map(|i| block[i])
+///     .collect();
+/// assert_eq!(structural, b"{:,:[,]}");
+/// assert!(!scanner.in_string());
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Scanner {
+    /// Whether the first byte of the next block is escaped.
+    next_is_escaped: u64,
+    /// All ones if the next block begins inside of a string, otherwise zero.
+    in_string: u64,
+}
+
+impl Scanner {
+    /// Creates a scanner for the beginning of a document.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            next_is_escaped: 0,
+            in_string: 0,
+        }
+    }
+
+    /// Returns true if the blocks scanned so far end inside of a string.
+    ///
+    /// After the final block of a document, this indicates an unterminated string.
+    #[inline]
+    #[must_use]
+    pub const fn in_string(&self) -> bool {
+        self.in_string != 0
+    }
+
+    /// Classifies the next block of the document.
+    ///
+    /// The final block of a document should be padded with whitespace.
+    #[inline]
+    pub fn scan(&mut self, block: &[u8; 64]) -> Block {
+        let bytes = u8x64::from_array(*block);
+
+        let escaped = self.escaped(eq_bits(bytes, b'\\'));
+        let quote = eq_bits(bytes, b'"') & !escaped;
+
+        // Strings are the bytes between pairs of quotes
+        let in_string = prefix_xor(quote) ^ self.in_string;
+        self.in_string = ((in_string as i64) >> 63) as u64;
+
+        let structural: Mask<i8, 64> = bytes.simd_eq(u8x64::splat(b'{'))
+            | bytes.simd_eq(u8x64::splat(b'}'))
+            | bytes.simd_eq(u8x64::splat(b'['))
+            | bytes.simd_eq(u8x64::splat(b']'))
+            | bytes.simd_eq(u8x64::splat(b':'))
+            | bytes.simd_eq(u8x64::splat(b','));
+        let whitespace: Mask<i8, 64> = bytes.simd_eq(u8x64::splat(b' '))
+            | bytes.simd_eq(u8x64::splat(b'\t'))
+            | bytes.simd_eq(u8x64::splat(b'\n'))
+            | bytes.simd_eq(u8x64::splat(b'\r'));
+
+        Block {
+            quote,
+            in_string,
+            escaped,
+            structural: structural.to_bitmask() & !in_string,
+            whitespace: whitespace.to_bitmask() & !in_string,
+        }
+    }
+
+    /// Finds the bytes escaped by a backslash, given the backslashes in the block.
+    ///
+    /// Within a run of backslashes, every other backslash escapes the following byte, starting
+    /// with the first unescaped backslash of the run.
+    #[inline]
+    fn escaped(&mut self, backslash: u64) -> u64 {
+        if backslash == 0 {
+            return core::mem::take(&mut self.next_is_escaped);
+        }
+
+        // A backslash escaped by the previous block can't begin an escape
+        let potential_escape = backslash & !self.next_is_escaped;
+
+        // Subtracting each run of backslashes from the odd bits following it leaves the
+        // escaping backslashes set, along with the byte after any run that escapes it.
+        let maybe_escaped = potential_escape << 1;
+        let escape_and_terminal =
+            (maybe_escaped | ODD_BITS).wrapping_sub(potential_escape) ^ ODD_BITS;
+
+        let escaped = escape_and_terminal ^ (backslash | self.next_is_escaped);
+        let escape = escape_and_terminal & backslash;
+        self.next_is_escaped = escape >> 63;
+        escaped
+    }
+}
@@ -1,6 +1,7 @@
 #![no_std]
 #![feature(
     convert_float_to_int,
+    core_intrinsics,
     decl_macro,
     intra_doc_pointers,
     platform_intrinsics,
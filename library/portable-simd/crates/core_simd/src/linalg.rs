@@ -0,0 +1,162 @@
+//! Dense linear algebra kernels.
+//!
+//! Matrices are stored in row-major order, as slices of their rows laid end to end.  The
+//! kernels are register-blocked: each vector loaded from memory is used by several independent
+//! accumulators before it is discarded, so the accumulators stay in registers and fewer loads
+//! are needed for each multiplication.
+
+use crate::simd::reduce::Reduce;
+use crate::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+use core::ops::Mul;
+
+/// The number of columns in each chunk.
+const LANES: usize = 16;
+
+/// The number of rows multiplied at once.
+const ROWS: usize = 4;
+
+/// Returns the dot product of each of the `R` rows of `rows` with `x`.
+#[inline]
+fn dot_rows<T, const R: usize>(rows: &[T], x: &[T]) -> [T; R]
+where
+    T: SimdElement,
+    Simd<T, LANES>: Reduce<Scalar = T> + Mul<Output = Simd<T, LANES>>,
+{
+    let cols = x.len();
+    let zero = Simd::<T, LANES>::zero();
+    let mut sums = [zero; R];
+
+    // Each chunk of `x` is loaded once, and multiplied with every row
+    let chunks = x.chunks_exact(LANES);
+    let partial = chunks.remainder();
+    for (j, chunk) in chunks.enumerate() {
+        let x = Simd::from_slice(chunk);
+        for (r, sum) in sums.iter_mut().enumerate() {
+            let row = Simd::from_slice(&rows[r * cols + j * LANES..]);
+            *sum = sum.lanewise_add(row * x);
+        }
+    }
+    if !partial.is_empty() {
+        let start = cols - partial.len();
+        let x = Simd::load_or(partial, zero);
+        for (r, sum) in sums.iter_mut().enumerate() {
+            let row = Simd::load_or(&rows[r * cols + start..(r + 1) * cols], zero);
+            *sum = sum.lanewise_add(row * x);
+        }
+    }
+    sums.map(Reduce::sum_lanes)
+}
+
+/// Multiplies `matrix` by the vector `x`, writing the product to `y`.
+///
+/// `matrix` has `y.len()` rows of `x.len()` columns, so each element of `y` is the dot product of
+/// a row of the matrix with `x`.
+///
+/// Four rows are multiplied at once, sharing each load of `x`.  Integers wrap on overflow.
+/// Floats are summed in several independent parts, so the result may differ from summing in
+/// order.
+///
+/// # Panics
+///
+/// Panics if `matrix.len()` isn't `y.len() * x.len()`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::linalg;
+/// let matrix = [
+///     1.0, 2.0, 3.0,
+///     4.0, 5.0, 6.0,
+/// ];
+/// let mut y = [0.0; 2];
+/// linalg::gemv(&matrix, &[1.0, 0.0, -1.0], &mut y);
+/// assert_eq!(y, [-2.0f32, -2.0]);
+/// ```
+#[inline]
+pub fn gemv<T>(matrix: &[T], x: &[T], y: &mut [T])
+where
+    T: SimdElement,
+    Simd<T, LANES>: Reduce<Scalar = T> + Mul<Output = Simd<T, LANES>>,
+{
+    let cols = x.len();
+    assert_eq!(
+        matrix.len(),
+        y.len() * cols,
+        "matrix must have `y.len()` rows of `x.len()` columns"
+    );
+    if cols == 0 {
+        y.fill(Simd::<T, LANES>::zero().sum_lanes());
+        return;
+    }
+
+    let mut blocks = matrix.chunks_exact(ROWS * cols);
+    let mut outputs = y.chunks_exact_mut(ROWS);
+    for (block, output) in (&mut blocks).zip(&mut outputs) {
+        output.copy_from_slice(&dot_rows::<T, ROWS>(block, x));
+    }
+    let rows = blocks.remainder().chunks_exact(cols);
+    for (row, output) in rows.zip(outputs.into_remainder()) {
+        [*output] = dot_rows::<T, 1>(row, x);
+    }
+}
+
+/// Adds the product of the packed panels `a` and `b` to a tile of accumulators, the inner kernel
+/// of a matrix multiplication.
+///
+/// `a` holds `MR` rows of a matrix and `b` holds `NR` columns of another, each of length `k`,
+/// packed in the order they are used: `a` is column-major, with element `(i, p)` at
+/// `p * MR + i`, and `b` is row-major, with element `(p, j)` at `p * NR + j`.  Each row of
+/// their `MR` by `NR` product is added to the corresponding vector of `tile`.
+///
+/// For each `p`, one vector of `b` is loaded and multiplied by `MR` splatted elements of `a`,
+/// so each load is used by `MR` independent accumulators, which stay in registers if `MR`
+/// vectors fit.  A matrix multiplication packs panels of its operands, calls this kernel for
+/// each tile of the result, and then adds each tile to the result, handling any scaling and
+/// partial tiles at the edges itself.
+///
+/// Integers wrap on overflow.
+///
+/// # Panics
+///
+/// Panics if `a.len()` isn't a multiple of `MR`, or if `a` and `b` don't have the same number
+/// of rows and columns, `k`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{linalg, f32x4};
+/// // Two rows of [[1, 2], [3, 4]], packed by column
+/// let a = [1.0, 3.0, 2.0, 4.0];
+/// // Four columns of [[1, 0, 0, 1], [0, 1, 1, 0]], packed by row
+/// let b = [1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0];
+/// let mut tile = [f32x4::splat(0.0); 2];
+/// linalg::gemm_micro::<f32, 2, 4>(&a, &b, &mut tile);
+/// assert_eq!(tile[0].to_array(), [1.0, 2.0, 2.0, 1.0]);
+/// assert_eq!(tile[1].to_array(), [3.0, 4.0, 4.0, 3.0]);
+/// ```
+#[inline]
+pub fn gemm_micro<T, const MR: usize, const NR: usize>(
+    a: &[T],
+    b: &[T],
+    tile: &mut [Simd<T, NR>; MR],
+) where
+    T: SimdElement,
+    LaneCount<NR>: SupportedLaneCount,
+    Simd<T, NR>: Reduce<Scalar = T> + Mul<Output = Simd<T, NR>>,
+{
+    let k = a.len() / MR;
+    assert!(
+        k * MR == a.len() && k * NR == b.len(),
+        "panels must have `MR` rows and `NR` columns of the same length"
+    );
+    for (a, b) in a.chunks_exact(MR).zip(b.chunks_exact(NR)) {
+        let b = Simd::from_slice(b);
+        for (accumulator, a) in tile.iter_mut().zip(a) {
+            *accumulator = accumulator.lanewise_add(Simd::splat(*a) * b);
+        }
+    }
+}
@@ -0,0 +1,581 @@
+//! Lookup tables of a size known at compile time.
+//!
+//! Looking up a vector of indices in a table has two implementations, with very different costs
+//! on different targets.  A table of bytes that fits in a register can be looked up with a single
+//! byte shuffle, such as `pshufb` or `tbl`, while larger tables need a gather, which is a single
+//! instruction on some targets and a load for each lane on others.  [`SimdLut`] picks the
+//! implementation for the size of its table and the target, so algorithms driven by lookup tables
+//! don't need to.  [`Simd::map_table`] does the same for tables that are constants, implementing
+//! [`Table`].
+//!
+//! [`lookup16`], [`lookup32`] and [`lookup64`] look up vectors of byte indices in tables of 16,
+//! 32 and 64 bytes held in registers, and [`is_single_instruction`] reports which of them are a
+//! single lookup instruction on the target, so the size of a table can be chosen without
+//! measuring every target.
+
+use crate::simd::{
+    LaneCount, Simd, SimdElement, SimdPartialEq, SimdPartialOrd, SimdUint, SupportedLaneCount,
+    Swizzle,
+};
+
+/// Whether the target has `pshufb`, looking up 16 bytes in a table of 16.
+const SSSE3: bool = cfg!(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "ssse3"
+));
+
+/// Whether the target has `vpshufb` for 32 bytes, looking up each half in a table of 16.
+const AVX2: bool = cfg!(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2"
+));
+
+/// Whether the target has `vpshufb` for 64 bytes, looking up each quarter in a table of 16.
+const AVX512BW: bool = cfg!(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx512bw"
+));
+
+/// Whether the target has `vpermb` for 64 bytes, looking up 64 bytes in a table of 64, and
+/// comparisons of bytes into masks for the lanes in bounds.
+const AVX512VBMI: bool = AVX512BW && cfg!(target_feature = "avx512vbmi");
+
+/// Whether the target has `vpermb` for 32 bytes, looking up 32 bytes in a table of 32.
+const AVX512VBMI_VL: bool = AVX512VBMI && cfg!(target_feature = "avx512vl");
+
+/// Whether the target has `tbl`, looking up 8 or 16 bytes in a table of up to 4 registers of 16.
+const NEON: bool = cfg!(all(
+    target_arch = "aarch64",
+    target_endian = "little",
+    target_feature = "neon"
+));
+
+/// Whether the target has `i8x16.swizzle`, looking up 16 bytes in a table of 16.
+const SIMD128: bool = cfg!(all(target_arch = "wasm32", target_feature = "simd128"));
+
+/// Whether the target has a byte shuffle instruction for vectors of 16 bytes.
+const HAS_SHUFFLE: bool = SSSE3 || NEON || SIMD128;
+
+/// The number of lanes of a byte shuffle, and the largest table it looks up.
+const SHUFFLE_LANES: usize = 16;
+
+/// A lookup table of `SIZE` entries, looked up a vector of indices at a time.
+///
+/// Tables of bytes that fit in a single register, up to 16 entries of `u8` or `i8`, are looked
+/// up with a byte shuffle when the target has one.  Other tables are looked up with a gather.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{lut::SimdLut, usizex4};
+/// let squares = SimdLut::new([0u32, 1, 4, 9, 16, 25]);
+/// let idxs = usizex4::from_array([5, 2, 9, 0]);
+/// // Out of bounds indices look up the default value
+/// assert_eq!(squares.lookup(idxs).to_array(), [25, 4, 0, 0]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SimdLut<T, const SIZE: usize> {
+    table: [T; SIZE],
+}
+
+impl<T, const SIZE: usize> SimdLut<T, SIZE>
+where
+    T: SimdElement + Default,
+{
+    /// Creates a lookup table of the entries of `table`.
+    #[inline]
+    #[must_use]
+    pub const fn new(table: [T; SIZE]) -> Self {
+        Self { table }
+    }
+
+    /// Returns the entries of the table.
+    #[inline]
+    #[must_use]
+    pub const fn as_array(&self) -> &[T; SIZE] {
+        &self.table
+    }
+
+    /// Returns the entry of the table at each lane of `idxs`, or the default value for indices
+    /// that are out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn lookup<const LANES: usize>(&self, idxs: Simd<usize, LANES>) -> Simd<T, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        if HAS_SHUFFLE && core::mem::size_of::<T>() == 1 && SIZE <= SHUFFLE_LANES {
+            self.shuffle(idxs)
+        } else {
+            Simd::gather_or_default(&self.table, idxs)
+        }
+    }
+
+    /// Looks up a table of bytes with byte shuffles of 16 lanes.
+    #[inline]
+    fn shuffle<const LANES: usize>(&self, idxs: Simd<usize, LANES>) -> Simd<T, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        // Out of bounds indices select 0, which is the default value of every byte element
+        let idxs = idxs
+            .simd_lt(Simd::splat(SIZE))
+            .select(idxs, Simd::splat(SHUFFLE_LANES))
+            .cast::<u8>();
+        shuffle_bytes(&self.table, idxs)
+    }
+}
+
+/// Looks up a table of at most 16 entries of a single byte with byte shuffles of 16 lanes.
+///
+/// Indices past the end of the table select 0.
+#[inline]
+fn shuffle_bytes<T, const LANES: usize>(entries: &[T], idxs: Simd<u8, LANES>) -> Simd<T, LANES>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut table = [0u8; SHUFFLE_LANES];
+    for (byte, entry) in table.iter_mut().zip(entries) {
+        // Safety: `T` is a single byte, and every byte is a valid `u8`
+        *byte = unsafe { core::mem::transmute_copy(entry) };
+    }
+    let table = Simd::<u8, SHUFFLE_LANES>::from_array(table);
+
+    let idxs = idxs.to_array();
+    let mut result = [T::default(); LANES];
+    for (result, idxs) in result
+        .chunks_mut(SHUFFLE_LANES)
+        .zip(idxs.chunks(SHUFFLE_LANES))
+    {
+        // Whole chunks are loaded directly, rather than with a masked load
+        let idxs = if idxs.len() == SHUFFLE_LANES {
+            Simd::<u8, SHUFFLE_LANES>::from_slice(idxs)
+        } else {
+            Simd::<u8, SHUFFLE_LANES>::load_or_default(idxs)
+        };
+        let bytes = table.swizzle_dyn(idxs);
+        for (result, byte) in result.iter_mut().zip(bytes.to_array()) {
+            // Safety: `T` is a single byte, and every byte is a valid integer
+            *result = unsafe { core::mem::transmute_copy(&byte) };
+        }
+    }
+    Simd::from_array(result)
+}
+
+/// A lookup table known at compile time, for [`Simd::map_table`].
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{lut::Table, u8x16};
+/// struct Hex;
+///
+/// impl Table for Hex {
+///     type Element = u8;
+///     const TABLE: &'static [u8] = b"0123456789abcdef";
+/// }
+///
+/// let nibbles = u8x16::from_array([
+///     0xd, 0xe, 0xa, 0xd, 0xb, 0xe, 0xe, 0xf, 0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7,
+/// ]);
+/// assert_eq!(nibbles.map_table::<Hex>().to_array(), *b"deadbeef01234567");
+/// ```
+pub trait Table {
+    /// The type of the entries of the table.
+    type Element: SimdElement + Default + 'static;
+
+    /// The entries of the table.
+    const TABLE: &'static [Self::Element];
+}
+
+impl<const LANES: usize> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Maps each lane through the table `M`, or to the default value if it's out of bounds.
+    ///
+    /// This replaces a `match` on each lane of small integers, such as the tags or nibbles of a
+    /// decoder, with a lookup of the whole vector.  Tables of up to 16 entries of `u8` or `i8`
+    /// are looked up with a byte shuffle when the target has one, so a vector of 16 lanes takes
+    /// a single shuffle instruction, with the table as a constant, after clearing out of bounds
+    /// indices.  Other tables are looked up with a gather, as [`SimdLut`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{lut::Table, u8x8};
+    /// // The length of a UTF-8 sequence, by the high nibble of its first byte
+    /// struct Utf8Len;
+    ///
+    /// impl Table for Utf8Len {
+    ///     type Element = u8;
+    ///     const TABLE: &'static [u8] = &[1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 2, 2, 3, 4];
+    /// }
+    ///
+    /// let bytes = u8x8::from_array(*b"a\xc3\xa9\xe2\x82\xac\xf0z");
+    /// let lens = (bytes >> u8x8::splat(4)).map_table::<Utf8Len>();
+    /// assert_eq!(lens.to_array(), [1, 2, 0, 3, 0, 0, 4, 1]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    pub fn map_table<M: Table>(self) -> Simd<M::Element, LANES> {
+        if HAS_SHUFFLE && core::mem::size_of::<M::Element>() == 1 && M::TABLE.len() <= SHUFFLE_LANES
+        {
+            shuffle_bytes(M::TABLE, self)
+        } else {
+            Simd::gather_or_default(M::TABLE, self.cast())
+        }
+    }
+}
+
+/// Returns whether looking up `lanes` byte indices in a table of `table_len` bytes, with
+/// [`lookup16`], [`lookup32`] or [`lookup64`], is a single table lookup instruction on the
+/// target.
+///
+/// Whether each lookup is a single instruction depends on the target features enabled when the
+/// standard library is built:
+///
+/// | Table | Lanes | Instruction | Target |
+/// |-------|-------|-------------|--------|
+/// | 16 | 16 | `pshufb` | x86 with SSSE3 |
+/// | 16 | 32 | `vpshufb` | x86 with AVX2 |
+/// | 16 | 64 | `vpshufb` | x86 with AVX-512BW |
+/// | 16 | 8, 16 | `tbl` | AArch64 (little-endian) with NEON |
+/// | 16 | 16 | `i8x16.swizzle` | `wasm32` with `simd128` |
+/// | 32 | 32 | `vpermb` | x86 with AVX-512VBMI and AVX-512VL |
+/// | 32 | 8, 16 | `tbl` with 2 registers | AArch64 (little-endian) with NEON |
+/// | 64 | 64 | `vpermb` | x86 with AVX-512VBMI |
+/// | 64 | 8, 16 | `tbl` with 4 registers | AArch64 (little-endian) with NEON |
+///
+/// On x86, one more instruction zeroes the lanes of out of bounds indices, which the lookup
+/// instructions of other targets do themselves.  Other lookups take several shuffles, or load
+/// each lane from the table separately.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::lut;
+/// // Split a table of 32 entries into two of 16, unless it can be looked up whole
+/// const SPLIT: bool = !lut::is_single_instruction(32, 16) && lut::is_single_instruction(16, 16);
+/// // Tables of other sizes are never looked up with a single instruction
+/// assert!(!lut::is_single_instruction(24, 16));
+/// ```
+#[must_use]
+#[inline]
+pub const fn is_single_instruction(table_len: usize, lanes: usize) -> bool {
+    match (table_len, lanes) {
+        (16, 8) => NEON,
+        (16, 16) => SSSE3 || NEON || SIMD128,
+        (16, 32) => AVX2,
+        (16, 64) => AVX512BW,
+        (32, 8 | 16) => NEON,
+        (32, 32) => AVX512VBMI_VL,
+        (64, 8 | 16) => NEON,
+        (64, 64) => AVX512VBMI,
+        _ => false,
+    }
+}
+
+/// Looks up each lane of `idxs` in a table of 16 bytes, or returns 0 for indices past its end.
+///
+/// See [`is_single_instruction`] for the vectors of indices this looks up with a single
+/// instruction on each target.  Other vectors of indices are looked up 16 lanes at a time, with
+/// [`Simd::swizzle_dyn`].
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{lut, u8x16, u8x8};
+/// let hex = u8x16::from_array(*b"0123456789abcdef");
+/// let nibbles = u8x8::from_array([0xc, 0xa, 0xf, 0xe, 0x0, 0x1, 16, 255]);
+/// assert_eq!(lut::lookup16(hex, nibbles).to_array(), *b"cafe01\0\0");
+/// ```
+#[must_use]
+#[inline]
+pub fn lookup16<const LANES: usize>(table: Simd<u8, 16>, idxs: Simd<u8, LANES>) -> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #![allow(unused_imports, unused_unsafe)]
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+    use core::arch::aarch64::{vqtbl1_u8, vqtbl1q_u8};
+    #[cfg(target_arch = "wasm32")]
+    use core::arch::wasm32 as wasm;
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64 as x86;
+
+    // Safety: each intrinsic is only used when its target feature is enabled, and on vectors of
+    // the same sizes as the table and `Simd<u8, LANES>`.
+    unsafe {
+        match LANES {
+            #[cfg(all(
+                target_arch = "aarch64",
+                target_endian = "little",
+                target_feature = "neon"
+            ))]
+            8 => transize(table.to_array(), idxs, |t, i| vqtbl1_u8(t, i)),
+            #[cfg(all(
+                target_arch = "aarch64",
+                target_endian = "little",
+                target_feature = "neon"
+            ))]
+            16 => transize(table.to_array(), idxs, |t, i| vqtbl1q_u8(t, i)),
+            #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+            16 => transize(table.to_array(), idxs, |t, i| wasm::i8x16_swizzle(t, i)),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "ssse3"
+            ))]
+            16 => transize(table.to_array(), zeroing_idxs(idxs), |t, i| {
+                x86::_mm_shuffle_epi8(t, i)
+            }),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "avx2"
+            ))]
+            32 => transize(
+                repeat::<LANES>(table).to_array(),
+                zeroing_idxs(idxs),
+                |t, i| x86::_mm256_shuffle_epi8(t, i),
+            ),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "avx512bw"
+            ))]
+            64 => transize(
+                repeat::<LANES>(table).to_array(),
+                zeroing_idxs(idxs),
+                |t, i| x86::_mm512_shuffle_epi8(t, i),
+            ),
+            _ => shuffle_bytes(table.as_array(), idxs),
+        }
+    }
+}
+
+/// Looks up each lane of `idxs` in a table of 32 bytes, or returns 0 for indices past its end.
+///
+/// See [`is_single_instruction`] for the vectors of indices this looks up with a single
+/// instruction on each target.  On x86 with SSSE3 or AVX2, and `wasm32` with `simd128`, other
+/// vectors of indices are looked up with a [`lookup16`] of each 16 bytes of the table, keeping
+/// the lanes whose indices have the high bits of that part.  Other targets use a gather.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{lut, u8x32, u8x4};
+/// // The 5-bit values of the base32 alphabet of RFC 4648
+/// let base32 = u8x32::from_array(*b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+/// let values = u8x4::from_array([2, 0, 27, 32]);
+/// assert_eq!(lut::lookup32(base32, values).to_array(), *b"CA3\0");
+/// ```
+#[must_use]
+#[inline]
+pub fn lookup32<const LANES: usize>(table: Simd<u8, 32>, idxs: Simd<u8, LANES>) -> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #![allow(unused_imports, unused_unsafe)]
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+    use core::arch::aarch64::{vqtbl2_u8, vqtbl2q_u8};
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64 as x86;
+
+    // Safety: each intrinsic is only used when its target feature is enabled, and on vectors of
+    // the same sizes as the table and `Simd<u8, LANES>`.
+    unsafe {
+        match LANES {
+            #[cfg(all(
+                target_arch = "aarch64",
+                target_endian = "little",
+                target_feature = "neon"
+            ))]
+            8 => transize(table.to_array(), idxs, |t, i| vqtbl2_u8(t, i)),
+            #[cfg(all(
+                target_arch = "aarch64",
+                target_endian = "little",
+                target_feature = "neon"
+            ))]
+            16 => transize(table.to_array(), idxs, |t, i| vqtbl2q_u8(t, i)),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "avx512bw",
+                target_feature = "avx512vbmi",
+                target_feature = "avx512vl"
+            ))]
+            32 => transize(table.to_array(), idxs, |t, i| {
+                let in_bounds = x86::_mm256_cmplt_epu8_mask(i, x86::_mm256_set1_epi8(32));
+                x86::_mm256_maskz_permutexvar_epi8(in_bounds, i, t)
+            }),
+            _ if SSSE3 || SIMD128 => lookup_parts(table.to_array(), idxs),
+            _ => Simd::gather_or_default(table.as_array(), idxs.cast()),
+        }
+    }
+}
+
+/// Looks up each lane of `idxs` in a table of 64 bytes, or returns 0 for indices past its end.
+///
+/// See [`is_single_instruction`] for the vectors of indices this looks up with a single
+/// instruction on each target.  On x86 with SSSE3 or AVX2, and `wasm32` with `simd128`, other
+/// vectors of indices are looked up with a [`lookup16`] of each 16 bytes of the table, keeping
+/// the lanes whose indices have the high bits of that part.  Other targets use a gather.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{lut, u8x64, u8x8};
+/// // The 6-bit values of the base64 alphabet
+/// let base64 = u8x64::from_array(
+///     *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+/// );
+/// let values = u8x8::from_array([19, 22, 5, 38, 63, 62, 0, 64]);
+/// assert_eq!(lut::lookup64(base64, values).to_array(), *b"TWFm/+A\0");
+/// ```
+#[must_use]
+#[inline]
+pub fn lookup64<const LANES: usize>(table: Simd<u8, 64>, idxs: Simd<u8, LANES>) -> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #![allow(unused_imports, unused_unsafe)]
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+    use core::arch::aarch64::{vqtbl4_u8, vqtbl4q_u8};
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64 as x86;
+
+    // Safety: each intrinsic is only used when its target feature is enabled, and on vectors of
+    // the same sizes as the table and `Simd<u8, LANES>`.
+    unsafe {
+        match LANES {
+            #[cfg(all(
+                target_arch = "aarch64",
+                target_endian = "little",
+                target_feature = "neon"
+            ))]
+            8 => transize(table.to_array(), idxs, |t, i| vqtbl4_u8(t, i)),
+            #[cfg(all(
+                target_arch = "aarch64",
+                target_endian = "little",
+                target_feature = "neon"
+            ))]
+            16 => transize(table.to_array(), idxs, |t, i| vqtbl4q_u8(t, i)),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "avx512bw",
+                target_feature = "avx512vbmi"
+            ))]
+            64 => transize(table.to_array(), idxs, |t, i| {
+                let in_bounds = x86::_mm512_cmplt_epu8_mask(i, x86::_mm512_set1_epi8(64));
+                x86::_mm512_maskz_permutexvar_epi8(in_bounds, i, t)
+            }),
+            _ if SSSE3 || SIMD128 => lookup_parts(table.to_array(), idxs),
+            _ => Simd::gather_or_default(table.as_array(), idxs.cast()),
+        }
+    }
+}
+
+/// Looks up a table of several parts of 16 bytes with a [`lookup16`] of each part, selecting
+/// the part for each lane by the high bits of its index.
+///
+/// Indices past the end of the table select no part, so they look up 0.
+#[inline(always)]
+fn lookup_parts<const SIZE: usize, const LANES: usize>(
+    table: [u8; SIZE],
+    idxs: Simd<u8, LANES>,
+) -> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let low = idxs & Simd::splat(0xf);
+    let high = idxs >> Simd::splat(4);
+    let mut result = Simd::splat(0);
+    for (part, entries) in table.chunks_exact(SHUFFLE_LANES).enumerate() {
+        let looked_up = lookup16(Simd::<u8, SHUFFLE_LANES>::from_slice(entries), low);
+        result = high
+            .simd_eq(Simd::splat(part as u8))
+            .select(looked_up, result);
+    }
+    result
+}
+
+/// Calls a vendor table lookup on vectors of the same sizes as `table` and `idxs`.
+///
+/// # Safety
+/// `T` must be the size of `[u8; SIZE]`, `I` must be the size of `Simd<u8, LANES>`, and
+/// `lookup` must be supported by the target.
+#[allow(unused)]
+#[inline(always)]
+unsafe fn transize<T, I, const SIZE: usize, const LANES: usize>(
+    table: [u8; SIZE],
+    idxs: Simd<u8, LANES>,
+    lookup: impl FnOnce(T, I) -> I,
+) -> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // Safety: the caller ensures the types have the same sizes, and every bit pattern is a
+    // valid vector of bytes.
+    unsafe {
+        let result = lookup(
+            core::mem::transmute_copy(&table),
+            core::mem::transmute_copy(&idxs),
+        );
+        core::mem::transmute_copy(&result)
+    }
+}
+
+/// Repeats a table of 16 bytes for each 16 lanes, since `vpshufb` looks up each 16 lanes in
+/// their own part of the table.
+#[allow(unused)]
+#[inline(always)]
+fn repeat<const LANES: usize>(table: Simd<u8, 16>) -> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    struct Repeat;
+    impl<const LANES: usize> Swizzle<16, LANES> for Repeat {
+        const INDEX: [usize; LANES] = {
+            let mut index = [0; LANES];
+            let mut i = 0;
+            while i < LANES {
+                index[i] = i % 16;
+                i += 1;
+            }
+            index
+        };
+    }
+    Repeat::swizzle(table)
+}
+
+/// Sets the high bit of indices past the end of a table of 16 bytes, which `pshufb` zeroes.
+///
+/// Adding `0x70` with saturation keeps the low bits of the indices in bounds, so it takes a
+/// single instruction.
+#[allow(unused)]
+#[inline(always)]
+fn zeroing_idxs<const LANES: usize>(idxs: Simd<u8, LANES>) -> Simd<u8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    idxs.saturating_add(Simd::splat(0x70))
+}
@@ -0,0 +1,312 @@
+//! The universal hashes of message authentication codes: Poly1305 and GHASH.
+//!
+//! Both hashes multiply an accumulator by a secret key in a finite field for every 16-byte block
+//! of the message, which is most of the work of the AEADs that use them, ChaCha20-Poly1305 and
+//! AES-GCM.  Each multiplication is split into many independent multiplications of small limbs,
+//! which are computed a vector at a time.  Neither hash branches on or indexes memory with the
+//! key or the message, only the length of the message.
+
+use crate::simd::{simd_swizzle, u64x8};
+
+/// The mask of a limb of a Poly1305 field element.
+const LIMB: u64 = (1 << 26) - 1;
+
+/// The streaming state of a Poly1305 one-time authenticator, as in [RFC 8439].
+///
+/// Field elements, modulo `2^130 - 5`, are held as five limbs of 26 bits in the lanes of a
+/// vector.  Multiplying the accumulator by the key is a sum of five vector products, one for
+/// each limb of the accumulator, and the carries out of every limb are propagated to the next
+/// limb at once.
+///
+/// [RFC 8439]: https://www.rfc-editor.org/rfc/rfc8439
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::mac::Poly1305;
+/// let key = [
+///     0x85, 0xd6, 0xbe, 0x78, 0x57, 0x55, 0x6d, 0x33, 0x7f, 0x44, 0x52, 0xfe, 0x42, 0xd5, 0x06,
+///     0xa8, 0x01, 0x03, 0x80, 0x8a, 0xfb, 0x0d, 0xb2, 0xfd, 0x4a, 0xbf, 0xf6, 0xaf, 0x41, 0x49,
+///     0xf5, 0x1b,
+/// ];
+/// let mut poly = Poly1305::new(&key);
+/// poly.update(b"Cryptographic Forum");
+/// poly.update(b" Research Group");
+/// assert_eq!(
+///     poly.finish(),
+///     [
+///         0xa8, 0x06, 0x1d, 0xc1, 0x30, 0x51, 0x36, 0xc6, 0xc2, 0x2b, 0x8b, 0xaf, 0x0c, 0x01,
+///         0x27, 0xa9,
+///     ]
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Poly1305 {
+    /// The multiples of the limbs of `r` that each limb of the accumulator is multiplied by.
+    rows: [u64x8; 5],
+    /// The limbs of the accumulator, which may be slightly larger than 26 bits.
+    h: u64x8,
+    /// The key added to the accumulator at the end.
+    s: u128,
+    /// The start of a block that hasn't been added to the accumulator yet.
+    buf: [u8; 16],
+    buf_len: usize,
+}
+
+impl Poly1305 {
+    /// Creates the state for an empty message, with a 32-byte one-time key.
+    #[inline]
+    #[must_use]
+    pub fn new(key: &[u8; 32]) -> Self {
+        let (r, s) = key.split_at(16);
+        let r = u128::from_le_bytes(r.try_into().unwrap()) & 0x0ffffffc_0ffffffc_0ffffffc_0fffffff;
+        let r: [u64; 5] = core::array::from_fn(|i| (r >> (26 * i)) as u64 & LIMB);
+
+        // Limb `i` of the product gets `h[j] * r[i - j]`, and since `2^130 = 5` in the field,
+        // `5 * h[j] * r[i - j + 5]` for the limbs that wrap around.
+        let rows = core::array::from_fn(|j| {
+            let mut row = [0; 8];
+            for (i, row) in row[..5].iter_mut().enumerate() {
+                *row = if i >= j { r[i - j] } else { 5 * r[i + 5 - j] };
+            }
+            u64x8::from_array(row)
+        });
+        Self {
+            rows,
+            h: u64x8::splat(0),
+            s: u128::from_le_bytes(s.try_into().unwrap()),
+            buf: [0; 16],
+            buf_len: 0,
+        }
+    }
+
+    /// Appends `data` to the authenticated message.
+    #[inline]
+    pub fn update(&mut self, mut data: &[u8]) {
+        if self.buf_len > 0 {
+            let len = data.len().min(16 - self.buf_len);
+            self.buf[self.buf_len..self.buf_len + len].copy_from_slice(&data[..len]);
+            self.buf_len += len;
+            data = &data[len..];
+            if self.buf_len < 16 {
+                return;
+            }
+            self.block(self.buf, 1);
+            self.buf_len = 0;
+        }
+
+        let mut blocks = data.chunks_exact(16);
+        for block in &mut blocks {
+            self.block(block.try_into().unwrap(), 1);
+        }
+        let rest = blocks.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.buf_len = rest.len();
+    }
+
+    /// Adds a block, with the bit above its last byte set to `high_bit`, to the accumulator and
+    /// multiplies the accumulator by `r`.
+    #[inline]
+    fn block(&mut self, block: [u8; 16], high_bit: u64) {
+        let m = u128::from_le_bytes(block);
+        let mut limbs = [0; 8];
+        for (i, limb) in limbs[..5].iter_mut().enumerate() {
+            *limb = (m >> (26 * i)) as u64 & LIMB;
+        }
+        limbs[4] |= high_bit << 24;
+        let h = (self.h + u64x8::from_array(limbs)).to_array();
+
+        // The limbs of the accumulator are less than 2^27 and the multiples of `r` less than
+        // 2^29, so each limb of the product is less than 2^59.
+        let mut d = u64x8::splat(0);
+        for (h, row) in h.iter().zip(self.rows) {
+            d += u64x8::splat(*h) * row;
+        }
+
+        // Two rounds of carries leave each limb at most 26 bits and a little, which is small
+        // enough for the next block.
+        let weights = u64x8::from_array([5, 1, 1, 1, 1, 0, 0, 0]);
+        for _ in 0..2 {
+            let carries = simd_swizzle!(d >> u64x8::splat(26), [4, 0, 1, 2, 3, 5, 6, 7]);
+            d = (d & u64x8::splat(LIMB)) + carries * weights;
+        }
+        self.h = d;
+    }
+
+    /// Returns the tag of the message so far.
+    #[inline]
+    #[must_use]
+    pub fn finish(&self) -> [u8; 16] {
+        let mut poly = self.clone();
+        if poly.buf_len > 0 {
+            let mut block = [0; 16];
+            block[..poly.buf_len].copy_from_slice(&poly.buf[..poly.buf_len]);
+            block[poly.buf_len] = 1;
+            poly.block(block, 0);
+        }
+
+        let mut h = poly.h.to_array();
+        for _ in 0..2 {
+            for i in 0..5 {
+                let carry = h[i] >> 26;
+                h[i] &= LIMB;
+                if i < 4 {
+                    h[i + 1] += carry;
+                } else {
+                    h[0] += 5 * carry;
+                }
+            }
+        }
+
+        // Subtract `p = 2^130 - 5` if `h >= p`, which is when `h + 5` carries out of 130 bits.
+        let mut g = [0; 5];
+        let mut carry = 5;
+        for i in 0..5 {
+            g[i] = h[i] + carry;
+            carry = g[i] >> 26;
+            g[i] &= LIMB;
+        }
+        let keep_h = carry.wrapping_sub(1);
+        let h = (0..5).fold(0u128, |acc, i| {
+            let limb = h[i] & keep_h | g[i] & !keep_h;
+            acc.wrapping_add(u128::from(limb) << (26 * i))
+        });
+        h.wrapping_add(poly.s).to_le_bytes()
+    }
+}
+
+/// The masks of every fourth bit, starting at each of the lowest four bits.
+const EVERY_FOURTH_BIT: [u64; 4] = [
+    0x1111_1111_1111_1111,
+    0x2222_2222_2222_2222,
+    0x4444_4444_4444_4444,
+    0x8888_8888_8888_8888,
+];
+
+/// Returns the low 64 bits of the carry-less product of each lane of `x` and `y`.
+///
+/// Every fourth bit of each operand is multiplied with integer multiplications, whose carries
+/// land in the three bits between the bits of the product and are masked off.
+#[inline]
+fn clmul_low(x: u64x8, y: u64x8) -> u64x8 {
+    let x = EVERY_FOURTH_BIT.map(|mask| x & u64x8::splat(mask));
+    let y = EVERY_FOURTH_BIT.map(|mask| y & u64x8::splat(mask));
+    let mut z = u64x8::splat(0);
+    for (i, mask) in EVERY_FOURTH_BIT.into_iter().enumerate() {
+        let mut zi = u64x8::splat(0);
+        for (j, x) in x.iter().enumerate() {
+            zi ^= *x * y[(i + 4 - j) % 4];
+        }
+        z |= zi & u64x8::splat(mask);
+    }
+    z
+}
+
+/// The streaming state of GHASH, the universal hash of AES-GCM, as in [NIST SP 800-38D].
+///
+/// Multiplying the accumulator by the hash key in `GF(2^128)` takes three carry-less products of
+/// 64-bit halves, and three more of their bit reversals for the high halves of the products.
+/// The six products are computed in the lanes of a vector, from integer products of every
+/// fourth bit.
+///
+/// [NIST SP 800-38D]: https://csrc.nist.gov/publications/detail/sp/800-38d/final
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::mac::GHash;
+/// // Test case 2 of the GCM specification: the empty additional data and the ciphertext of a
+/// // block of zeros, followed by their lengths in bits
+/// let h = 0x66e94bd4ef8a2c3b884cfa59ca342b2e_u128.to_be_bytes();
+/// let mut ghash = GHash::new(&h);
+/// ghash.update(&0x0388dace60b6a392f328c2b971b2fe78_u128.to_be_bytes());
+/// ghash.update(&128_u128.to_be_bytes());
+/// assert_eq!(ghash.finish(), 0xf38cbb1ad69223dcc3457ae5b6b0f885_u128.to_be_bytes());
+/// ```
+#[derive(Clone)]
+pub struct GHash {
+    /// The halves of the key, their sum, and their bit reversals.
+    h: u64x8,
+    /// The high half of the accumulator.
+    y1: u64,
+    /// The low half of the accumulator.
+    y0: u64,
+}
+
+impl GHash {
+    /// Creates the state for an empty message, with a 16-byte hash key.
+    #[inline]
+    #[must_use]
+    pub fn new(h: &[u8; 16]) -> Self {
+        let h = u128::from_be_bytes(*h);
+        let (h1, h0) = ((h >> 64) as u64, h as u64);
+        let h2 = h0 ^ h1;
+        let [h0r, h1r, h2r] = [h0, h1, h2].map(u64::reverse_bits);
+        Self {
+            h: u64x8::from_array([h0, h1, h2, h0r, h1r, h2r, 0, 0]),
+            y1: 0,
+            y0: 0,
+        }
+    }
+
+    /// Appends `data` to the hashed message, padding it with zeros to a multiple of 16 bytes.
+    ///
+    /// GCM pads the additional data and the ciphertext separately, so each is appended with a
+    /// single call.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        for block in data.chunks(16) {
+            let mut padded = [0; 16];
+            padded[..block.len()].copy_from_slice(block);
+            let block = u128::from_be_bytes(padded);
+            self.block((block >> 64) as u64, block as u64);
+        }
+    }
+
+    /// Adds a block to the accumulator and multiplies the accumulator by the key.
+    #[inline]
+    fn block(&mut self, high: u64, low: u64) {
+        let (y1, y0) = (self.y1 ^ high, self.y0 ^ low);
+        let y2 = y0 ^ y1;
+        let [y0r, y1r, y2r] = [y0, y1, y2].map(u64::reverse_bits);
+        let [z0, z1, mut z2, mut z0h, mut z1h, mut z2h, _, _] =
+            clmul_low(u64x8::from_array([y0, y1, y2, y0r, y1r, y2r, 0, 0]), self.h).to_array();
+
+        // Karatsuba, with the high halves of the products recovered from the low halves of the
+        // products of the reversals.
+        z2 ^= z0 ^ z1;
+        z2h ^= z0h ^ z1h;
+        z0h = z0h.reverse_bits() >> 1;
+        z1h = z1h.reverse_bits() >> 1;
+        z2h = z2h.reverse_bits() >> 1;
+
+        // The bit-reflected product, shifted into place.
+        let v0 = z0;
+        let v1 = z0h ^ z2;
+        let v2 = z1 ^ z2h;
+        let v3 = z1h;
+        let v3 = (v3 << 1) | (v2 >> 63);
+        let v2 = (v2 << 1) | (v1 >> 63);
+        let v1 = (v1 << 1) | (v0 >> 63);
+        let v0 = v0 << 1;
+
+        // Reduction modulo `x^128 + x^7 + x^2 + x + 1`.
+        let v2 = v2 ^ v0 ^ (v0 >> 1) ^ (v0 >> 2) ^ (v0 >> 7);
+        let v1 = v1 ^ (v0 << 63) ^ (v0 << 62) ^ (v0 << 57);
+        let v3 = v3 ^ v1 ^ (v1 >> 1) ^ (v1 >> 2) ^ (v1 >> 7);
+        let v2 = v2 ^ (v1 << 63) ^ (v1 << 62) ^ (v1 << 57);
+        self.y0 = v2;
+        self.y1 = v3;
+    }
+
+    /// Returns the hash of the message so far.
+    #[inline]
+    #[must_use]
+    pub fn finish(&self) -> [u8; 16] {
+        (u128::from(self.y1) << 64 | u128::from(self.y0)).to_be_bytes()
+    }
+}
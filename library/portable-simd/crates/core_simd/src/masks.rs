@@ -18,7 +18,9 @@
 #[cfg(feature = "generic_const_exprs")]
 pub use to_bitmask::{bitmask_len, ToBitMaskArray};
 
-use crate::simd::{intrinsics, LaneCount, Simd, SimdElement, SimdPartialEq, SupportedLaneCount};
+use crate::simd::{
+    intrinsics, LaneCount, Simd, SimdElement, SimdPartialEq, SimdPartialOrd, SupportedLaneCount,
+};
 use core::cmp::Ordering;
 use core::{fmt, mem};
 
@@ -117,6 +119,29 @@ pub fn splat(value: bool) -> Self {
         Self(mask_impl::Mask::splat(value))
     }
 
+    /// Construct a mask with the first `n` lanes set and all other lanes unset.
+    ///
+    /// If `n` is greater than `LANES`, all lanes are set.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Mask;
+    /// let mask = Mask::<i32, 4>::splat_first_n(3);
+    /// assert_eq!(mask.to_array(), [true, true, true, false]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn splat_first_n(n: usize) -> Self {
+        let mut indices = [0; LANES];
+        for (i, index) in indices.iter_mut().enumerate() {
+            *index = i;
+        }
+        Simd::from_array(indices).simd_lt(Simd::splat(n)).cast()
+    }
+
     /// Converts an array of bools to a SIMD mask.
     pub fn from_array(array: [bool; LANES]) -> Self {
         // SAFETY: Rust's bool has a layout of 1 byte (u8) with a value of
@@ -186,6 +211,23 @@ pub fn to_int(self) -> Simd<T, LANES> {
     }
 
     /// Converts the mask to a mask of any other lane size.
+    ///
+    /// The truth value of each lane is preserved, whether the lanes are widened or narrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdPartialEq};
+    /// let bytes = Simd::from_array([b'a', b' ', b'b', b' ']);
+    /// let spaces: Mask<i8, 4> = bytes.simd_eq(Simd::splat(b' '));
+    ///
+    /// // Use a comparison of bytes to select between wider lanes
+    /// let counts = Simd::from_array([10u32, 20, 30, 40]);
+    /// let counts = spaces.cast::<i32>().select(counts + Simd::splat(1), counts);
+    /// assert_eq!(counts.to_array(), [10, 21, 30, 41]);
+    /// ```
     #[inline]
     #[must_use = "method returns a new mask and does not mutate the original value"]
     pub fn cast<U: MaskElement>(self) -> Mask<U, LANES> {
@@ -253,6 +295,36 @@ pub fn any(self) -> bool {
     pub fn all(self) -> bool {
         self.0.all()
     }
+
+    /// Returns a mask with the lanes that are set in exactly one of `self` and `other`.
+    ///
+    /// This is equivalent to `self ^ other`.
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn symmetric_difference(self, other: Self) -> Self {
+        self ^ other
+    }
+
+    /// Returns a mask with the lanes where `self` implies `other`: each lane is set unless it is
+    /// set in `self` and unset in `other`.
+    ///
+    /// This is equivalent to `!self | other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Mask;
+    /// let a = Mask::<i32, 4>::from_array([false, false, true, true]);
+    /// let b = Mask::<i32, 4>::from_array([false, true, false, true]);
+    /// assert_eq!(a.implies(b).to_array(), [true, true, false, true]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn implies(self, other: Self) -> Self {
+        !self | other
+    }
 }
 
 // vector/array conversion
@@ -0,0 +1,496 @@
+//! Filling, copying, segmenting and gathering memory a vector at a time.
+//!
+//! The destination is split into a vector-aligned middle, which is written with aligned stores
+//! of a whole vector, and unaligned edges, which are covered by one unaligned vector store at
+//! each end, overlapping the middle.  Destinations shorter than a vector are written with a
+//! single masked store.  Buffers too large to fit in cache are written with non-temporal stores,
+//! so they don't evict data that is still in use.
+//!
+//! [`PipelinedGather`] hides the latency of gathers from memory by prefetching ahead of them,
+//! and [`AlignedSlice`] proves that a slice is aligned so its vectors can be loaded without
+//! checking.
+
+use crate::simd::{u8x64, LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+use core::iter::Fuse;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::sync::atomic::{fence, Ordering};
+
+/// Buffers at least this long are written with non-temporal stores.
+const NONTEMPORAL_THRESHOLD: usize = 1 << 20;
+
+/// Splits `dst` into unaligned edges and a vector-aligned middle.
+#[inline]
+fn split_aligned(dst: &mut [u8]) -> (&mut [u8], &mut [u8x64], &mut [u8]) {
+    // Safety: every byte pattern is a valid vector of bytes.
+    unsafe { dst.align_to_mut::<u8x64>() }
+}
+
+/// Writes each of `values` to the corresponding element of `dst`.
+#[inline]
+fn store_aligned(dst: &mut [u8x64], values: impl Fn(usize) -> u8x64) {
+    if dst.len() * u8x64::LANES < NONTEMPORAL_THRESHOLD {
+        for (i, vector) in dst.iter_mut().enumerate() {
+            *vector = values(i);
+        }
+    } else {
+        for (i, vector) in dst.iter_mut().enumerate() {
+            // Safety: the pointer comes from a reference, so it's valid for writes and aligned.
+            unsafe { core::intrinsics::nontemporal_store(vector, values(i)) }
+        }
+        // Non-temporal stores are weakly ordered, so they must be fenced before returning
+        fence(Ordering::SeqCst);
+    }
+}
+
+/// Sets every byte of `dst` to `value`.
+///
+/// This is equivalent to `dst.fill(value)`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::mem;
+/// let mut buffer = [0u8; 100];
+/// mem::fill(&mut buffer[3..], 0xaa);
+/// assert_eq!(buffer[..3], [0; 3]);
+/// assert!(buffer[3..].iter().all(|x| *x == 0xaa));
+/// ```
+#[inline]
+pub fn fill(dst: &mut [u8], value: u8) {
+    let value = u8x64::splat(value);
+    let len = dst.len();
+    if len < u8x64::LANES {
+        value.store_select(dst, Mask::splat(true));
+        return;
+    }
+    dst[..u8x64::LANES].copy_from_slice(value.as_array());
+    dst[len - u8x64::LANES..].copy_from_slice(value.as_array());
+    let (_, middle, _) = split_aligned(dst);
+    store_aligned(middle, |_| value);
+}
+
+/// Copies every byte of `src` to `dst`.
+///
+/// This is equivalent to `dst.copy_from_slice(src)`.  Stores to the middle of `dst` are aligned,
+/// while loads from `src` may not be.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::mem;
+/// let src: Vec<u8> = (0..200).collect();
+/// let mut dst = [0u8; 150];
+/// mem::copy(&mut dst, &src[50..]);
+/// assert_eq!(dst[..], src[50..]);
+/// ```
+#[inline]
+pub fn copy(dst: &mut [u8], src: &[u8]) {
+    assert_eq!(
+        dst.len(),
+        src.len(),
+        "source and destination must have the same length"
+    );
+
+    let len = dst.len();
+    if len < u8x64::LANES {
+        u8x64::load_or_default(src).store_select(dst, Mask::splat(true));
+        return;
+    }
+    dst[..u8x64::LANES].copy_from_slice(&src[..u8x64::LANES]);
+    dst[len - u8x64::LANES..].copy_from_slice(&src[len - u8x64::LANES..]);
+    let (head, middle, _) = split_aligned(dst);
+    let src_middle = &src[head.len()..];
+    store_aligned(middle, |i| {
+        u8x64::from_slice(&src_middle[i * u8x64::LANES..])
+    });
+}
+
+/// A segment of a byte slice split by [`segments`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Segment<'a, const N: usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Bytes before or after the aligned vectors.
+    Bytes(&'a [u8]),
+    /// Aligned vectors of bytes.
+    Vectors(&'a [Simd<u8, N>]),
+}
+
+/// An iterator over the segments of a byte slice, created by [`segments`].
+#[derive(Clone, Debug)]
+pub struct Segments<'a, const N: usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    head: &'a [u8],
+    middle: &'a [Simd<u8, N>],
+    tail: &'a [u8],
+}
+
+impl<'a, const N: usize> Iterator for Segments<'a, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Item = Segment<'a, N>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.head.is_empty() {
+            Some(Segment::Bytes(core::mem::take(&mut self.head)))
+        } else if !self.middle.is_empty() {
+            Some(Segment::Vectors(core::mem::take(&mut self.middle)))
+        } else if !self.tail.is_empty() {
+            Some(Segment::Bytes(core::mem::take(&mut self.tail)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Splits `bytes` into unaligned bytes, aligned vectors, and the remaining bytes, in order.
+///
+/// The vectors start at an address that is a multiple of `ALIGN`, or of the alignment of
+/// `Simd<u8, N>` if that is larger, and are as many as possible.  So there are fewer than
+/// `ALIGN` bytes before them and fewer than `N` after them.  Empty segments are skipped.
+///
+/// Checksums and ciphers process the bytes one at a time and the vectors a vector at a time,
+/// while processing the whole slice in order.
+///
+/// # Panics
+///
+/// Panics if `ALIGN` isn't a power of two.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{mem::{self, Segment}, u8x16, SimdUint};
+/// let data: Vec<u8> = (0..100).collect();
+/// let mut sum = 0u32;
+/// for segment in mem::segments::<16, 64>(&data[3..]) {
+///     match segment {
+///         Segment::Bytes(bytes) => sum += bytes.iter().map(|x| *x as u32).sum::<u32>(),
+///         Segment::Vectors(vectors) => {
+///             assert_eq!(vectors.as_ptr() as usize % 64, 0);
+///             sum += vectors.iter().map(|v| v.cast::<u32>().reduce_sum()).sum::<u32>();
+///         }
+///     }
+/// }
+/// assert_eq!(sum, (3..100).sum());
+/// ```
+#[inline]
+pub fn segments<const N: usize, const ALIGN: usize>(bytes: &[u8]) -> Segments<'_, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    assert!(ALIGN.is_power_of_two(), "alignment must be a power of two");
+    let align = ALIGN.max(core::mem::align_of::<Simd<u8, N>>());
+    let head_len = bytes.as_ptr().align_offset(align).min(bytes.len());
+    let (head, rest) = bytes.split_at(head_len);
+    let (middle, tail) = rest.split_at(rest.len() / N * N);
+    let middle = if middle.is_empty() {
+        &[]
+    } else {
+        // Safety: `middle` is aligned to at least the alignment of `Simd<u8, N>`, holds a whole
+        // number of vectors, and every bit pattern is a valid vector of bytes.
+        unsafe { core::slice::from_raw_parts(middle.as_ptr().cast(), middle.len() / N) }
+    };
+    Segments { head, middle, tail }
+}
+
+/// An iterator gathering from a slice at each vector of indices, created by
+/// [`PipelinedGather::new`].
+///
+/// A loop that gathers at each index vector as soon as it's computed waits for every gather's
+/// loads to arrive from memory before the next one starts.  Instead, this computes the index
+/// vectors `DEPTH` iterations ahead and prefetches the elements they point to, so by the time
+/// they're gathered the loads of `DEPTH` vectors have been in flight at once.  The index
+/// iterator is only advanced as the pipeline needs it, so computing the indices overlaps with
+/// the memory accesses too.
+///
+/// Indices that are out of bounds gather the default value, as [`Simd::gather_or_default`] does,
+/// and aren't prefetched.  The best depth depends on the latency of memory and how much work is
+/// done with each gathered vector; a handful of vectors usually covers the latency of main memory.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{mem::PipelinedGather, usizex4, SimdUint};
+/// let table: Vec<u32> = (0..1000).map(|x| x * x).collect();
+/// // Pseudo-random indices, computed as they're needed
+/// let idxs = (0..100).map(|i| usizex4::from_array([i, i * 7, i * 31, i * 997]) % usizex4::splat(1000));
+///
+/// let mut sum = 0u32;
+/// for vector in PipelinedGather::<_, _, 4, 8>::new(&table, idxs) {
+///     sum = sum.wrapping_add(vector.reduce_sum());
+/// }
+/// # let expected = (0..100).flat_map(|i| [i, i * 7 % 1000, i * 31 % 1000, i * 997 % 1000]);
+/// # assert_eq!(sum, expected.fold(0u32, |sum, i| sum.wrapping_add(table[i])));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PipelinedGather<'a, T, I, const LANES: usize, const DEPTH: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// The slice to gather from.
+    slice: &'a [T],
+    /// The index vectors that haven't been computed yet.
+    idxs: Fuse<I>,
+    /// Index vectors that have been prefetched, the oldest at `head`.
+    pending: [Simd<usize, LANES>; DEPTH],
+    /// The position of the oldest index vector in `pending`.
+    head: usize,
+    /// The number of index vectors in `pending`.
+    len: usize,
+}
+
+impl<'a, T, I, const LANES: usize, const DEPTH: usize> PipelinedGather<'a, T, I, LANES, DEPTH>
+where
+    T: SimdElement + Default,
+    I: Iterator<Item = Simd<usize, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates an iterator gathering from `slice` at each vector of `idxs`, prefetching `DEPTH`
+    /// vectors ahead.
+    ///
+    /// The first `DEPTH` index vectors are computed and prefetched immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `DEPTH` is zero.
+    #[inline]
+    pub fn new(slice: &'a [T], idxs: impl IntoIterator<IntoIter = I>) -> Self {
+        assert!(DEPTH > 0, "the pipeline must be at least one vector deep");
+        let mut gather = Self {
+            slice,
+            idxs: idxs.into_iter().fuse(),
+            pending: [Simd::splat(0); DEPTH],
+            head: 0,
+            len: 0,
+        };
+        while gather.len < DEPTH {
+            match gather.idxs.next() {
+                Some(idxs) => {
+                    gather.prefetch(idxs);
+                    gather.pending[gather.len] = idxs;
+                    gather.len += 1;
+                }
+                None => break,
+            }
+        }
+        gather
+    }
+
+    /// Prefetches the elements at each lane of `idxs` that is in bounds.
+    #[inline]
+    fn prefetch(&self, idxs: Simd<usize, LANES>) {
+        for idx in idxs.to_array() {
+            if let Some(element) = self.slice.get(idx) {
+                // Safety: prefetching has no effect on the behavior of the program
+                unsafe { core::intrinsics::prefetch_read_data(element, 3) }
+            }
+        }
+    }
+}
+
+impl<'a, T, I, const LANES: usize, const DEPTH: usize> Iterator
+    for PipelinedGather<'a, T, I, LANES, DEPTH>
+where
+    T: SimdElement + Default,
+    I: Iterator<Item = Simd<usize, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Item = Simd<T, LANES>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let idxs = self.pending[self.head];
+        // The pipeline is full until the index vectors run out, so the next one takes the place
+        // of the oldest
+        match self.idxs.next() {
+            Some(next) => {
+                self.prefetch(next);
+                self.pending[self.head] = next;
+            }
+            None => self.len -= 1,
+        }
+        self.head = (self.head + 1) % DEPTH;
+        Some(Simd::gather_or_default(self.slice, idxs))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.idxs.size_hint();
+        (
+            lower.saturating_add(self.len),
+            upper.and_then(|upper| upper.checked_add(self.len)),
+        )
+    }
+}
+
+/// A slice of elements starting at the alignment of a vector of `LANES` of them.
+///
+/// Code holding an `AlignedSlice` has proven once, when it was created, that its vectors are
+/// aligned, so loads with [`Simd::from_slice_aligned`] and [`as_vectors`](Self::as_vectors)
+/// never check the alignment or handle an unaligned start.  Since the compiler knows the loads
+/// are aligned, it can use aligned load instructions, or fold the loads into the operands of
+/// instructions that require alignment, such as SSE arithmetic on x86.
+///
+/// It dereferences to the slice of elements.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{mem::AlignedSlice, f32x4, SimdFloat};
+/// let vectors = [f32x4::splat(1.0), f32x4::splat(2.0), f32x4::splat(3.0)];
+/// let slice = AlignedSlice::from_vectors(&vectors);
+/// assert_eq!(slice.len(), 12);
+///
+/// let (head, rest) = slice.split_at(1);
+/// assert_eq!(f32x4::from_slice_aligned(head), f32x4::splat(1.0));
+/// let mut sum = f32x4::splat(0.0);
+/// for vector in rest.as_vectors().0 {
+///     sum += vector;
+/// }
+/// assert_eq!(sum.reduce_sum(), 20.0);
+///
+/// // Slices that aren't aligned are rejected
+/// assert!(AlignedSlice::<f32, 4>::new(&slice[1..]).is_none());
+/// ```
+#[derive(Debug, PartialEq)]
+#[repr(transparent)]
+pub struct AlignedSlice<T, const LANES: usize>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// The vector type whose alignment the elements start at.
+    vector: PhantomData<Simd<T, LANES>>,
+    /// The elements, starting at the alignment of `Simd<T, LANES>`.
+    elements: [T],
+}
+
+impl<T, const LANES: usize> AlignedSlice<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Returns `slice` as an aligned slice, or `None` if it doesn't start at the alignment of
+    /// `Simd<T, LANES>`.
+    #[inline]
+    #[must_use]
+    pub fn new(slice: &[T]) -> Option<&Self> {
+        let align = core::mem::align_of::<Simd<T, LANES>>();
+        if slice.as_ptr() as usize & (align - 1) == 0 {
+            // Safety: the slice is aligned
+            Some(unsafe { Self::from_slice_unchecked(slice) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `slice` as an aligned slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` doesn't start at the alignment of `Simd<T, LANES>`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn assert_aligned(slice: &[T]) -> &Self {
+        Self::new(slice).expect("slice must be aligned to the vector type")
+    }
+
+    /// Returns the elements of `vectors` as an aligned slice.
+    #[inline]
+    #[must_use]
+    pub fn from_vectors(vectors: &[Simd<T, LANES>]) -> &Self {
+        // Safety: the vectors are aligned, and hold `LANES` elements each without padding
+        let slice = unsafe {
+            core::slice::from_raw_parts(vectors.as_ptr().cast::<T>(), vectors.len() * LANES)
+        };
+        // Safety: the slice starts at the first vector, so it is aligned
+        unsafe { Self::from_slice_unchecked(slice) }
+    }
+
+    /// Returns `slice` as an aligned slice, without checking its alignment.
+    ///
+    /// # Safety
+    ///
+    /// `slice` must start at the alignment of `Simd<T, LANES>`.
+    #[inline]
+    unsafe fn from_slice_unchecked(slice: &[T]) -> &Self {
+        // Safety: `Self` is a transparent wrapper of `[T]`, and the caller ensures the slice is
+        // aligned
+        unsafe { &*(slice as *const [T] as *const Self) }
+    }
+
+    /// Returns the elements.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    /// Returns the whole vectors of the slice, and the elements after them.
+    #[inline]
+    #[must_use]
+    pub fn as_vectors(&self) -> (&[Simd<T, LANES>], &[T]) {
+        let vectors = self.elements.len() / LANES;
+        let (whole, tail) = self.elements.split_at(vectors * LANES);
+        // Safety: the elements are aligned, and `LANES` elements make a vector without padding
+        let whole = unsafe { core::slice::from_raw_parts(whole.as_ptr().cast(), vectors) };
+        (whole, tail)
+    }
+
+    /// Divides the slice in two after `vectors` vectors, so both halves are aligned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice has fewer than `vectors * LANES` elements.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn split_at(&self, vectors: usize) -> (&Self, &Self) {
+        let (head, tail) = self.elements.split_at(vectors * LANES);
+        // Safety: `head` starts where `self` does, and `tail` starts a whole number of vectors
+        // later, which is a multiple of the alignment since the size of a vector is
+        unsafe {
+            (
+                Self::from_slice_unchecked(head),
+                Self::from_slice_unchecked(tail),
+            )
+        }
+    }
+}
+
+impl<T, const LANES: usize> Deref for AlignedSlice<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.elements
+    }
+}
@@ -6,27 +6,111 @@
 #[cfg(feature = "generic_const_exprs")]
 mod to_bytes;
 
+mod accumulate;
+pub mod automata;
+pub mod bitpack;
+pub mod bitset;
+pub mod bytes;
+pub mod checksum;
+pub mod crypto;
+pub mod ct;
+pub mod delta;
+pub mod dict;
+pub mod distance;
+pub mod dsp;
 mod elements;
 mod eq;
-mod fmt;
+pub mod fft;
+pub mod fmt;
+pub mod geom;
+pub mod half;
+pub mod hash;
+pub mod id;
+mod index;
+pub mod interval;
 mod iter;
+pub mod json;
 mod lane_count;
+pub mod linalg;
+pub mod lut;
+pub mod mac;
 mod masks;
+pub mod mem;
+pub mod net;
+pub mod nn;
+pub mod noise;
+pub mod nullable;
 mod ops;
 mod ord;
-mod select;
+pub mod quant;
+pub mod rand;
+pub mod reduce;
+mod reshape;
+pub mod scan;
+pub mod search;
+pub mod select;
+mod shift;
+pub mod slice;
+pub mod sort;
+pub mod spatial;
+pub mod stats;
+mod swizzle_dyn;
+pub mod unicode;
+pub mod varint;
 mod vector;
 mod vendor;
+mod zip;
 
 #[doc = include_str!("core_simd_docs.md")]
 pub mod simd {
     pub(crate) use crate::core_simd::intrinsics;
 
+    pub use crate::core_simd::automata;
+    pub use crate::core_simd::bitpack;
+    pub use crate::core_simd::bitset;
+    pub use crate::core_simd::bytes;
+    pub use crate::core_simd::checksum;
+    pub use crate::core_simd::crypto;
+    pub use crate::core_simd::ct;
+    pub use crate::core_simd::delta;
+    pub use crate::core_simd::dict;
+    pub use crate::core_simd::distance;
+    pub use crate::core_simd::dsp;
     pub use crate::core_simd::elements::*;
     pub use crate::core_simd::eq::*;
+    pub use crate::core_simd::fft;
+    pub use crate::core_simd::fmt;
+    pub use crate::core_simd::geom;
+    pub use crate::core_simd::half;
+    pub use crate::core_simd::hash;
+    pub use crate::core_simd::id;
+    pub use crate::core_simd::interval;
+    pub use crate::core_simd::json;
     pub use crate::core_simd::lane_count::{LaneCount, SupportedLaneCount};
+    pub use crate::core_simd::linalg;
+    pub use crate::core_simd::lut;
+    pub use crate::core_simd::mac;
     pub use crate::core_simd::masks::*;
+    pub use crate::core_simd::mem;
+    pub use crate::core_simd::net;
+    pub use crate::core_simd::nn;
+    pub use crate::core_simd::noise;
+    pub use crate::core_simd::nullable;
     pub use crate::core_simd::ord::*;
+    pub use crate::core_simd::quant;
+    pub use crate::core_simd::rand;
+    pub use crate::core_simd::reduce;
+    pub use crate::core_simd::reshape::SameBits;
+    pub use crate::core_simd::scan;
+    pub use crate::core_simd::search;
+    pub use crate::core_simd::select;
+    pub use crate::core_simd::slice;
+    pub use crate::core_simd::sort;
+    pub use crate::core_simd::spatial;
+    pub use crate::core_simd::stats;
     pub use crate::core_simd::swizzle::*;
+    pub use crate::core_simd::unicode;
+    pub use crate::core_simd::varint;
     pub use crate::core_simd::vector::*;
+    pub use crate::core_simd::zip::*;
 }
@@ -0,0 +1,227 @@
+//! Parsing and formatting of IP addresses.
+//!
+//! An IPv4 address in dotted decimal notation is at most 15 bytes long, so it is parsed in a
+//! single vector: every byte is validated at once, and the digits of each octet are moved into
+//! place with a dynamic swizzle and combined with a multiplication.  Formatting converts every
+//! octet or group of an address to digits at once.
+
+use crate::simd::{
+    u16x16, u16x4, u16x8, u8x16, u8x8, SimdOrd, SimdPartialEq, SimdPartialOrd, SimdUint, ToBitMask,
+};
+
+/// The longest IPv4 address in dotted decimal notation.
+const IPV4_LEN: usize = 15;
+
+/// Parses an IPv4 address in dotted decimal notation, such as `192.168.0.1`.
+///
+/// Returns `None` if `text` isn't four decimal octets between 0 and 255 separated by dots.  As
+/// with [`Ipv4Addr`](https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html), octets with
+/// leading zeros are rejected, since some parsers read them as octal.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::net;
+/// assert_eq!(net::parse_ipv4(b"192.168.0.1"), Some([192, 168, 0, 1]));
+/// assert_eq!(net::parse_ipv4(b"255.255.255.256"), None);
+/// assert_eq!(net::parse_ipv4(b"10.0.0.01"), None);
+/// ```
+#[must_use]
+#[inline]
+pub fn parse_ipv4(text: &[u8]) -> Option<[u8; 4]> {
+    if !(7..=IPV4_LEN).contains(&text.len()) {
+        return None;
+    }
+    let in_bounds = (1u16 << text.len()) - 1;
+
+    let bytes = u8x16::load_or_default(text);
+    let digits = bytes - u8x16::splat(b'0');
+    let is_digit = digits.simd_lt(u8x16::splat(10));
+    let is_dot = bytes.simd_eq(u8x16::splat(b'.'));
+    if (is_digit | is_dot).to_bitmask() & in_bounds != in_bounds {
+        return None;
+    }
+    let dots = is_dot.to_bitmask() & in_bounds;
+    if dots.count_ones() != 3 {
+        return None;
+    }
+
+    // Lane `k` of group `i` selects the digit of octet `i` with weight `10^(3 - k)`, so the
+    // digits of each octet are aligned to the end of its group, and the rest of the lanes are
+    // zeroed by selecting out of bounds.
+    let mut idxs = [u8::MAX; 16];
+    let mut ends = u32::from(dots) | 1 << text.len();
+    let mut start = 0;
+    for group in idxs.chunks_exact_mut(4) {
+        let end = ends.trailing_zeros() as usize;
+        ends &= ends - 1;
+        let len = end - start;
+        if len == 0 || len > 3 || (len > 1 && text[start] == b'0') {
+            return None;
+        }
+        for (idx, i) in group[4 - len..].iter_mut().zip(start..end) {
+            *idx = i as u8;
+        }
+        start = end + 1;
+    }
+
+    let aligned = digits.swizzle_dyn(u8x16::from_array(idxs)).cast::<u16>();
+    let weights = u16x16::from_array([0, 100, 10, 1, 0, 100, 10, 1, 0, 100, 10, 1, 0, 100, 10, 1]);
+    let weighted = (aligned * weights).to_array();
+    let mut octets = [0; 4];
+    for (octet, weighted) in octets.iter_mut().zip(weighted.chunks_exact(4)) {
+        *octet = u8::try_from(weighted.iter().sum::<u16>()).ok()?;
+    }
+    Some(octets)
+}
+
+/// Writes each of `addrs` in dotted decimal notation into `buf`, separated by `separator`.
+///
+/// Returns the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if `buf` is too small to hold the formatted addresses.
+/// Each address takes at most 15 bytes, plus one byte for each separator.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::net;
+/// let mut buf = [0; 64];
+/// let len = net::format_ipv4(&mut buf, &[[127, 0, 0, 1], [192, 168, 20, 255]], b' ');
+/// assert_eq!(&buf[..len], b"127.0.0.1 192.168.20.255");
+/// ```
+#[inline]
+pub fn format_ipv4(buf: &mut [u8], addrs: &[[u8; 4]], separator: u8) -> usize {
+    let mut len = 0;
+    for (i, addr) in addrs.iter().enumerate() {
+        if i != 0 {
+            buf[len] = separator;
+            len += 1;
+        }
+        let x = u16x4::from_array(addr.map(u16::from));
+        // x / 100 == (x * 41) >> 12 for all x < 256
+        let hundreds = (x * u16x4::splat(41)) >> u16x4::splat(12);
+        let rest = x - hundreds * u16x4::splat(100);
+        // x / 10 == (x * 103) >> 10 for all x < 100
+        let tens = (rest * u16x4::splat(103)) >> u16x4::splat(10);
+        let ones = rest - tens * u16x4::splat(10);
+        let [hundreds, tens, ones] = [hundreds, tens, ones].map(|digit| {
+            (digit + u16x4::splat(u16::from(b'0')))
+                .cast::<u8>()
+                .to_array()
+        });
+
+        for (octet, value) in addr.iter().enumerate() {
+            if octet != 0 {
+                buf[len] = b'.';
+                len += 1;
+            }
+            let digits = [hundreds[octet], tens[octet], ones[octet]];
+            let octet_len = match value {
+                0..=9 => 1,
+                10..=99 => 2,
+                _ => 3,
+            };
+            buf[len..len + octet_len].copy_from_slice(&digits[3 - octet_len..]);
+            len += octet_len;
+        }
+    }
+    len
+}
+
+/// Writes each of `addrs`, as eight 16-bit groups, in IPv6 notation into `buf`, separated by
+/// `separator`.
+///
+/// Addresses are written in the canonical form of [RFC 5952]: groups are lowercase hexadecimal
+/// without leading zeros, and the longest run of two or more zero groups, or the first of the
+/// longest runs, is replaced by `::`.  Unlike the standard library, IPv4-mapped addresses are
+/// written in hexadecimal too.
+///
+/// Returns the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if `buf` is too small to hold the formatted addresses.
+/// Each address takes at most 39 bytes, plus one byte for each separator.
+///
+/// [RFC 5952]: https://www.rfc-editor.org/rfc/rfc5952
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::net;
+/// let mut buf = [0; 64];
+/// let addrs = [[0x2001, 0xdb8, 0, 0, 0, 0xff00, 0x42, 0x8329], [0, 0, 0, 0, 0, 0, 0, 1]];
+/// let len = net::format_ipv6(&mut buf, &addrs, b',');
+/// assert_eq!(&buf[..len], b"2001:db8::ff00:42:8329,::1");
+/// ```
+#[inline]
+pub fn format_ipv6(buf: &mut [u8], addrs: &[[u16; 8]], separator: u8) -> usize {
+    let mut len = 0;
+    for (i, addr) in addrs.iter().enumerate() {
+        if i != 0 {
+            buf[len] = separator;
+            len += 1;
+        }
+        let x = u16x8::from_array(*addr);
+        let digits = [12, 8, 4, 0].map(|shift| {
+            let nibbles = ((x >> u16x8::splat(shift)) & u16x8::splat(0xf)).cast::<u8>();
+            nibbles
+                .simd_lt(u8x8::splat(10))
+                .select(
+                    nibbles + u8x8::splat(b'0'),
+                    nibbles + u8x8::splat(b'a' - 10),
+                )
+                .to_array()
+        });
+        // The number of digits of each group, without leading zeros
+        let group_lens = ((u16x8::splat(19) - x.leading_zeros()) >> u16x8::splat(2))
+            .simd_max(u16x8::splat(1))
+            .to_array();
+
+        let (run_start, run_len) = longest_run(x.simd_eq(u16x8::splat(0)).to_bitmask());
+        let mut group = 0;
+        while group < 8 {
+            if group == run_start && run_len >= 2 {
+                buf[len..len + 2].copy_from_slice(b"::");
+                len += 2;
+                group += run_len;
+                continue;
+            }
+            if group != 0 && (group != run_start + run_len || run_len < 2) {
+                buf[len] = b':';
+                len += 1;
+            }
+            let group_len = group_lens[group] as usize;
+            for digits in &digits[4 - group_len..] {
+                buf[len] = digits[group];
+                len += 1;
+            }
+            group += 1;
+        }
+    }
+    len
+}
+
+/// Returns the start and length of the first longest run of set bits in `bits`.
+#[inline]
+fn longest_run(bits: u8) -> (usize, usize) {
+    let mut longest = (0, 0);
+    let mut i = 0;
+    while i < 8 {
+        let len = (bits >> i).trailing_ones() as usize;
+        if len > longest.1 {
+            longest = (i, len);
+        }
+        i += len.max(1);
+    }
+    longest
+}
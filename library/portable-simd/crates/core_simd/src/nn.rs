@@ -0,0 +1,189 @@
+//! Building blocks for neural network inference.
+
+use crate::simd::{f32x16, LaneCount, Simd, SimdFloat, SupportedLaneCount};
+
+/// Computes `e^x` for each lane, to within a couple of ULPs.  NaN lanes stay NaN.
+///
+/// The argument is reduced to `x = n ln(2) + r`, with `|r| <= ln(2) / 2`, and `e^r` approximated
+/// by a polynomial (from Cephes) before scaling by `2^n`.
+#[inline]
+fn exp<const LANES: usize>(x: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // Beyond these bounds the result overflows to infinity, or underflows to zero.  Clamping
+    // would replace NaN with a bound, so NaN lanes are kept as they are.
+    let x = x
+        .is_nan()
+        .select(x, x.simd_clamp(Simd::splat(-104.0), Simd::splat(88.8)));
+
+    // Adding and subtracting 1.5 * 2^23 rounds to an integer
+    const ROUND: f32 = 12582912.0;
+    let n = (x * Simd::splat(core::f32::consts::LOG2_E) + Simd::splat(ROUND)) - Simd::splat(ROUND);
+
+    // ln(2) split into a part exactly representable in a few bits, and the remainder
+    let r = x - n * Simd::splat(0.693_359_4) - n * Simd::splat(-2.121_944_4e-4);
+
+    let mut p = Simd::splat(1.987_569_1e-4);
+    for c in [
+        1.398_199_9e-3,
+        8.333_452e-3,
+        4.166_579_6e-2,
+        0.166_666_65,
+        0.5,
+    ] {
+        p = p * r + Simd::splat(c);
+    }
+    let p = p * r * r + r + Simd::splat(1.0);
+
+    // 2^n may not be representable, so scale by two halves of it
+    let n = n.cast::<i32>();
+    let half = n >> Simd::splat(1);
+    let pow2 = |n: Simd<i32, LANES>| {
+        Simd::<f32, LANES>::from_bits(((n + Simd::splat(127)) << Simd::splat(23)).cast())
+    };
+    p * pow2(half) * pow2(n - half)
+}
+
+/// A running maximum and sum of exponentials, the first pass of a softmax.
+///
+/// To avoid overflow, the exponentials are summed relative to the maximum, as
+/// `sum(e^(x - max))`.  The maximum and sum are updated together, so the input may be streamed
+/// in any number of slices.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::nn::ExpSum;
+/// let mut exp_sum = ExpSum::new();
+/// exp_sum.update(&[1.0, 2.0]);
+/// exp_sum.update(&[1000.0, 1000.0]);
+/// assert_eq!(exp_sum.max(), 1000.0);
+/// assert_eq!(exp_sum.sum(), 2.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExpSum {
+    max: f32,
+    sum: f32,
+}
+
+impl ExpSum {
+    /// Creates the state for an empty input.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max: f32::NEG_INFINITY,
+            sum: 0.0,
+        }
+    }
+
+    /// Returns the maximum of the input so far, or negative infinity if it is empty.
+    #[inline]
+    #[must_use]
+    pub const fn max(&self) -> f32 {
+        self.max
+    }
+
+    /// Returns the sum of `e^(x - max)` over the input so far.
+    #[inline]
+    #[must_use]
+    pub const fn sum(&self) -> f32 {
+        self.sum
+    }
+
+    /// Appends `input` to the input.
+    #[inline]
+    pub fn update(&mut self, input: &[f32]) {
+        if input.is_empty() {
+            return;
+        }
+
+        // Starting from the smallest finite value, rather than negative infinity, keeps the
+        // rescaling below from computing `-inf - -inf`.
+        let mut max = f32x16::splat(f32::MIN);
+        let mut sum = f32x16::splat(0.0);
+        let mut add = |x: f32x16| {
+            let new_max = max.simd_max(x);
+            sum = sum * exp(max - new_max) + exp(x - new_max);
+            max = new_max;
+        };
+
+        let chunks = input.chunks_exact(f32x16::LANES);
+        let rest = chunks.remainder();
+        for chunk in chunks {
+            add(f32x16::from_slice(chunk));
+        }
+        if !rest.is_empty() {
+            // Padding contributes e^-inf = 0
+            let mut padded = [f32::NEG_INFINITY; f32x16::LANES];
+            padded[..rest.len()].copy_from_slice(rest);
+            add(f32x16::from_array(padded));
+        }
+
+        // Combine the lanes with each other, and the previous state
+        let new_max = max.reduce_max().max(self.max);
+        let sum = (sum * exp(max - f32x16::splat(new_max))).reduce_sum();
+        self.sum = self.sum * exp(f32x16::splat(self.max - new_max))[0] + sum;
+        self.max = new_max;
+    }
+}
+
+impl Default for ExpSum {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes the softmax of `input` to `output`, `e^x / sum(e^x)` for each element `x`.
+///
+/// The maximum is subtracted from each element before exponentiating, so large inputs don't
+/// overflow.  If `input` contains NaN, every element of `output` is NaN.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::nn;
+/// let logits = [0.0, 0.0, 2f32.ln(), f32::NEG_INFINITY];
+/// let mut probabilities = [0.0; 4];
+/// nn::softmax(&logits, &mut probabilities);
+/// for (p, expected) in probabilities.iter().zip([0.25, 0.25, 0.5, 0.0]) {
+///     assert!((p - expected).abs() < 1e-6);
+/// }
+/// ```
+#[inline]
+pub fn softmax(input: &[f32], output: &mut [f32]) {
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "slices must have the same length"
+    );
+
+    let mut exp_sum = ExpSum::new();
+    exp_sum.update(input);
+    let max = f32x16::splat(exp_sum.max());
+    let scale = f32x16::splat(exp_sum.sum().recip());
+
+    let mut input_chunks = input.chunks_exact(f32x16::LANES);
+    let mut output_chunks = output.chunks_exact_mut(f32x16::LANES);
+    for (input, output) in (&mut input_chunks).zip(&mut output_chunks) {
+        let x = f32x16::from_slice(input);
+        output.copy_from_slice((exp(x - max) * scale).as_array());
+    }
+
+    let input = input_chunks.remainder();
+    let output = output_chunks.into_remainder();
+    let mut padded = [f32::NEG_INFINITY; f32x16::LANES];
+    padded[..input.len()].copy_from_slice(input);
+    let x = f32x16::from_array(padded);
+    output.copy_from_slice(&(exp(x - max) * scale)[..input.len()]);
+}
@@ -0,0 +1,176 @@
+//! Gradient noise for procedural generation.
+//!
+//! Each function evaluates the noise at a point in each lane.  The noise is pseudo-random but
+//! smooth, and always the same at the same point.  Rather than permuting a table, which would
+//! need a gather for each lane, each lattice point is hashed with integer arithmetic, and the
+//! hash selects one of eight gradients of equal length, so the noise is between -1 and 1.
+
+use crate::simd::{LaneCount, Simd, SimdFloat, SimdPartialEq, SimdPartialOrd, SupportedLaneCount};
+
+/// Returns the largest integer less than or equal to each lane, and the distance to it.
+#[inline]
+fn floor<const LANES: usize>(x: Simd<f32, LANES>) -> (Simd<i32, LANES>, Simd<f32, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // Truncating rounds negative values up, so subtract one from those that aren't integers
+    let truncated = x.cast::<i32>();
+    let floor = truncated + x.simd_lt(truncated.cast()).to_int();
+    (floor, x - floor.cast())
+}
+
+/// Returns a hash of each lane's lattice point.
+#[inline]
+fn hash<const LANES: usize>(x: Simd<i32, LANES>, y: Simd<i32, LANES>) -> Simd<u32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut h =
+        (x.cast::<u32>() * Simd::splat(0x9e37_79b1)) ^ (y.cast::<u32>() * Simd::splat(0x85eb_ca77));
+    h ^= h >> Simd::splat(15);
+    h *= Simd::splat(0x2c1b_3c6d);
+    h ^= h >> Simd::splat(12);
+    h *= Simd::splat(0x297a_2d39);
+    h ^ h >> Simd::splat(15)
+}
+
+/// Returns the dot product of `(dx, dy)` with the gradient selected by `hash`.
+///
+/// The gradients are the diagonals `(±1, ±1)` and the axes `(±√2, 0)` and `(0, ±√2)`.
+#[inline]
+fn gradient<const LANES: usize>(
+    hash: Simd<u32, LANES>,
+    dx: Simd<f32, LANES>,
+    dy: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let bit = |bit: u32| (hash & Simd::splat(bit)).simd_ne(Simd::splat(0));
+    let dx = dx.negate_where(bit(1));
+    let dy = dy.negate_where(bit(2));
+    let axis = bit(8).select(dx, dy) * Simd::splat(core::f32::consts::SQRT_2);
+    bit(4).select(axis, dx + dy)
+}
+
+/// Returns `t` eased by `6t^5 - 15t^4 + 10t^3`, which has zero first and second derivatives
+/// at 0 and 1.
+#[inline]
+fn fade<const LANES: usize>(t: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    t * t * t * (t * (t * Simd::splat(6.0) - Simd::splat(15.0)) + Simd::splat(10.0))
+}
+
+/// Returns `a` blended towards `b` by `t`.
+#[inline]
+fn lerp<const LANES: usize>(
+    a: Simd<f32, LANES>,
+    b: Simd<f32, LANES>,
+    t: Simd<f32, LANES>,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    a + (b - a) * t
+}
+
+/// Returns 2D Perlin noise at each point `(x, y)`.
+///
+/// The noise is zero at integer coordinates, and otherwise blends the gradients of the four
+/// surrounding lattice points.  Coordinates are wrapped to 32-bit integers, so the noise
+/// repeats with a very long period.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{noise, f32x4};
+/// let x = f32x4::from_array([0.0, 1.5, -3.25, 100.75]);
+/// let y = f32x4::from_array([0.0, 0.5, 2.5, -7.125]);
+/// let noise = noise::perlin2d(x, y);
+/// assert_eq!(noise[0], 0.0);
+/// assert!(noise.to_array().iter().all(|n| (-1.0..=1.0).contains(n)));
+/// ```
+#[must_use]
+#[inline]
+pub fn perlin2d<const LANES: usize>(x: Simd<f32, LANES>, y: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let one = Simd::splat(1);
+    let (x0, dx) = floor(x);
+    let (y0, dy) = floor(y);
+    let (x1, y1) = (x0 + one, y0 + one);
+    let (dx1, dy1) = (dx - Simd::splat(1.0), dy - Simd::splat(1.0));
+
+    let n00 = gradient(hash(x0, y0), dx, dy);
+    let n10 = gradient(hash(x1, y0), dx1, dy);
+    let n01 = gradient(hash(x0, y1), dx, dy1);
+    let n11 = gradient(hash(x1, y1), dx1, dy1);
+
+    let u = fade(dx);
+    lerp(lerp(n00, n10, u), lerp(n01, n11, u), fade(dy))
+}
+
+/// Returns 2D simplex noise at each point `(x, y)`.
+///
+/// The plane is divided into triangles rather than squares, so the noise blends the gradients
+/// of only three lattice points, and has fewer directional artifacts than [`perlin2d`].
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{noise, f32x4};
+/// let x = f32x4::from_array([0.0, 1.5, -3.25, 100.75]);
+/// let y = f32x4::from_array([0.0, 0.5, 2.5, -7.125]);
+/// let noise = noise::simplex2d(x, y);
+/// assert_eq!(noise[0], 0.0);
+/// assert!(noise.to_array().iter().all(|n| (-1.0..=1.0).contains(n)));
+/// ```
+#[must_use]
+#[inline]
+pub fn simplex2d<const LANES: usize>(x: Simd<f32, LANES>, y: Simd<f32, LANES>) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // (sqrt(3) - 1) / 2 skews the triangles to squares, and (3 - sqrt(3)) / 6 unskews them
+    const SKEW: f32 = 0.366_025_42;
+    const UNSKEW: f32 = 0.211_324_87;
+    // The sum of the contributions is at most about 1 / 70.15, at the center of an edge
+    const SCALE: f32 = 70.0;
+
+    let skew = (x + y) * Simd::splat(SKEW);
+    let (i, _) = floor(x + skew);
+    let (j, _) = floor(y + skew);
+    let unskew = (i + j).cast::<f32>() * Simd::splat(UNSKEW);
+    let dx0 = x - (i.cast() - unskew);
+    let dy0 = y - (j.cast() - unskew);
+
+    // The middle corner of the triangle is one step along the larger of the offsets
+    let lower = dx0.simd_gt(dy0);
+    let one = Simd::splat(1);
+    let (i1, j1) = (
+        lower.select(one, Simd::splat(0)),
+        lower.select(Simd::splat(0), one),
+    );
+    let dx1 = dx0 - i1.cast() + Simd::splat(UNSKEW);
+    let dy1 = dy0 - j1.cast() + Simd::splat(UNSKEW);
+    let dx2 = dx0 - Simd::splat(1.0 - 2.0 * UNSKEW);
+    let dy2 = dy0 - Simd::splat(1.0 - 2.0 * UNSKEW);
+
+    // Each corner contributes within a radius of sqrt(0.5)
+    let corner = |hash, dx: Simd<f32, LANES>, dy: Simd<f32, LANES>| {
+        let t = (Simd::splat(0.5) - dx * dx - dy * dy).simd_max(Simd::splat(0.0));
+        let t2 = t * t;
+        t2 * t2 * gradient(hash, dx, dy)
+    };
+    let n0 = corner(hash(i, j), dx0, dy0);
+    let n1 = corner(hash(i + i1, j + j1), dx1, dy1);
+    let n2 = corner(hash(i + one, j + one), dx2, dy2);
+    (n0 + n1 + n2) * Simd::splat(SCALE)
+}
@@ -0,0 +1,143 @@
+//! Arithmetic on nullable columns, with validity bitmaps as in Apache Arrow.
+//!
+//! A column's validity bitmap has a bit for each element, least significant bit first, which is
+//! set if the element is valid and clear if it is null.  Each byte of the bitmap covers a vector
+//! of eight elements, and converts directly to the mask of that vector's valid lanes.  Bitmaps
+//! must start at bit 0 of their first byte.
+
+use crate::simd::{Mask, Simd, SimdElement, ToBitMask};
+
+/// The number of elements covered by each byte of a bitmap.
+const LANES: usize = 8;
+
+/// Applies `op` to vectors of corresponding elements of `inputs`, writing the results to `out`
+/// and the validity of the results to `out_validity`.
+#[inline]
+fn apply<T, const N: usize>(
+    inputs: [(&[T], &[u8]); N],
+    out: &mut [T],
+    out_validity: &mut [u8],
+    mut op: impl FnMut([Simd<T, LANES>; N], Mask<T::Mask, LANES>) -> Simd<T, LANES>,
+) where
+    T: SimdElement + Default,
+{
+    let len = out.len();
+    let bytes = (len + LANES - 1) / LANES;
+    for (values, validity) in inputs {
+        assert_eq!(
+            values.len(),
+            len,
+            "inputs and output must have the same length"
+        );
+        assert!(validity.len() >= bytes, "validity bitmap is too short");
+    }
+    assert!(out_validity.len() >= bytes, "validity bitmap is too short");
+
+    let default = Simd::splat(T::default());
+    for (i, (out, out_validity)) in out.chunks_mut(LANES).zip(out_validity).enumerate() {
+        // Bits past the end of the column are cleared
+        let mut valid = u8::MAX >> (LANES - out.len());
+        let vectors = inputs.map(|(values, validity)| {
+            valid &= validity[i];
+            Simd::load_or_default(&values[i * LANES..])
+        });
+        let valid_lanes = Mask::from_bitmask(valid);
+
+        let result = valid_lanes.select(op(vectors, valid_lanes), default);
+        out.copy_from_slice(&result[..out.len()]);
+        *out_validity = valid;
+    }
+}
+
+/// Applies `op` to vectors of elements of `values`, writing the results to `out` and their
+/// validity to `out_validity`.
+///
+/// `op` is called with each vector of eight elements and the mask of which of them are valid.
+/// Null lanes may hold any value, and the results in those lanes are discarded, so `op` must
+/// only avoid panicking on them, such as by selecting a divisor of 1.  Null elements of `out`
+/// are set to the default value.
+///
+/// # Panics
+///
+/// Panics if `values` and `out` have different lengths, or if a validity bitmap is shorter than
+/// one bit per element.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{nullable, SimdInt};
+/// let values = [-1, 2, 0, -4];
+/// let validity = [0b1011];
+/// let mut out = [0; 4];
+/// let mut out_validity = [0];
+/// nullable::unary(&values, &validity, &mut out, &mut out_validity, |x, _| x.abs());
+/// assert_eq!(out, [1, 2, 0, 4]);
+/// assert_eq!(out_validity, [0b1011]);
+/// ```
+#[inline]
+pub fn unary<T>(
+    values: &[T],
+    validity: &[u8],
+    out: &mut [T],
+    out_validity: &mut [u8],
+    mut op: impl FnMut(Simd<T, LANES>, Mask<T::Mask, LANES>) -> Simd<T, LANES>,
+) where
+    T: SimdElement + Default,
+{
+    apply([(values, validity)], out, out_validity, |[x], valid| {
+        op(x, valid)
+    });
+}
+
+/// Applies `op` to vectors of corresponding elements of `a` and `b`, writing the results to
+/// `out` and their validity to `out_validity`.
+///
+/// A result is valid if both of its inputs are.  `op` is called with each pair of vectors of
+/// eight elements and the mask of which lanes are valid in both.  Null lanes may hold any value,
+/// and the results in those lanes are discarded, so `op` must only avoid panicking on them,
+/// such as by selecting a divisor of 1.  Null elements of `out` are set to the default value.
+///
+/// # Panics
+///
+/// Panics if `a`, `b` and `out` have different lengths, or if a validity bitmap is shorter than
+/// one bit per element.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{nullable, Simd};
+/// let a = [10, 20, 30, 40, 50];
+/// let b = [2, 0, 3, 0, 5];
+/// let (a_validity, b_validity) = ([0b11111], [0b10101]);
+/// let mut out = [0; 5];
+/// let mut out_validity = [0];
+/// // Null divisors are replaced by 1, so they can't divide by zero
+/// nullable::binary(&a, &a_validity, &b, &b_validity, &mut out, &mut out_validity, |a, b, valid| {
+///     a / valid.select(b, Simd::splat(1))
+/// });
+/// assert_eq!(out, [5, 0, 10, 0, 10]);
+/// assert_eq!(out_validity, [0b10101]);
+/// ```
+#[inline]
+pub fn binary<T>(
+    a: &[T],
+    a_validity: &[u8],
+    b: &[T],
+    b_validity: &[u8],
+    out: &mut [T],
+    out_validity: &mut [u8],
+    mut op: impl FnMut(Simd<T, LANES>, Simd<T, LANES>, Mask<T::Mask, LANES>) -> Simd<T, LANES>,
+) where
+    T: SimdElement + Default,
+{
+    apply(
+        [(a, a_validity), (b, b_validity)],
+        out,
+        out_validity,
+        |[a, b], valid| op(a, b, valid),
+    );
+}
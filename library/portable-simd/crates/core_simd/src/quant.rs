@@ -0,0 +1,73 @@
+//! Linear quantization between `f32` and 8-bit integers.
+//!
+//! A quantized value `q` represents the real value `(q - zero_point) * scale`, following the
+//! ONNX `QuantizeLinear` operator and TensorFlow Lite's quantization scheme.
+
+use crate::simd::{LaneCount, Simd, SimdFloat, SupportedLaneCount};
+
+/// Adding and subtracting this rounds an `f32` of magnitude less than 2^22 to an integer, with
+/// ties to even.
+const ROUND: f32 = 12582912.0;
+
+/// Quantizes each lane to `i8`, computing `round(value / scale) + zero_point` and saturating
+/// to the range of `i8`.
+///
+/// Rounding is to nearest, with ties to even.  NaNs quantize to `zero_point`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{f32x4, i8x4, quant};
+/// let values = f32x4::from_array([0.25, 0.75, -1.2, 100.0]);
+/// assert_eq!(quant::quantize(values, 0.5, 3), i8x4::from_array([3, 5, 1, 127]));
+/// ```
+#[must_use]
+#[inline]
+pub fn quantize<const LANES: usize>(
+    values: Simd<f32, LANES>,
+    scale: f32,
+    zero_point: i8,
+) -> Simd<i8, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let scaled = values / Simd::splat(scale);
+    // Anything outside of this range saturates after adding the zero point, and clamping keeps
+    // the values small enough to round.
+    let scaled = scaled.is_nan().select(Simd::splat(0.0), scaled);
+    let scaled = scaled.simd_clamp(Simd::splat(-512.0), Simd::splat(512.0));
+    let rounded = (scaled + Simd::splat(ROUND)) - Simd::splat(ROUND);
+    (rounded + Simd::splat(zero_point as f32)).cast()
+}
+
+/// Dequantizes each lane to `f32`, computing `(quantized - zero_point) * scale`.
+///
+/// The zero point is subtracted exactly, before widening to `f32`, so the result is rounded only
+/// once.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{f32x4, i8x4, quant};
+/// let quantized = i8x4::from_array([3, 5, 1, 127]);
+/// let values = quant::dequantize(quantized, 0.5, 3);
+/// assert_eq!(values, f32x4::from_array([0.0, 1.0, -1.0, 62.0]));
+/// assert_eq!(quant::quantize(values, 0.5, 3), quantized);
+/// ```
+#[must_use]
+#[inline]
+pub fn dequantize<const LANES: usize>(
+    quantized: Simd<i8, LANES>,
+    scale: f32,
+    zero_point: i8,
+) -> Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let centered = quantized.cast::<i16>() - Simd::splat(zero_point as i16);
+    centered.cast::<f32>() * Simd::splat(scale)
+}
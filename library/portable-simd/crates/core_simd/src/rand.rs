@@ -0,0 +1,271 @@
+//! Pseudo-random numbers, generated a vector at a time.
+//!
+//! [`SimdRng`] is not cryptographically secure, and is meant for simulations, sampling and
+//! randomized algorithms, where the same seed should give the same numbers on every target.
+//! It can also shuffle many small groups of lanes at once, such as when resampling or
+//! augmenting a batch of data.
+//!
+//! [`pick_by_threshold`] and [`pick_by_cumulative_weight`] turn uniform random floats, such as
+//! those of [`SimdRng::next_f32`], into weighted choices.
+
+use super::shift::prefix_sum;
+use crate::simd::{
+    LaneCount, Mask, Simd, SimdFloat, SimdInt, SimdPartialEq, SimdPartialOrd, SupportedLaneCount,
+};
+
+/// The increment of the state of SplitMix64, the golden ratio in fixed point.
+const GOLDEN: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// A pseudo-random number generator with `LANES` lanes.
+///
+/// The lanes are consecutive outputs of the SplitMix64 generator, so the numbers returned by
+/// [`next_u64`](Self::next_u64) are, in lane order, the same as those of the scalar generator
+/// with the same seed, however many lanes there are.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{rand::SimdRng, Simd};
+/// let mut rng = SimdRng::<8>::new(42);
+/// let dice = rng.below(Simd::splat(6)) + Simd::splat(1);
+/// assert!(dice.to_array().iter().all(|x| (1..=6).contains(x)));
+///
+/// // Reseeding repeats the numbers
+/// assert_eq!(SimdRng::<8>::new(42).below(Simd::splat(6)) + Simd::splat(1), dice);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimdRng<const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// The state of the scalar generator for each lane of the next output.
+    state: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> SimdRng<LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Creates a generator from `seed`.
+    #[inline]
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        let lanes = Simd::from_array(core::array::from_fn(|i| i as u64 + 1));
+        Self {
+            state: Simd::splat(seed) + lanes * Simd::splat(GOLDEN),
+        }
+    }
+
+    /// Returns the next 64 random bits of each lane.
+    #[inline]
+    #[must_use]
+    pub fn next_u64(&mut self) -> Simd<u64, LANES> {
+        let mut z = self.state;
+        self.state += Simd::splat((LANES as u64).wrapping_mul(GOLDEN));
+        z = (z ^ (z >> Simd::splat(30))) * Simd::splat(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> Simd::splat(27))) * Simd::splat(0x94d0_49bb_1331_11eb);
+        z ^ (z >> Simd::splat(31))
+    }
+
+    /// Returns the next 32 random bits of each lane.
+    ///
+    /// These are the high bits of [`next_u64`](Self::next_u64).
+    #[inline]
+    #[must_use]
+    pub fn next_u32(&mut self) -> Simd<u32, LANES> {
+        (self.next_u64() >> Simd::splat(32)).cast()
+    }
+
+    /// Returns a random float in `0.0..1.0` in each lane.
+    ///
+    /// The floats are multiples of 2<sup>-24</sup>, each equally likely.
+    #[inline]
+    #[must_use]
+    pub fn next_f32(&mut self) -> Simd<f32, LANES> {
+        (self.next_u32() >> Simd::splat(8)).cast::<f32>() * Simd::splat(1.0 / (1 << 24) as f32)
+    }
+
+    /// Returns a random integer below the lane of `bound` in each lane.
+    ///
+    /// The random bits are scaled by the bound rather than rejected when they fall past a
+    /// multiple of it, so some results are more likely than others by at most `bound` in
+    /// 2<sup>32</sup>.  For the small bounds of shuffling and sampling, this is far less than
+    /// can be measured.  A bound of 0 returns 0.
+    #[inline]
+    #[must_use]
+    pub fn below(&mut self, bound: Simd<u32, LANES>) -> Simd<u32, LANES> {
+        ((self.next_u32().cast::<u64>() * bound.cast::<u64>()) >> Simd::splat(32)).cast()
+    }
+
+    /// Returns a random permutation of each group of `GROUP` consecutive lanes.
+    ///
+    /// Each lane is the index of a lane in the same group, and every permutation of a group is
+    /// equally likely.  Swizzling a vector of bytes by the indices with
+    /// [`swizzle_dyn`](Simd::swizzle_dyn) shuffles it, as [`shuffle`](Self::shuffle) does, and
+    /// gathering with them shuffles other elements.  Each group is shuffled with its own
+    /// Fisher-Yates shuffle, with the swaps of all groups done at once by swizzles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `GROUP` is 0 or doesn't divide `LANES`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::rand::SimdRng;
+    /// let mut rng = SimdRng::<8>::new(7);
+    /// let idxs = rng.permutation::<4>().to_array();
+    /// let (mut first, mut second) = ([0; 4], [0; 4]);
+    /// first.copy_from_slice(&idxs[..4]);
+    /// second.copy_from_slice(&idxs[4..]);
+    /// first.sort();
+    /// second.sort();
+    /// assert_eq!(first, [0, 1, 2, 3]);
+    /// assert_eq!(second, [4, 5, 6, 7]);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn permutation<const GROUP: usize>(&mut self) -> Simd<u8, LANES> {
+        assert!(
+            GROUP > 0 && LANES / GROUP * GROUP == LANES,
+            "group size must divide the number of lanes"
+        );
+        let lane = Simd::from_array(core::array::from_fn(|i| i as u8));
+        let position = lane % Simd::splat(GROUP as u8);
+        let start = lane - position;
+
+        // Lane `i` of each group holds the lane to swap with lane `i`, at or below it
+        let swaps = self
+            .below(position.cast::<u32>() + Simd::splat(1))
+            .cast::<u8>();
+        let mut idxs = lane;
+        for i in (1..GROUP).rev() {
+            let i = Simd::splat(i as u8);
+            let j = swaps.swizzle_dyn(start + i);
+            let swap = position
+                .simd_eq(i)
+                .select(start + j, position.simd_eq(j).select(start + i, lane));
+            idxs = idxs.swizzle_dyn(swap);
+        }
+        idxs
+    }
+
+    /// Shuffles each group of `GROUP` consecutive lanes of `x`.
+    ///
+    /// This swizzles `x` by a [`permutation`](Self::permutation).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `GROUP` is 0 or doesn't divide `LANES`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{rand::SimdRng, Simd};
+    /// // Shuffle the bytes of four words independently
+    /// let words = Simd::from_array(*b"lanesimdwordbyte");
+    /// let shuffled = SimdRng::<16>::new(1).shuffle::<4>(words).to_array();
+    /// for (word, shuffled) in words.to_array().chunks(4).zip(shuffled.chunks(4)) {
+    ///     let (mut word, mut shuffled) = (word.to_vec(), shuffled.to_vec());
+    ///     word.sort();
+    ///     shuffled.sort();
+    ///     assert_eq!(word, shuffled);
+    /// }
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    #[track_caller]
+    pub fn shuffle<const GROUP: usize>(&mut self, x: Simd<u8, LANES>) -> Simd<u8, LANES> {
+        x.swizzle_dyn(self.permutation::<GROUP>())
+    }
+}
+
+/// Returns which lanes are picked, where each lane is picked with the probability of its lane of
+/// `weights`, given a uniform random float in `0.0..1.0` in each lane of `rand`.
+///
+/// A lane is picked when its random float is below its weight, so weights of 0 or less are never
+/// picked and weights of 1 or more always are.
+///
+/// # Examples
+/// Weighted reservoir sampling keeps one item of a stream, with each item kept with probability
+/// proportional to its weight, by replacing the kept item with each new item with probability
+/// its weight over the total weight so far.  Each lane samples a separate stream:
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{rand::{pick_by_threshold, SimdRng}, f32x4, u32x4};
+/// let mut rng = SimdRng::new(5);
+/// let (mut total, mut kept) = (f32x4::splat(0.0), u32x4::splat(0));
+/// for item in 0..100 {
+///     // The weights of the items of each stream
+///     let weights = f32x4::from_array([1.0, (item % 2) as f32, 0.0, (item == 50) as u32 as f32]);
+///     total += weights;
+///     let replace = pick_by_threshold(weights / total, rng.next_f32());
+///     kept = replace.select(u32x4::splat(item), kept);
+/// }
+/// assert_eq!(kept[1] % 2, 1, "items with no weight are never kept");
+/// assert_eq!(kept[2], 0, "nothing is kept from streams with no weight");
+/// assert_eq!(kept[3], 50);
+/// ```
+#[inline]
+#[must_use]
+pub fn pick_by_threshold<const LANES: usize>(
+    weights: Simd<f32, LANES>,
+    rand: Simd<f32, LANES>,
+) -> Mask<i32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    rand.simd_lt(weights)
+}
+
+/// Returns the index of a lane of `weights`, each picked with probability proportional to its
+/// weight, given a uniform random float `rand` in `0.0..1.0`.
+///
+/// The lane picked is the first whose cumulative weight, the sum of its weight and those below
+/// it, is greater than `rand` times the total weight.  The cumulative weights are a prefix sum
+/// within the vector, computed in a logarithmic number of steps, and the lane is found by
+/// counting the cumulative weights that aren't greater, rather than searching for it.
+///
+/// Returns `None` if the total weight isn't positive.  Weights must not be negative, or the
+/// result is unspecified.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{rand::pick_by_cumulative_weight, f32x4};
+/// let weights = f32x4::from_array([1.0, 0.0, 2.0, 1.0]);
+/// assert_eq!(pick_by_cumulative_weight(weights, 0.0), Some(0));
+/// assert_eq!(pick_by_cumulative_weight(weights, 0.3), Some(2));
+/// assert_eq!(pick_by_cumulative_weight(weights, 0.75), Some(3));
+/// assert_eq!(pick_by_cumulative_weight(f32x4::splat(0.0), 0.5), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn pick_by_cumulative_weight<const LANES: usize>(
+    weights: Simd<f32, LANES>,
+    rand: f32,
+) -> Option<usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let cumulative = prefix_sum(weights);
+    let total = cumulative[LANES - 1];
+    if total > 0.0 {
+        // Rounding could take the target to the total, past every lane
+        let target = Simd::splat(rand * total).simd_min(Simd::splat(total).next_down());
+        Some((-cumulative.simd_le(target).to_int().reduce_sum()) as usize)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,320 @@
+//! Reductions over arrays of vectors.
+//!
+//! Loops that keep several independent accumulator vectors hide the latency of each operation,
+//! since consecutive iterations don't wait on each other.  The functions in this module finish
+//! such loops, combining the accumulators pairwise in a tree, and then reducing the lanes of the
+//! result.
+//!
+//! [`RunningExtrema`] is the accumulator of a loop finding the minimum and maximum of a stream,
+//! which also tracks where they were found.
+
+use crate::simd::{
+    LaneCount, Mask, Simd, SimdElement, SimdFloat, SimdInt, SimdOrd, SimdPartialEq, SimdPartialOrd,
+    SimdUint, SupportedLaneCount,
+};
+
+pub(crate) mod sealed {
+    /// Vectors whose lanes can be combined and reduced.
+    pub trait Reduce: Copy {
+        type Scalar;
+        fn zero() -> Self;
+        fn lanewise_add(self, other: Self) -> Self;
+        fn lanewise_min(self, other: Self) -> Self;
+        fn lanewise_max(self, other: Self) -> Self;
+        fn sum_lanes(self) -> Self::Scalar;
+        fn min_lane(self) -> Self::Scalar;
+        fn max_lane(self) -> Self::Scalar;
+    }
+}
+pub(crate) use sealed::Reduce;
+
+macro_rules! impl_reduce {
+    { $($ty:ty),* } => {
+        $(
+        impl<const LANES: usize> Reduce for Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Scalar = $ty;
+
+            #[inline]
+            fn zero() -> Self {
+                Simd::splat(0 as $ty)
+            }
+
+            #[inline]
+            fn lanewise_add(self, other: Self) -> Self {
+                self + other
+            }
+
+            #[inline]
+            fn lanewise_min(self, other: Self) -> Self {
+                self.simd_min(other)
+            }
+
+            #[inline]
+            fn lanewise_max(self, other: Self) -> Self {
+                self.simd_max(other)
+            }
+
+            #[inline]
+            fn sum_lanes(self) -> $ty {
+                self.reduce_sum()
+            }
+
+            #[inline]
+            fn min_lane(self) -> $ty {
+                self.reduce_min()
+            }
+
+            #[inline]
+            fn max_lane(self) -> $ty {
+                self.reduce_max()
+            }
+        }
+        )*
+    }
+}
+
+impl_reduce! { f32, f64, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+
+/// Combines the vectors pairwise, so each has a path of logarithmic length to the result.
+#[inline]
+fn tree<V: Reduce>(vectors: &[V], combine: fn(V, V) -> V) -> V {
+    match vectors {
+        [] => unreachable!(),
+        [vector] => *vector,
+        [a, b] => combine(*a, *b),
+        _ => {
+            let (left, right) = vectors.split_at(vectors.len() / 2);
+            combine(tree(left, combine), tree(right, combine))
+        }
+    }
+}
+
+/// Returns the sum of every lane of every vector.
+///
+/// Integers wrap on overflow.  Floats are summed pairwise, which is typically more accurate
+/// than summing them in order, but the result is unspecified if any lane is NaN or infinite.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{f32x8, reduce};
+/// let data: Vec<f32> = (0..1024).map(|x| x as f32).collect();
+///
+/// // Four accumulators, so consecutive additions are independent
+/// let mut accumulators = [f32x8::splat(0.0); 4];
+/// for chunk in data.chunks_exact(32) {
+///     for (accumulator, chunk) in accumulators.iter_mut().zip(chunk.chunks_exact(8)) {
+///         *accumulator += f32x8::from_slice(chunk);
+///     }
+/// }
+/// assert_eq!(reduce::reduce_sum(&accumulators), 1023.0 * 1024.0 / 2.0);
+/// ```
+#[must_use]
+#[inline]
+pub fn reduce_sum<T, const LANES: usize>(vectors: &[Simd<T, LANES>]) -> T
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: Reduce<Scalar = T>,
+{
+    if vectors.is_empty() {
+        return Simd::<T, LANES>::zero().sum_lanes();
+    }
+    tree(vectors, Reduce::lanewise_add).sum_lanes()
+}
+
+/// Returns the minimum of every lane of every vector, or `None` if there are no vectors.
+///
+/// For floats, NaNs are ignored unless every lane is NaN.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{i32x4, reduce};
+/// let vectors = [i32x4::from_array([3, 1, 4, 1]), i32x4::from_array([5, -9, 2, 6])];
+/// assert_eq!(reduce::reduce_min(&vectors), Some(-9));
+/// assert_eq!(reduce::reduce_min::<i32, 4>(&[]), None);
+/// ```
+#[must_use]
+#[inline]
+pub fn reduce_min<T, const LANES: usize>(vectors: &[Simd<T, LANES>]) -> Option<T>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: Reduce<Scalar = T>,
+{
+    if vectors.is_empty() {
+        return None;
+    }
+    Some(tree(vectors, Reduce::lanewise_min).min_lane())
+}
+
+/// Returns the maximum of every lane of every vector, or `None` if there are no vectors.
+///
+/// For floats, NaNs are ignored unless every lane is NaN.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{f32x4, reduce};
+/// let vectors = [
+///     f32x4::from_array([3., 1., 4., 1.]),
+///     f32x4::from_array([5., -9., f32::NAN, 6.]),
+/// ];
+/// assert_eq!(reduce::reduce_max(&vectors), Some(6.));
+/// ```
+#[must_use]
+#[inline]
+pub fn reduce_max<T, const LANES: usize>(vectors: &[Simd<T, LANES>]) -> Option<T>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: Reduce<Scalar = T>,
+{
+    if vectors.is_empty() {
+        return None;
+    }
+    Some(tree(vectors, Reduce::lanewise_max).max_lane())
+}
+
+/// The minimum and maximum of each lane of a stream of vectors, and where they were seen.
+///
+/// Finding the extremes of a stream is done in two phases: the lanes of every vector are
+/// combined with the running minimum and maximum lane by lane, and only at the end are the lanes
+/// reduced to a single value.  This type keeps the state of the first phase, along with the
+/// index of the element each lane's extreme came from, so the position of the result is known
+/// without searching the data again.
+///
+/// The index of lane `j` of the `i`th vector passed to [`update`](RunningExtrema::update) is
+/// `i * LANES + j`, its index in the slice the vectors were loaded from.  When the extreme occurs
+/// more than once, the smallest index is returned.  For floats, NaNs are ignored unless every
+/// element is NaN, in which case the first one is returned.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{f32x4, reduce::RunningExtrema};
+/// let data = [3.0, 1.0, 4.0, 1.0, 5.0, -9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0];
+/// let mut extrema = RunningExtrema::new();
+/// for chunk in data.chunks_exact(4) {
+///     extrema.update(f32x4::from_slice(chunk));
+/// }
+/// assert_eq!(extrema.minimum(), Some((-9.0, 5)));
+/// assert_eq!(extrema.maximum(), Some((8.0, 11)));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct RunningExtrema<T, const LANES: usize>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// The minimum of each lane.
+    min: Simd<T, LANES>,
+    /// The maximum of each lane.
+    max: Simd<T, LANES>,
+    /// The update that each lane's minimum came from.
+    min_update: Simd<usize, LANES>,
+    /// The update that each lane's maximum came from.
+    max_update: Simd<usize, LANES>,
+    /// The number of updates.
+    updates: usize,
+}
+
+impl<T, const LANES: usize> RunningExtrema<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: Reduce<Scalar = T> + SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    /// Creates a tracker that hasn't seen any vectors.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            min: Simd::zero(),
+            max: Simd::zero(),
+            min_update: Simd::splat(0),
+            max_update: Simd::splat(0),
+            updates: 0,
+        }
+    }
+
+    /// Combines the lanes of `values` with the minimum and maximum of each lane.
+    #[inline]
+    pub fn update(&mut self, values: Simd<T, LANES>) {
+        if self.updates == 0 {
+            self.min = values;
+            self.max = values;
+        } else {
+            // A NaN extreme is replaced by the first value of its lane that isn't NaN
+            let replace_nan = values.simd_eq(values);
+            let lower = values.simd_lt(self.min) | (self.min.simd_ne(self.min) & replace_nan);
+            let higher = values.simd_gt(self.max) | (self.max.simd_ne(self.max) & replace_nan);
+            let update = Simd::splat(self.updates);
+            self.min = lower.select(values, self.min);
+            self.max = higher.select(values, self.max);
+            self.min_update = lower.cast::<isize>().select(update, self.min_update);
+            self.max_update = higher.cast::<isize>().select(update, self.max_update);
+        }
+        self.updates += 1;
+    }
+
+    /// Returns the minimum element and its index, or `None` if there have been no updates.
+    #[inline]
+    #[must_use]
+    pub fn minimum(&self) -> Option<(T, usize)> {
+        if self.updates == 0 {
+            return None;
+        }
+        let min = self.min.min_lane();
+        Some((min, Self::index_of(self.min, self.min_update, min)))
+    }
+
+    /// Returns the maximum element and its index, or `None` if there have been no updates.
+    #[inline]
+    #[must_use]
+    pub fn maximum(&self) -> Option<(T, usize)> {
+        if self.updates == 0 {
+            return None;
+        }
+        let max = self.max.max_lane();
+        Some((max, Self::index_of(self.max, self.max_update, max)))
+    }
+
+    /// Returns the smallest index of the lanes of `values` that equal `value`, or of any lane if
+    /// none do, which happens when every lane is NaN.
+    #[inline]
+    fn index_of(values: Simd<T, LANES>, updates: Simd<usize, LANES>, value: T) -> usize {
+        let lanes = Simd::from_array(core::array::from_fn(|i| i));
+        let indices = updates * Simd::splat(LANES) + lanes;
+        let equal = values.simd_eq(Simd::splat(value)).cast::<isize>();
+        if equal.any() {
+            equal.select(indices, Simd::splat(usize::MAX)).reduce_min()
+        } else {
+            indices.reduce_min()
+        }
+    }
+}
+
+impl<T, const LANES: usize> Default for RunningExtrema<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T, LANES>: Reduce<Scalar = T> + SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
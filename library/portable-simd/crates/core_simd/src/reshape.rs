@@ -0,0 +1,41 @@
+use crate::simd::Simd;
+
+mod sealed {
+    /// Vectors of numbers, by their size in bytes.
+    pub trait Bits {
+        /// An array with one byte for each byte of the vector.
+        type Bytes;
+    }
+}
+use sealed::Bits;
+
+/// Statically guarantees that two vectors have the same size, so the bits of one can be
+/// reinterpreted as the other with [`Simd::try_reshape`].
+///
+/// This trait is *sealed*: it's implemented for every pair of vectors of numbers that have the
+/// same size, and users can't implement it for other types.
+pub trait SameBits<V>: Bits {}
+
+impl<V, W> SameBits<W> for V
+where
+    V: Bits,
+    W: Bits<Bytes = V::Bytes>,
+{
+}
+
+macro_rules! impl_bits {
+    { $($ty:ty),* } => {
+        $(
+        impl_bits! { $ty; 1, 2, 4, 8, 16, 32, 64 }
+        )*
+    };
+    { $ty:ty; $($lanes:literal),* } => {
+        $(
+        impl Bits for Simd<$ty, $lanes> {
+            type Bytes = [u8; core::mem::size_of::<$ty>() * $lanes];
+        }
+        )*
+    };
+}
+
+impl_bits! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64 }
@@ -0,0 +1,141 @@
+//! Scanning slices for changes between neighboring elements.
+
+use super::shift::ShiftUp;
+use crate::simd::{Mask, Simd, SimdElement, SimdPartialEq, Swizzle2, ToBitMask};
+
+/// The number of elements compared at once.
+const LANES: usize = 16;
+
+/// The number of elements described by each word of boundaries.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Returns a bitmask of the elements of `chunk` that differ from the element before them, where
+/// the element before the first is `previous`.
+#[inline]
+fn changes<T>(chunk: &[T], previous: T) -> u64
+where
+    T: SimdElement,
+    Simd<T, LANES>: SimdPartialEq<Mask = Mask<T::Mask, LANES>>,
+{
+    let mut padded = [chunk[chunk.len() - 1]; LANES];
+    padded[..chunk.len()].copy_from_slice(chunk);
+    let current = Simd::from_array(padded);
+    let previous = ShiftUp::<1>::swizzle2(current, Simd::splat(previous));
+    // Padding may differ from itself if it's NaN
+    let valid = (1 << chunk.len()) - 1;
+    current.simd_ne(previous).to_bitmask() as u64 & valid
+}
+
+/// Writes a bitmask of the starts of runs of equal elements in `slice` to `boundaries`, and
+/// returns the number of runs.
+///
+/// Bit `i % 64` of word `i / 64` is set if element `i` starts a run: if it's the first element,
+/// or differs from the element before it.  Only the words covering `slice` are written.
+///
+/// Each vector of elements is compared with itself shifted by one lane, with the last element of
+/// the previous vector shifted in, so every element is loaded once.  Elements are compared with
+/// `==`, so each NaN starts a run.
+///
+/// # Panics
+///
+/// Panics if `boundaries` has fewer than one word for every 64 elements of `slice`.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::scan;
+/// let values = [7, 7, 7, 2, 2, 9, 7, 7];
+/// let mut boundaries = [0];
+/// assert_eq!(scan::run_boundaries(&values, &mut boundaries), 4);
+/// assert_eq!(boundaries, [0b0110_1001]);
+/// ```
+#[inline]
+pub fn run_boundaries<T>(slice: &[T], boundaries: &mut [u64]) -> usize
+where
+    T: SimdElement,
+    Simd<T, LANES>: SimdPartialEq<Mask = Mask<T::Mask, LANES>>,
+{
+    let words = (slice.len() + WORD_BITS - 1) / WORD_BITS;
+    assert!(
+        boundaries.len() >= words,
+        "boundaries must have a word for every 64 elements"
+    );
+    let Some(&first) = slice.first() else {
+        return 0;
+    };
+
+    let mut previous = first;
+    let mut runs = 0;
+    for (word, elements) in boundaries.iter_mut().zip(slice.chunks(WORD_BITS)) {
+        let mut bits = 0;
+        for (i, chunk) in elements.chunks(LANES).enumerate() {
+            bits |= changes(chunk, previous) << (i * LANES);
+            previous = chunk[chunk.len() - 1];
+        }
+        runs += bits.count_ones() as usize;
+        *word = bits;
+    }
+
+    // The first element always starts a run, even if it's equal to the `previous` it was
+    // compared with
+    if boundaries[0] & 1 == 0 {
+        boundaries[0] |= 1;
+        runs += 1;
+    }
+    runs
+}
+
+/// Writes the index of the first element of each run of equal elements in `slice` to `starts`,
+/// and returns the number of runs.
+///
+/// This is equivalent to collecting the set bits of [`run_boundaries`], but without a
+/// bitmask of the whole slice.  Elements are compared with `==`, so each NaN starts a run.
+///
+/// # Panics
+///
+/// Panics if `starts` is shorter than `slice`, which would be too short if every element
+/// started a run.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::scan;
+/// let sorted = [1, 1, 1, 4, 4, 6, 8, 8];
+/// let mut starts = [0; 8];
+/// let runs = scan::run_starts(&sorted, &mut starts);
+/// assert_eq!(starts[..runs], [0, 3, 5, 6]);
+/// ```
+#[inline]
+pub fn run_starts<T>(slice: &[T], starts: &mut [usize]) -> usize
+where
+    T: SimdElement,
+    Simd<T, LANES>: SimdPartialEq<Mask = Mask<T::Mask, LANES>>,
+{
+    assert!(
+        starts.len() >= slice.len(),
+        "starts must be at least as long as the slice"
+    );
+    let Some(&first) = slice.first() else {
+        return 0;
+    };
+
+    let mut previous = first;
+    let mut runs = 0;
+    for (i, chunk) in slice.chunks(LANES).enumerate() {
+        let mut bits = changes(chunk, previous);
+        if i == 0 {
+            bits |= 1;
+        }
+        while bits != 0 {
+            starts[runs] = i * LANES + bits.trailing_zeros() as usize;
+            runs += 1;
+            bits &= bits - 1;
+        }
+        previous = chunk[chunk.len() - 1];
+    }
+    runs
+}
@@ -0,0 +1,80 @@
+//! Searching sorted slices, comparing a vector of candidates at a time.
+
+use crate::simd::{Mask, Simd, SimdElement, SimdPartialOrd, ToBitMask};
+
+/// The number of candidates compared at each step.
+const LANES: usize = 16;
+
+/// Returns the number of lanes of `candidates` less than `key`.
+#[inline]
+fn count_less<T>(candidates: Simd<T, LANES>, key: T) -> usize
+where
+    T: SimdElement,
+    Simd<T, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    candidates
+        .simd_lt(Simd::splat(key))
+        .to_bitmask()
+        .count_ones() as usize
+}
+
+/// Returns the index of the first element of `sorted` that isn't less than `key`, or the length
+/// of `sorted` if every element is less than `key`.
+///
+/// This is the index at which `key` could be inserted while keeping `sorted` in order, and is
+/// equivalent to `sorted.partition_point(|x| *x < key)`.  If `sorted` isn't sorted, the result
+/// is unspecified.
+///
+/// Rather than halving the range at each step, the range is divided into 17 parts by 16 evenly
+/// spaced candidates, which are compared with the key at once.  Counting the candidates less
+/// than the key selects the part containing the result, without branching on the comparisons.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::search;
+/// let sorted: Vec<u32> = (0..1000).map(|x| x * 2).collect();
+/// assert_eq!(search::lower_bound(&sorted, 0), 0);
+/// assert_eq!(search::lower_bound(&sorted, 501), 251);
+/// assert_eq!(search::lower_bound(&sorted, 502), 251);
+/// assert_eq!(search::lower_bound(&sorted, 5000), 1000);
+/// ```
+#[must_use]
+#[inline]
+pub fn lower_bound<T>(sorted: &[T], key: T) -> usize
+where
+    T: SimdElement,
+    Simd<T, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    let offsets = Simd::from_array(core::array::from_fn(|i| i + 1));
+
+    // The result is in `start..=start + len`
+    let mut start = 0;
+    let mut len = sorted.len();
+    while len > LANES {
+        // Candidate `i` is the last element of part `i`, the final part holding the remainder
+        let step = len / (LANES + 1);
+        let indices = Simd::splat(start) + offsets * Simd::splat(step) - Simd::splat(1);
+        // Safety: the largest index is `start + LANES * step - 1`, which is less than
+        // `start + len`, and so within `sorted`.
+        let candidates = unsafe {
+            Simd::gather_select_unchecked(sorted, Mask::splat(true), indices, Simd::splat(key))
+        };
+        let less = count_less(candidates, key);
+
+        // The result follows every candidate less than the key, and precedes the rest
+        start += less * step;
+        len = if less == LANES {
+            len - LANES * step
+        } else {
+            step - 1
+        };
+    }
+
+    // Pad with the key, which isn't less than itself
+    let mut remaining = [key; LANES];
+    remaining[..len].copy_from_slice(&sorted[start..start + len]);
+    start + count_less(Simd::from_array(remaining), key)
+}
@@ -1,5 +1,11 @@
+//! Selecting lanes of vectors, and selecting elements of slices.
+
 use crate::simd::intrinsics;
-use crate::simd::{LaneCount, Mask, MaskElement, Simd, SimdElement, SupportedLaneCount};
+use crate::simd::sort::Key;
+use crate::simd::{
+    LaneCount, Mask, MaskElement, Simd, SimdElement, SimdPartialOrd, SupportedLaneCount, Swizzle2,
+    ToBitMask, Which,
+};
 
 impl<T, const LANES: usize> Mask<T, LANES>
 where
@@ -11,6 +17,12 @@ impl<T, const LANES: usize> Mask<T, LANES>
     /// For each lane in the mask, choose the corresponding lane from `true_values` if
     /// that lane mask is true, and `false_values` if that lane mask is false.
     ///
+    /// This makes no promise about how long the select takes.  It's lowered to a vector select,
+    /// which targets usually implement with a blend or with bitwise operations, but the
+    /// optimizer is free to specialize it or turn it into a branch when it can prove something
+    /// about the mask, such as a mask splatted from a `bool`.  Code that must take the same time
+    /// for every mask, such as cryptography, should use [`Mask::select_branchless`] instead.
+    ///
     /// # Examples
     /// ```
     /// # #![feature(portable_simd)]
@@ -36,11 +48,55 @@ pub fn select<U>(
         unsafe { intrinsics::simd_select(self.to_int(), true_values, false_values) }
     }
 
+    /// Choose lanes from two vectors, without branching on the mask.
+    ///
+    /// Like [`Mask::select`], but the mask is hidden from the optimizer, so the select can't be
+    /// specialized for particular masks or turned into a branch.  Both vectors are always
+    /// computed, and the select takes the same instructions for every mask, as constant-time
+    /// code that selects on secret data requires.
+    ///
+    /// Like [`black_box`](core::hint::black_box), this is a best effort rather than a guarantee
+    /// the language makes.  The compiler's assembly tests check that it doesn't branch on
+    /// x86-64 and AArch64 with optimizations enabled, for the masks they cover; other targets
+    /// and optimization levels aren't checked.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{u32x4, Mask};
+    /// // Conditionally swap two vectors, depending on a secret bit
+    /// let (a, b) = (u32x4::splat(1), u32x4::splat(2));
+    /// let secret = true;
+    /// let swap = Mask::splat(secret);
+    /// let (a, b) = (swap.select_branchless(b, a), swap.select_branchless(a, b));
+    /// assert_eq!((a.to_array(), b.to_array()), ([2; 4], [1; 4]));
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn select_branchless<U>(
+        self,
+        true_values: Simd<U, LANES>,
+        false_values: Simd<U, LANES>,
+    ) -> Simd<U, LANES>
+    where
+        U: SimdElement<Mask = T>,
+    {
+        let mask = core::hint::black_box(self.to_int());
+        // Safety: The mask has been cast to a vector of integers,
+        // and the operands to select between are vectors of the same type and length.
+        unsafe { intrinsics::simd_select(mask, true_values, false_values) }
+    }
+
     /// Choose lanes from two masks.
     ///
     /// For each lane in the mask, choose the corresponding lane from `true_values` if
     /// that lane mask is true, and `false_values` if that lane mask is false.
     ///
+    /// Like [`Mask::select`], this makes no promise about how long the select takes: it's
+    /// written with bitwise operations, but the optimizer may turn it into a branch.
+    ///
     /// # Examples
     /// ```
     /// # #![feature(portable_simd)]
@@ -57,3 +113,187 @@ pub fn select_mask(self, true_values: Self, false_values: Self) -> Self {
         self & true_values | !self & false_values
     }
 }
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Choose lanes from a sequence of vectors, like the arms of a `match`.
+    ///
+    /// For each lane, choose the corresponding lane from the value of the first case whose mask
+    /// is true in that lane, or from `default` if no mask is true.
+    ///
+    /// This is lowered to a chain of [`Mask::select`]s, one for each case, applied from the
+    /// last case to the first so that earlier cases take priority.  It's equivalent to, but
+    /// easier to read than, nesting the selects by hand.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdPartialEq};
+    /// // FizzBuzz, where 0 is "FizzBuzz", -1 is "Fizz", and -2 is "Buzz"
+    /// let n = Simd::from_array([1i32, 3, 5, 15, 7, 9, 10, 30]);
+    /// let zero = Simd::splat(0);
+    /// let fizz = (n % Simd::splat(3)).simd_eq(zero);
+    /// let buzz = (n % Simd::splat(5)).simd_eq(zero);
+    ///
+    /// let result = Simd::select_many(
+    ///     &[(fizz & buzz, zero), (fizz, Simd::splat(-1)), (buzz, Simd::splat(-2))],
+    ///     n,
+    /// );
+    /// assert_eq!(result.to_array(), [1, -1, -2, 0, 7, -1, -2, 0]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn select_many(cases: &[(Mask<T::Mask, LANES>, Self)], default: Self) -> Self {
+        cases
+            .iter()
+            .rev()
+            .fold(default, |selected, (mask, values)| {
+                mask.select(*values, selected)
+            })
+    }
+
+    /// Choose lanes from three vectors by two masks.
+    ///
+    /// For each lane, choose the corresponding lane from `a` if `a_mask` is true in that lane,
+    /// otherwise from `b` if `b_mask` is true, and otherwise from `c`.  This is always exactly
+    /// two [`Mask::select`]s, which suits piecewise functions of three pieces, such as the
+    /// regions of a clamp or a piecewise approximation of an activation function.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdPartialOrd};
+    /// // Hard sigmoid: 0 below -3, 1 above 3, and linear in between
+    /// let x = f32x4::from_array([-4.0, -1.5, 0.0, 6.0]);
+    /// let below = x.simd_le(f32x4::splat(-3.0));
+    /// let above = x.simd_ge(f32x4::splat(3.0));
+    /// let linear = x / f32x4::splat(6.0) + f32x4::splat(0.5);
+    /// let y = f32x4::select3(below, above, f32x4::splat(0.0), f32x4::splat(1.0), linear);
+    /// assert_eq!(y.to_array(), [0.0, 0.25, 0.5, 1.0]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn select3(
+        a_mask: Mask<T::Mask, LANES>,
+        b_mask: Mask<T::Mask, LANES>,
+        a: Self,
+        b: Self,
+        c: Self,
+    ) -> Self {
+        a_mask.select(a, b_mask.select(b, c))
+    }
+}
+
+/// The largest number of elements [`top_k`] can select.
+const TOP_K_LANES: usize = 16;
+
+/// Shifts the lanes of the first vector up by one, filling the first lane from the second.
+struct InsertFirst;
+
+impl Swizzle2<TOP_K_LANES, TOP_K_LANES> for InsertFirst {
+    const INDEX: [Which; TOP_K_LANES] = {
+        let mut index = [Which::Second(0); TOP_K_LANES];
+        let mut i = 1;
+        while i < TOP_K_LANES {
+            index[i] = Which::First(i - 1);
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Writes the largest elements of `slice` to `top`, in descending order, and returns the number
+/// of elements written.
+///
+/// The number of elements selected, `k`, is the length of `top`, unless `slice` is shorter.
+/// Elements are ordered as in [`sort`](crate::simd::sort), so floats are ordered by their total
+/// order.
+///
+/// The largest elements so far are kept in order in a vector.  Each chunk of `slice` is
+/// compared with the smallest of them at once, and in the common case that no element of the
+/// chunk is larger, the chunk is skipped.  Otherwise, each larger element is inserted into the
+/// vector with a masked shift.
+///
+/// # Panics
+///
+/// Panics if `top` is longer than 16 elements.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::select;
+/// let distances = [4.5, 0.25, 9.0, 1.0, 0.5, 3.0];
+/// let mut top = [0.0; 3];
+/// assert_eq!(select::top_k(&distances, &mut top), 3);
+/// assert_eq!(top, [9.0, 4.5, 3.0]);
+///
+/// let mut top = [0; 4];
+/// assert_eq!(select::top_k(&[5, -2], &mut top), 2);
+/// assert_eq!(top[..2], [5, -2]);
+/// ```
+#[inline]
+pub fn top_k<T>(slice: &[T], top: &mut [T]) -> usize
+where
+    T: Key,
+    Simd<T::Bits, TOP_K_LANES>:
+        SimdPartialOrd<Mask = Mask<<T::Bits as SimdElement>::Mask, TOP_K_LANES>>,
+{
+    assert!(
+        top.len() <= TOP_K_LANES,
+        "can't select more than {TOP_K_LANES} elements"
+    );
+    let k = top.len().min(slice.len());
+    if k == 0 {
+        return 0;
+    }
+
+    // The largest keys, in descending order, starting from the smallest possible key.  Lanes
+    // past `k` only serve to make insertion unconditional: a key that isn't among the largest
+    // `k` is inserted past them, where it's ignored.
+    let mut largest = Simd::<T::Bits, TOP_K_LANES>::splat(T::Bits::default());
+    let mut add = |keys: Simd<T::Bits, TOP_K_LANES>| {
+        // Most chunks have no keys larger than the smallest of the largest so far
+        let mut larger = Simd::splat(largest[k - 1]).simd_lt(keys).to_bitmask();
+        while larger != 0 {
+            let key = Simd::splat(keys[larger.trailing_zeros() as usize]);
+            larger &= larger - 1;
+
+            // Shift the smaller keys up by one lane, and insert the key before them
+            let shifted = InsertFirst::swizzle2(largest, key);
+            let insert = largest.simd_lt(key);
+            let shift = shifted.simd_lt(key);
+            largest = shift.select(shifted, insert.select(key, largest));
+        }
+    };
+
+    let mut chunks = slice.chunks_exact(TOP_K_LANES);
+    for chunk in &mut chunks {
+        add(T::to_bits(Simd::from_slice(chunk)));
+    }
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        let mut padded = [rest[0]; TOP_K_LANES];
+        padded[..rest.len()].copy_from_slice(rest);
+        let keys = T::to_bits(Simd::from_array(padded)).to_array();
+        // Padding with the smallest possible key never inserts it
+        add(Simd::from_array(core::array::from_fn(|i| {
+            if i < rest.len() {
+                keys[i]
+            } else {
+                T::Bits::default()
+            }
+        })));
+    }
+
+    top[..k].copy_from_slice(&T::from_bits(largest)[..k]);
+    k
+}
@@ -0,0 +1,48 @@
+//! Shifting lanes across a vector, and the prefix sums built from those shifts.
+
+use crate::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount, Swizzle2, Which};
+use core::ops::Add;
+
+/// Shifts the lanes of the first vector up by `DISTANCE`, filling the lowest lanes from the
+/// first lane of the second.
+pub(crate) struct ShiftUp<const DISTANCE: usize>;
+
+impl<const DISTANCE: usize, const LANES: usize> Swizzle2<LANES, LANES> for ShiftUp<DISTANCE> {
+    const INDEX: [Which; LANES] = {
+        let mut index = [Which::Second(0); LANES];
+        let mut i = DISTANCE;
+        while i < LANES {
+            index[i] = Which::First(i - DISTANCE);
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Returns the sum of each lane and the lanes below it, in a logarithmic number of steps.
+#[inline]
+pub(crate) fn prefix_sum<T, const LANES: usize>(mut x: Simd<T, LANES>) -> Simd<T, LANES>
+where
+    T: SimdElement + Default,
+    Simd<T, LANES>: Add<Output = Simd<T, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(T::default());
+    x = x + ShiftUp::<1>::swizzle2(x, zero);
+    if LANES > 2 {
+        x = x + ShiftUp::<2>::swizzle2(x, zero);
+    }
+    if LANES > 4 {
+        x = x + ShiftUp::<4>::swizzle2(x, zero);
+    }
+    if LANES > 8 {
+        x = x + ShiftUp::<8>::swizzle2(x, zero);
+    }
+    if LANES > 16 {
+        x = x + ShiftUp::<16>::swizzle2(x, zero);
+    }
+    if LANES > 32 {
+        x = x + ShiftUp::<32>::swizzle2(x, zero);
+    }
+    x
+}
@@ -0,0 +1,329 @@
+//! Statistics and filters over slices of numbers, computed a vector at a time.
+//!
+//! Each function loads the slice a vector at a time, into several independent accumulators
+//! where possible, and handles any remaining elements at the end, so slices of any length and
+//! alignment are supported.
+
+use crate::simd::reduce::{self, Reduce};
+use crate::simd::{Mask, Simd, SimdElement, SimdUint, ToBitMask};
+
+/// The number of elements in each chunk.
+const LANES: usize = 16;
+
+/// The number of independent accumulators.
+const ACCUMULATORS: usize = 4;
+
+/// Splits `slice` into groups of full accumulators, full chunks, and a partial chunk.
+#[inline]
+fn split<T: SimdElement>(slice: &[T]) -> (&[T], &[T], &[T]) {
+    let groups = slice.len() - slice.len() % (LANES * ACCUMULATORS);
+    let chunks = slice.len() - slice.len() % LANES;
+    let (groups, rest) = slice.split_at(groups);
+    let (chunks, rest) = rest.split_at(chunks - groups.len());
+    (groups, chunks, rest)
+}
+
+/// Loads a partial chunk, filling the remaining lanes with `fill`.
+#[inline]
+fn load_partial<T: SimdElement>(partial: &[T], fill: T) -> Simd<T, LANES> {
+    let mut padded = [fill; LANES];
+    padded[..partial.len()].copy_from_slice(partial);
+    Simd::from_array(padded)
+}
+
+/// Combines every lane of `slice` with `combine`, starting from `init` in every lane.
+#[inline]
+fn fold<T, F>(slice: &[T], init: T, combine: F) -> Simd<T, LANES>
+where
+    T: SimdElement,
+    F: Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+{
+    let (groups, chunks, partial) = split(slice);
+    let mut accumulators = [Simd::splat(init); ACCUMULATORS];
+    for group in groups.chunks_exact(LANES * ACCUMULATORS) {
+        for (accumulator, chunk) in accumulators.iter_mut().zip(group.chunks_exact(LANES)) {
+            *accumulator = combine(*accumulator, Simd::from_slice(chunk));
+        }
+    }
+    for (accumulator, chunk) in accumulators.iter_mut().zip(chunks.chunks_exact(LANES)) {
+        *accumulator = combine(*accumulator, Simd::from_slice(chunk));
+    }
+    accumulators[0] = combine(accumulators[0], load_partial(partial, init));
+
+    let [a, b, c, d] = accumulators;
+    combine(combine(a, b), combine(c, d))
+}
+
+/// Returns the sum of the elements of `slice`.
+///
+/// Integers wrap on overflow.  Floats are summed in several independent parts, so the result
+/// may differ from summing in order.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// let values: Vec<u32> = (1..=100).collect();
+/// assert_eq!(slice::sum(&values), 5050);
+/// assert_eq!(slice::sum::<f32>(&[]), 0.0);
+/// ```
+#[must_use]
+#[inline]
+pub fn sum<T>(slice: &[T]) -> T
+where
+    T: SimdElement,
+    Simd<T, LANES>: Reduce<Scalar = T>,
+{
+    let zero = Simd::<T, LANES>::zero()[0];
+    fold(slice, zero, Reduce::lanewise_add).sum_lanes()
+}
+
+/// Returns the minimum element of `slice`, or `None` if it is empty.
+///
+/// For floats, NaNs are ignored unless every element is NaN.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// assert_eq!(slice::min(&[3, 1, 4, 1, 5, -9, 2, 6]), Some(-9));
+/// assert_eq!(slice::min(&[f32::NAN, 2.0, 1.0]), Some(1.0));
+/// assert_eq!(slice::min::<u8>(&[]), None);
+/// ```
+#[must_use]
+#[inline]
+pub fn min<T>(slice: &[T]) -> Option<T>
+where
+    T: SimdElement,
+    Simd<T, LANES>: Reduce<Scalar = T>,
+{
+    let first = *slice.first()?;
+    Some(fold(slice, first, Reduce::lanewise_min).min_lane())
+}
+
+/// Returns the maximum element of `slice`, or `None` if it is empty.
+///
+/// For floats, NaNs are ignored unless every element is NaN.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// assert_eq!(slice::max(&[3, 1, 4, 1, 5, -9, 2, 6]), Some(6));
+/// assert_eq!(slice::max(&[f64::NAN, 2.0, 1.0]), Some(2.0));
+/// assert_eq!(slice::max::<u8>(&[]), None);
+/// ```
+#[must_use]
+#[inline]
+pub fn max<T>(slice: &[T]) -> Option<T>
+where
+    T: SimdElement,
+    Simd<T, LANES>: Reduce<Scalar = T>,
+{
+    let first = *slice.first()?;
+    Some(fold(slice, first, Reduce::lanewise_max).max_lane())
+}
+
+/// Returns the minimum and maximum elements of `slice`, or `None` if it is empty.
+///
+/// Both are found in a single pass over the slice.  For floats, NaNs are ignored unless every
+/// element is NaN.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// let values: Vec<i16> = (-500..500).rev().collect();
+/// assert_eq!(slice::minmax(&values), Some((-500, 499)));
+/// ```
+#[must_use]
+#[inline]
+pub fn minmax<T>(slice: &[T]) -> Option<(T, T)>
+where
+    T: SimdElement,
+    Simd<T, LANES>: Reduce<Scalar = T>,
+{
+    let first = *slice.first()?;
+    let (groups, chunks, partial) = split(slice);
+    let mut min = Simd::splat(first);
+    let mut max = min;
+    for chunk in groups.chunks_exact(LANES).chain(chunks.chunks_exact(LANES)) {
+        let chunk = Simd::from_slice(chunk);
+        min = min.lanewise_min(chunk);
+        max = max.lanewise_max(chunk);
+    }
+    let partial = load_partial(partial, first);
+    let min = min.lanewise_min(partial).min_lane();
+    let max = max.lanewise_max(partial).max_lane();
+    Some((min, max))
+}
+
+/// Returns the arithmetic mean of the elements of `slice`, or `None` if it is empty.
+///
+/// The elements are converted to `f64` and summed without overflow, so the mean of integers is
+/// exact unless the sum exceeds 2<sup>53</sup> in magnitude.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// assert_eq!(slice::mean(&[u8::MAX; 1000]), Some(255.0));
+/// assert_eq!(slice::mean(&[1.0f32, 2.0, 4.0, 5.0]), Some(3.0));
+/// assert_eq!(slice::mean::<i32>(&[]), None);
+/// ```
+#[must_use]
+#[inline]
+pub fn mean<T>(slice: &[T]) -> Option<f64>
+where
+    T: SimdElement,
+    Simd<T, LANES>: Reduce<Scalar = T>,
+{
+    if slice.is_empty() {
+        return None;
+    }
+    let zero = Simd::<T, LANES>::zero()[0];
+    let (groups, chunks, partial) = split(slice);
+    let mut accumulators = [Simd::<f64, LANES>::splat(0.0); ACCUMULATORS];
+    for group in groups.chunks_exact(LANES * ACCUMULATORS) {
+        for (accumulator, chunk) in accumulators.iter_mut().zip(group.chunks_exact(LANES)) {
+            *accumulator += Simd::<T, LANES>::from_slice(chunk).cast::<f64>();
+        }
+    }
+    for (accumulator, chunk) in accumulators.iter_mut().zip(chunks.chunks_exact(LANES)) {
+        *accumulator += Simd::<T, LANES>::from_slice(chunk).cast::<f64>();
+    }
+    accumulators[0] += load_partial(partial, zero).cast::<f64>();
+    let sum = reduce::reduce_sum::<f64, LANES>(&accumulators);
+    Some(sum / slice.len() as f64)
+}
+
+/// Returns the dot product of `a` and `b`, the sum of the products of their corresponding
+/// elements.
+///
+/// The products are summed in several independent parts, so the result may differ from summing
+/// in order.  Each product is rounded before it is added, since fused multiply-add may require a
+/// call to a math library that isn't available here.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::slice;
+/// let a: Vec<f32> = (0..100).map(|x| x as f32).collect();
+/// let b = vec![2.0; 100];
+/// assert_eq!(slice::dot(&a, &b), 9900.0);
+/// assert_eq!(slice::dot(&[], &[]), 0.0);
+/// ```
+#[must_use]
+#[inline]
+pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let (a_groups, a_chunks, a_partial) = split(a);
+    let (b_groups, b_chunks, b_partial) = split(b);
+    let load = Simd::<f32, LANES>::from_slice;
+
+    let mut accumulators = [Simd::splat(0.0); ACCUMULATORS];
+    let groups = a_groups.chunks_exact(LANES * ACCUMULATORS);
+    for (a, b) in groups.zip(b_groups.chunks_exact(LANES * ACCUMULATORS)) {
+        let chunks = a.chunks_exact(LANES).zip(b.chunks_exact(LANES));
+        for (accumulator, (a, b)) in accumulators.iter_mut().zip(chunks) {
+            *accumulator += load(a) * load(b);
+        }
+    }
+    let chunks = a_chunks
+        .chunks_exact(LANES)
+        .zip(b_chunks.chunks_exact(LANES));
+    for (accumulator, (a, b)) in accumulators.iter_mut().zip(chunks) {
+        *accumulator += load(a) * load(b);
+    }
+    accumulators[0] += load_partial(a_partial, 0.0) * load_partial(b_partial, 0.0);
+    reduce::reduce_sum::<f32, LANES>(&accumulators)
+}
+
+/// Writes the lanes of `values` enabled by `enable` to consecutive elements of `dst`, starting at
+/// `start`, and returns the number of lanes written.
+///
+/// Each enabled lane is written to `start` plus the number of enabled lanes before it, which is
+/// counted for every lane at once, so the lanes are compressed with a single scatter.
+#[inline]
+fn compress_store<T: SimdElement>(
+    values: Simd<T, LANES>,
+    enable: Mask<T::Mask, LANES>,
+    dst: &mut [T],
+    start: usize,
+) -> usize {
+    let bits = enable.to_bitmask() as u32;
+    let below = Simd::from_array(core::array::from_fn(|i| (1 << i) - 1));
+    let before = (Simd::<u32, LANES>::splat(bits) & below).count_ones();
+    let indices = Simd::splat(start) + before.cast();
+    values.scatter_select(dst, enable.cast(), indices);
+    bits.count_ones() as usize
+}
+
+/// Writes the elements of `src` for which `predicate` is true to the start of `dst`, in order,
+/// and returns the number of elements written.
+///
+/// The predicate is evaluated a vector of elements at a time, returning a mask of the elements
+/// to keep.  The kept elements of each vector are then compressed into `dst` without branching
+/// on the mask.
+///
+/// # Panics
+///
+/// Panics if `dst` is shorter than `src`, which would be too short if every element were kept.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{slice, Simd, SimdPartialOrd};
+/// let prices: Vec<u32> = (0..100).map(|x| x * 37 % 101).collect();
+/// let mut cheap = vec![0; prices.len()];
+/// let count = slice::filter_into(&prices, |x| x.simd_lt(Simd::splat(10)), &mut cheap);
+///
+/// let expected: Vec<u32> = prices.iter().copied().filter(|x| *x < 10).collect();
+/// assert_eq!(cheap[..count], expected);
+/// ```
+#[inline]
+pub fn filter_into<T, F>(src: &[T], predicate: F, dst: &mut [T]) -> usize
+where
+    T: SimdElement,
+    F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+{
+    assert!(
+        dst.len() >= src.len(),
+        "destination must be at least as long as the source"
+    );
+    let Some(&first) = src.first() else {
+        return 0;
+    };
+
+    let chunks = src.chunks_exact(LANES);
+    let partial = chunks.remainder();
+    let mut count = 0;
+    for chunk in chunks {
+        let values = Simd::from_slice(chunk);
+        count += compress_store(values, predicate(values), dst, count);
+    }
+    if !partial.is_empty() {
+        let values = load_partial(partial, first);
+        let valid = Mask::from_array(core::array::from_fn(|i| i < partial.len()));
+        count += compress_store(values, predicate(values) & valid, dst, count);
+    }
+    count
+}
@@ -0,0 +1,313 @@
+//! Sorting small arrays with sorting networks.
+//!
+//! The keys are loaded into a single vector and sorted by a bitonic network, which compares
+//! every lane with a partner lane at each step, without branching.  These are building blocks
+//! for vectorized sorting algorithms, such as sorting the partitions of a quicksort once they
+//! are small enough.
+
+use crate::simd::{
+    LaneCount, Mask, Simd, SimdElement, SimdFloat, SimdOrd, SimdPartialEq, SupportedLaneCount,
+    Swizzle,
+};
+
+pub(crate) mod sealed {
+    use super::*;
+
+    /// Keys that can be sorted by mapping them to unsigned integers with the same order.
+    pub trait Key: SimdElement {
+        type Bits: SimdElement + Default;
+
+        fn to_bits<const LANES: usize>(keys: Simd<Self, LANES>) -> Simd<Self::Bits, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+
+        fn from_bits<const LANES: usize>(bits: Simd<Self::Bits, LANES>) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+    }
+}
+pub(crate) use sealed::Key;
+
+macro_rules! impl_unsigned_key {
+    { $($ty:ty),* } => {
+        $(
+        impl Key for $ty {
+            type Bits = $ty;
+
+            #[inline]
+            fn to_bits<const LANES: usize>(keys: Simd<$ty, LANES>) -> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                keys
+            }
+
+            #[inline]
+            fn from_bits<const LANES: usize>(bits: Simd<$ty, LANES>) -> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                bits
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_signed_key {
+    { $($ty:ty: $bits:ty),* } => {
+        $(
+        impl Key for $ty {
+            type Bits = $bits;
+
+            #[inline]
+            fn to_bits<const LANES: usize>(keys: Simd<$ty, LANES>) -> Simd<$bits, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                // Flipping the sign bit moves negative numbers below positive numbers
+                keys.cast::<$bits>() ^ Simd::splat(1 << (<$bits>::BITS - 1))
+            }
+
+            #[inline]
+            fn from_bits<const LANES: usize>(bits: Simd<$bits, LANES>) -> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                (bits ^ Simd::splat(1 << (<$bits>::BITS - 1))).cast()
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_float_key {
+    { $($ty:ty: $bits:ty, $signed:ty),* } => {
+        $(
+        impl Key for $ty {
+            type Bits = $bits;
+
+            #[inline]
+            fn to_bits<const LANES: usize>(keys: Simd<$ty, LANES>) -> Simd<$bits, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                // Flipping the sign bit of positive numbers moves them above negative numbers,
+                // and flipping every bit of negative numbers reverses their order.
+                let bits = keys.to_bits();
+                let sign = bits.cast::<$signed>() >> Simd::splat(<$bits>::BITS as $signed - 1);
+                bits ^ (sign.cast::<$bits>() | Simd::splat(1 << (<$bits>::BITS - 1)))
+            }
+
+            #[inline]
+            fn from_bits<const LANES: usize>(bits: Simd<$bits, LANES>) -> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                let positive =
+                    bits.cast::<$signed>() >> Simd::splat(<$bits>::BITS as $signed - 1);
+                let flip = !positive.cast::<$bits>() | Simd::splat(1 << (<$bits>::BITS - 1));
+                Simd::from_bits(bits ^ flip)
+            }
+        }
+        )*
+    }
+}
+
+impl_unsigned_key! { u32, u64 }
+impl_signed_key! { i32: u32, i64: u64 }
+impl_float_key! { f32: u32, i32, f64: u64, i64 }
+
+/// Swaps each lane with the lane whose index differs in bit `DISTANCE`.
+struct Partner<const DISTANCE: usize>;
+
+impl<const DISTANCE: usize, const LANES: usize> Swizzle<LANES, LANES> for Partner<DISTANCE> {
+    const INDEX: [usize; LANES] = {
+        let mut index = [0; LANES];
+        let mut i = 0;
+        while i < LANES {
+            // Stages wider than the vector are never run, but must still be valid
+            index[i] = if i ^ DISTANCE < LANES {
+                i ^ DISTANCE
+            } else {
+                i
+            };
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Compares each lane with its partner `DISTANCE` away, within bitonic sequences of length
+/// `BLOCK`, keeping the smaller key in the lower lane of blocks that are sorted in ascending
+/// order, and in the upper lane of the others.
+#[inline]
+fn stage<U, const DISTANCE: usize, const BLOCK: usize, const LANES: usize>(
+    bits: Simd<U, LANES>,
+) -> Simd<U, LANES>
+where
+    U: SimdElement,
+    Simd<U, LANES>: SimdOrd + SimdPartialEq<Mask = Mask<U::Mask, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let partner = Partner::<DISTANCE>::swizzle(bits);
+    let min = bits.simd_min(partner);
+    let max = bits.simd_max(partner);
+    let keep_min = Mask::from_array(core::array::from_fn(|i| {
+        (i & DISTANCE == 0) == (i & BLOCK == 0)
+    }));
+    keep_min.select(min, max)
+}
+
+/// Sorts the lanes of `bits` in ascending order.
+#[inline]
+fn sort_bits<U, const LANES: usize>(bits: Simd<U, LANES>) -> Simd<U, LANES>
+where
+    U: SimdElement,
+    Simd<U, LANES>: SimdOrd + SimdPartialEq<Mask = Mask<U::Mask, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // Sort blocks of 2, then merge pairs of blocks into blocks of 4, and so on
+    let mut bits = stage::<U, 1, 2, LANES>(bits);
+    bits = stage::<U, 2, 4, LANES>(bits);
+    bits = stage::<U, 1, 4, LANES>(bits);
+    bits = stage::<U, 4, 8, LANES>(bits);
+    bits = stage::<U, 2, 8, LANES>(bits);
+    bits = stage::<U, 1, 8, LANES>(bits);
+    if LANES >= 16 {
+        bits = stage::<U, 8, 16, LANES>(bits);
+        bits = stage::<U, 4, 16, LANES>(bits);
+        bits = stage::<U, 2, 16, LANES>(bits);
+        bits = stage::<U, 1, 16, LANES>(bits);
+    }
+    if LANES >= 32 {
+        bits = stage::<U, 16, 32, LANES>(bits);
+        bits = stage::<U, 8, 32, LANES>(bits);
+        bits = stage::<U, 4, 32, LANES>(bits);
+        bits = stage::<U, 2, 32, LANES>(bits);
+        bits = stage::<U, 1, 32, LANES>(bits);
+    }
+    bits
+}
+
+#[inline]
+fn sort<T, const LANES: usize>(keys: &mut [T; LANES])
+where
+    T: Key,
+    Simd<T::Bits, LANES>:
+        SimdOrd + SimdPartialEq<Mask = Mask<<T::Bits as SimdElement>::Mask, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let bits = T::to_bits(Simd::from_array(*keys));
+    *keys = T::from_bits(sort_bits(bits)).to_array();
+}
+
+macro_rules! impl_sort {
+    { $($name:ident: $lanes:literal),* } => {
+        $(
+        #[doc = concat!("Sorts an array of ", stringify!($lanes), " keys in ascending order.")]
+        ///
+        /// Sorting is supported for `u32`, `i32`, `f32`, `u64`, `i64` and `f64` keys.  Floats are
+        /// sorted by their total order, as in [`f32::total_cmp`]: negative NaNs first, then
+        /// negative infinity, negative numbers, `-0.0`, `0.0`, positive numbers, positive
+        /// infinity, and positive NaNs last.
+        ///
+        /// The sort isn't stable, but equal keys are indistinguishable.
+        ///
+        /// # Examples
+        /// ```
+        /// # #![feature(portable_simd)]
+        /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+        /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+        /// # use simd::sort;
+        /// let mut keys = core::array::from_fn(|i| (i as f32 - 4.5).powi(2));
+        #[doc = concat!("sort::", stringify!($name), "(&mut keys);")]
+        /// assert!(keys.windows(2).all(|pair| pair[0] <= pair[1]));
+        /// ```
+        #[inline]
+        pub fn $name<T>(keys: &mut [T; $lanes])
+        where
+            T: Key,
+            Simd<T::Bits, $lanes>:
+                SimdOrd + SimdPartialEq<Mask = Mask<<T::Bits as SimdElement>::Mask, $lanes>>,
+        {
+            sort(keys)
+        }
+        )*
+    }
+}
+
+impl_sort! { sort8: 8, sort16: 16, sort32: 32 }
+
+/// Sorts the lanes of a bitonic sequence `bits` in ascending order.
+#[inline]
+fn merge_bits<U, const LANES: usize>(bits: Simd<U, LANES>) -> Simd<U, LANES>
+where
+    U: SimdElement,
+    Simd<U, LANES>: SimdOrd + SimdPartialEq<Mask = Mask<U::Mask, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // A block wider than any vector, so every lane is sorted in ascending order
+    const BLOCK: usize = 128;
+    let mut bits = bits;
+    if LANES >= 64 {
+        bits = stage::<U, 32, BLOCK, LANES>(bits);
+    }
+    if LANES >= 32 {
+        bits = stage::<U, 16, BLOCK, LANES>(bits);
+    }
+    if LANES >= 16 {
+        bits = stage::<U, 8, BLOCK, LANES>(bits);
+    }
+    if LANES >= 8 {
+        bits = stage::<U, 4, BLOCK, LANES>(bits);
+    }
+    if LANES >= 4 {
+        bits = stage::<U, 2, BLOCK, LANES>(bits);
+    }
+    if LANES >= 2 {
+        bits = stage::<U, 1, BLOCK, LANES>(bits);
+    }
+    bits
+}
+
+/// Merges two vectors of keys sorted in ascending order, returning the smaller and larger
+/// halves of the merged keys, each sorted in ascending order.
+///
+/// Keys are ordered as in [`sort8`].  If either vector isn't sorted, the result is unspecified.
+///
+/// Reversing `b` and taking the lanewise minimum and maximum with `a` splits the keys into two
+/// bitonic sequences, with every key of the first no larger than any key of the second, which
+/// are then sorted by the second half of a bitonic network.  Merging a vector at a time like
+/// this is the inner step of a vectorized merge sort.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{sort, u32x4};
+/// let a = u32x4::from_array([1, 4, 6, 7]);
+/// let b = u32x4::from_array([2, 3, 5, 8]);
+/// let (lo, hi) = sort::merge_sorted(a, b);
+/// assert_eq!(lo.to_array(), [1, 2, 3, 4]);
+/// assert_eq!(hi.to_array(), [5, 6, 7, 8]);
+/// ```
+#[must_use]
+#[inline]
+pub fn merge_sorted<T, const LANES: usize>(
+    a: Simd<T, LANES>,
+    b: Simd<T, LANES>,
+) -> (Simd<T, LANES>, Simd<T, LANES>)
+where
+    T: Key,
+    Simd<T::Bits, LANES>:
+        SimdOrd + SimdPartialEq<Mask = Mask<<T::Bits as SimdElement>::Mask, LANES>>,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let a = T::to_bits(a);
+    let b = T::to_bits(b).reverse();
+    let lo = merge_bits(a.simd_min(b));
+    let hi = merge_bits(a.simd_max(b));
+    (T::from_bits(lo), T::from_bits(hi))
+}
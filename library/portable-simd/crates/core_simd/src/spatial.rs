@@ -0,0 +1,180 @@
+//! Morton codes (Z-order curves) for spatial indexes.
+//!
+//! A Morton code interleaves the bits of two or three coordinates, so points that are close in
+//! space tend to have close codes.  Sorting points by their codes groups them by location, and
+//! storing a texture in Morton order keeps neighbouring texels in the same cache lines.
+//!
+//! The bits are interleaved with [`SimdUint::bit_deposit`] and separated with
+//! [`SimdUint::bit_extract`].
+
+use crate::simd::{LaneCount, Simd, SimdElement, SimdUint, SupportedLaneCount};
+
+pub(crate) mod sealed {
+    use super::*;
+
+    /// Integers that Morton codes can be stored in.
+    pub trait Code: SimdElement {
+        /// The bits of each coordinate of a 2D code.
+        const MASKS_2D: [Self; 2];
+
+        /// The bits of each coordinate of a 3D code.
+        const MASKS_3D: [Self; 3];
+
+        fn deposit<const LANES: usize>(x: Simd<Self, LANES>, mask: Self) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+
+        fn extract<const LANES: usize>(code: Simd<Self, LANES>, mask: Self) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+
+        fn or<const LANES: usize>(a: Simd<Self, LANES>, b: Simd<Self, LANES>) -> Simd<Self, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount;
+    }
+}
+use sealed::Code;
+
+macro_rules! impl_code {
+    { $($ty:ty: $masks_2d:expr, $masks_3d:expr),* } => {
+        $(
+        impl Code for $ty {
+            const MASKS_2D: [$ty; 2] = $masks_2d;
+            const MASKS_3D: [$ty; 3] = $masks_3d;
+
+            #[inline]
+            fn deposit<const LANES: usize>(x: Simd<$ty, LANES>, mask: $ty) -> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                x.bit_deposit(Simd::splat(mask))
+            }
+
+            #[inline]
+            fn extract<const LANES: usize>(code: Simd<$ty, LANES>, mask: $ty) -> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                code.bit_extract(Simd::splat(mask))
+            }
+
+            #[inline]
+            fn or<const LANES: usize>(a: Simd<$ty, LANES>, b: Simd<$ty, LANES>) -> Simd<$ty, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                a | b
+            }
+        }
+        )*
+    }
+}
+
+impl_code! {
+    u32: [0x5555_5555, 0xaaaa_aaaa], [0x0924_9249, 0x1249_2492, 0x2492_4924],
+    u64: [0x5555_5555_5555_5555, 0xaaaa_aaaa_aaaa_aaaa],
+        [0x1249_2492_4924_9249, 0x2492_4924_9249_2492, 0x4924_9249_2492_4924]
+}
+
+/// Returns the 2D Morton code of each lane of `x` and `y`.
+///
+/// The bits of `x` are placed in the even bits of the code, and the bits of `y` in the odd bits.
+/// Each coordinate has half the bits of the code, 16 for `u32` and 32 for `u64`, and any higher
+/// bits are ignored.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{spatial, u32x4};
+/// let x = u32x4::from_array([0, 1, 0, 0b11]);
+/// let y = u32x4::from_array([0, 0, 1, 0b10]);
+/// let codes = spatial::morton_encode_2d(x, y);
+/// assert_eq!(codes.to_array(), [0, 0b01, 0b10, 0b1101]);
+/// assert_eq!(spatial::morton_decode_2d(codes), (x, y));
+/// ```
+#[must_use]
+#[inline]
+pub fn morton_encode_2d<T, const LANES: usize>(
+    x: Simd<T, LANES>,
+    y: Simd<T, LANES>,
+) -> Simd<T, LANES>
+where
+    T: Code,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let [mask_x, mask_y] = T::MASKS_2D;
+    T::or(T::deposit(x, mask_x), T::deposit(y, mask_y))
+}
+
+/// Returns the coordinates of each lane of the 2D Morton codes `code`.
+///
+/// This is the inverse of [`morton_encode_2d`].
+#[must_use]
+#[inline]
+pub fn morton_decode_2d<T, const LANES: usize>(
+    code: Simd<T, LANES>,
+) -> (Simd<T, LANES>, Simd<T, LANES>)
+where
+    T: Code,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let [mask_x, mask_y] = T::MASKS_2D;
+    (T::extract(code, mask_x), T::extract(code, mask_y))
+}
+
+/// Returns the 3D Morton code of each lane of `x`, `y` and `z`.
+///
+/// Bit `i` of `x`, `y` and `z` is placed in bit `3 * i`, `3 * i + 1` and `3 * i + 2` of the code.
+/// Each coordinate has a third of the bits of the code, 10 for `u32` and 21 for `u64`, and any
+/// higher bits are ignored.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{spatial, u64x2};
+/// let x = u64x2::from_array([1, (1 << 21) - 1]);
+/// let y = u64x2::from_array([0, 0]);
+/// let z = u64x2::from_array([1, 0]);
+/// let codes = spatial::morton_encode_3d(x, y, z);
+/// assert_eq!(codes.to_array(), [0b101, 0x1249_2492_4924_9249]);
+/// assert_eq!(spatial::morton_decode_3d(codes), (x, y, z));
+/// ```
+#[must_use]
+#[inline]
+pub fn morton_encode_3d<T, const LANES: usize>(
+    x: Simd<T, LANES>,
+    y: Simd<T, LANES>,
+    z: Simd<T, LANES>,
+) -> Simd<T, LANES>
+where
+    T: Code,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let [mask_x, mask_y, mask_z] = T::MASKS_3D;
+    let xy = T::or(T::deposit(x, mask_x), T::deposit(y, mask_y));
+    T::or(xy, T::deposit(z, mask_z))
+}
+
+/// Returns the coordinates of each lane of the 3D Morton codes `code`.
+///
+/// This is the inverse of [`morton_encode_3d`].
+#[must_use]
+#[inline]
+pub fn morton_decode_3d<T, const LANES: usize>(
+    code: Simd<T, LANES>,
+) -> (Simd<T, LANES>, Simd<T, LANES>, Simd<T, LANES>)
+where
+    T: Code,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let [mask_x, mask_y, mask_z] = T::MASKS_3D;
+    (
+        T::extract(code, mask_x),
+        T::extract(code, mask_y),
+        T::extract(code, mask_z),
+    )
+}
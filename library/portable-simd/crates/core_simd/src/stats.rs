@@ -0,0 +1,169 @@
+//! Statistics over slices, and the standardization of features for machine learning.
+
+use crate::simd::{f32x8, i32x8, u32x16, u8x16, Simd, SimdPartialOrd};
+
+/// The number of separate histograms that are counted into.
+const BANKS: usize = 4;
+
+/// Returns the number of times each byte value occurs in `bytes`.
+///
+/// Counting consecutive bytes into the same histogram is slow when they are equal, since each
+/// increment waits on the previous one to the same count.  Instead, the bytes are loaded a
+/// vector at a time and each lane is counted into one of several banks of counts in turn, so
+/// neighbouring increments are independent even in runs of a single value.  The banks are
+/// summed a vector of counts at a time at the end.
+///
+/// Counts wrap on overflow, which requires more than 4 GiB of input.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::stats;
+/// let histogram = stats::byte_histogram(b"mississippi");
+/// assert_eq!(histogram[usize::from(b'i')], 4);
+/// assert_eq!(histogram[usize::from(b'p')], 2);
+/// assert_eq!(histogram[usize::from(b'z')], 0);
+/// assert_eq!(histogram.iter().sum::<u32>(), 11);
+/// ```
+#[must_use]
+#[inline]
+pub fn byte_histogram(bytes: &[u8]) -> [u32; 256] {
+    let mut banks = [[0u32; 256]; BANKS];
+    let mut count = |bytes: &[u8]| {
+        for group in bytes.chunks(BANKS) {
+            for (bank, &byte) in banks.iter_mut().zip(group) {
+                let count = &mut bank[usize::from(byte)];
+                *count = count.wrapping_add(1);
+            }
+        }
+    };
+
+    let chunks = bytes.chunks_exact(u8x16::LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        count(u8x16::from_slice(chunk).as_array());
+    }
+    count(rest);
+
+    let mut histogram = [0; 256];
+    for (i, counts) in histogram.chunks_exact_mut(u32x16::LANES).enumerate() {
+        let start = i * u32x16::LANES;
+        let sum = banks.iter().fold(u32x16::splat(0), |sum, bank| {
+            sum + u32x16::from_slice(&bank[start..])
+        });
+        counts.copy_from_slice(sum.as_array());
+    }
+    histogram
+}
+
+/// Returns the mean and variance of `values`, in a single pass.
+///
+/// The variance is the population variance, the mean of the squared differences from the mean,
+/// as used to normalize a batch of features.  Each lane keeps the running mean and sum of
+/// squared differences of its own share of the values, updated with Welford's algorithm, which
+/// doesn't lose precision to cancellation as summing the values and their squares would.  The
+/// lanes are then combined as samples of the same population.
+///
+/// Returns NaN for both if `values` is empty.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::stats;
+/// assert_eq!(stats::mean_and_variance(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), (5.0, 4.0));
+///
+/// // The offset doesn't affect the variance
+/// let offset: Vec<f32> = [1.0, 2.0, 3.0].iter().map(|x| x + 1e6).collect();
+/// let (mean, variance) = stats::mean_and_variance(&offset);
+/// assert_eq!(mean, 1e6 + 2.0);
+/// assert!((variance - 2.0 / 3.0).abs() < 1e-3);
+/// ```
+#[must_use]
+#[inline]
+pub fn mean_and_variance(values: &[f32]) -> (f32, f32) {
+    let chunks = values.chunks_exact(f32x8::LANES);
+    let rest = chunks.remainder();
+    // Counted as an integer, since a float count stops increasing at 2^24
+    let mut count = 0usize;
+    let mut mean = f32x8::splat(0.0);
+    let mut squares = f32x8::splat(0.0);
+    for chunk in chunks {
+        let x = f32x8::from_slice(chunk);
+        count += 1;
+        let delta = x - mean;
+        mean += delta / f32x8::splat(count as f32);
+        squares += delta * (x - mean);
+    }
+
+    // Only the lanes holding values of the last vector are updated
+    if !rest.is_empty() {
+        let lanes = i32x8::from_array(core::array::from_fn(|i| i as i32));
+        let valid = lanes.simd_lt(Simd::splat(rest.len() as i32));
+        let x = f32x8::load_or_default(rest);
+        let delta = x - mean;
+        let updated_mean = mean + delta / f32x8::splat((count + 1) as f32);
+        squares = valid.select(squares + delta * (x - updated_mean), squares);
+        mean = valid.select(updated_mean, mean);
+    }
+    let counts: [usize; f32x8::LANES] = core::array::from_fn(|i| count + (i < rest.len()) as usize);
+
+    // Combine the lanes with Chan's formula for the variance of a union of samples
+    let (mut total_count, mut total_mean, mut total_squares) = (0.0, 0.0, 0.0);
+    for ((count, mean), squares) in counts
+        .into_iter()
+        .zip(mean.to_array())
+        .zip(squares.to_array())
+    {
+        if count == 0 {
+            continue;
+        }
+        let count = count as f32;
+        let combined_count = total_count + count;
+        let delta = mean - total_mean;
+        total_mean += delta * count / combined_count;
+        total_squares += squares + delta * delta * total_count * count / combined_count;
+        total_count = combined_count;
+    }
+    if total_count == 0.0 {
+        (f32::NAN, f32::NAN)
+    } else {
+        (total_mean, total_squares / total_count)
+    }
+}
+
+/// Standardizes `values` in place, subtracting `mean` from each value and dividing by
+/// `std_dev`.
+///
+/// With the mean and the square root of the variance from [`mean_and_variance`], this scales the
+/// values to a mean of 0 and a variance of 1, as features are before they are fed to a model.
+/// Each value is multiplied by the reciprocal of `std_dev` rather than divided by it, which is
+/// faster but can differ from dividing in the last bit.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::stats;
+/// let mut values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, 3.0];
+/// stats::standardize(&mut values, 5.0, 2.0);
+/// assert_eq!(values, [-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0, -1.0]);
+/// ```
+#[inline]
+pub fn standardize(values: &mut [f32], mean: f32, std_dev: f32) {
+    let mean = f32x8::splat(mean);
+    let scale = f32x8::splat(1.0 / std_dev);
+    let mut chunks = values.chunks_exact_mut(f32x8::LANES);
+    for chunk in &mut chunks {
+        let x = f32x8::from_slice(chunk);
+        chunk.copy_from_slice(((x - mean) * scale).as_array());
+    }
+
+    let chunk = chunks.into_remainder();
+    let x = (f32x8::load_or_default(chunk) - mean) * scale;
+    chunk.copy_from_slice(&x[..chunk.len()]);
+}
@@ -1,5 +1,5 @@
 use crate::simd::intrinsics;
-use crate::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+use crate::simd::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
 
 /// Constructs a new SIMD vector by copying elements from selected lanes in other vectors.
 ///
@@ -183,6 +183,26 @@ impl<T, const INPUT_LANES: usize, const OUTPUT_LANES: usize> Swizzle2Impl<INPUT_
     };
 }
 
+/// Copy the only lane of `vector` to every lane of the output, for [`Simd::splat`].
+///
+/// Unlike [`Swizzle::swizzle`], this is always inlined, so splatting is a broadcast even in
+/// unoptimized builds.
+#[inline(always)]
+pub(crate) fn broadcast<T, const LANES: usize>(vector: Simd<T, 1>) -> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    struct Splat;
+    impl<const LANES: usize> Swizzle<1, LANES> for Splat {
+        const INDEX: [usize; LANES] = [0; LANES];
+    }
+    // Safety: `vector` is a vector, and `INDEX_IMPL` is a const array of u32.
+    unsafe {
+        intrinsics::simd_shuffle(vector, vector, <Splat as SwizzleImpl<1, LANES>>::INDEX_IMPL)
+    }
+}
+
 impl<T, const LANES: usize> Simd<T, LANES>
 where
     T: SimdElement,
@@ -263,6 +283,64 @@ impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES, LANES> for Rotate<O
         Rotate::<OFFSET>::swizzle(self)
     }
 
+    /// Rotates the vector left by `n` lanes, like [`rotate_lanes_left`](Self::rotate_lanes_left),
+    /// but with an offset that is only known at runtime.
+    ///
+    /// `n` is taken modulo `LANES`.  The rotation is composed of a constant rotation by each
+    /// power of two below `LANES`, each selected by the matching bit of `n`, so it takes
+    /// `log2(LANES)` swizzles and selects and doesn't branch on `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u32x4;
+    /// let v = u32x4::from_array([0, 1, 2, 3]);
+    /// assert_eq!(v.rotate_lanes_left_dyn(1).to_array(), [1, 2, 3, 0]);
+    /// assert_eq!(v.rotate_lanes_left_dyn(6).to_array(), [2, 3, 0, 1]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn rotate_lanes_left_dyn(self, n: usize) -> Self {
+        let n = n % LANES;
+        let mut x = self;
+        macro_rules! step {
+            { $($offset:literal),* } => {
+                $(
+                    if LANES > $offset {
+                        x = Mask::<T::Mask, LANES>::splat(n & $offset != 0)
+                            .select(x.rotate_lanes_left::<$offset>(), x);
+                    }
+                )*
+            }
+        }
+        step! { 1, 2, 4, 8, 16, 32 }
+        x
+    }
+
+    /// Rotates the vector right by `n` lanes, like
+    /// [`rotate_lanes_right`](Self::rotate_lanes_right), but with an offset that is only known at
+    /// runtime.
+    ///
+    /// `n` is taken modulo `LANES`.  See [`rotate_lanes_left_dyn`](Self::rotate_lanes_left_dyn).
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u32x4;
+    /// let v = u32x4::from_array([0, 1, 2, 3]);
+    /// assert_eq!(v.rotate_lanes_right_dyn(1).to_array(), [3, 0, 1, 2]);
+    /// assert_eq!(v.rotate_lanes_right_dyn(4).to_array(), [0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn rotate_lanes_right_dyn(self, n: usize) -> Self {
+        self.rotate_lanes_left_dyn(LANES - n % LANES)
+    }
+
     /// Interleave two vectors.
     ///
     /// Produces two vectors with lanes taken alternately from `self` and `other`.
@@ -382,4 +460,98 @@ impl<const LANES: usize> Swizzle2<LANES, LANES> for Odd {
 
         (Even::swizzle2(self, other), Odd::swizzle2(self, other))
     }
+
+    /// Repeats each lane `K` times, into a vector of `OUTPUT_LANES` lanes.
+    ///
+    /// Lane `i` of the output is lane `i / K` of `self`, which matches a vector with an operand
+    /// that has a value for each group of `K` lanes, or upsamples a signal by a factor of `K`.
+    /// `OUTPUT_LANES` must be `LANES * K`, which is checked at compile time.
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let a = Simd::from_array([0, 1]);
+    /// assert_eq!(a.repeat_each::<2, 4>().to_array(), [0, 0, 1, 1]);
+    /// assert_eq!(a.repeat_each::<4, 8>().to_array(), [0, 0, 0, 0, 1, 1, 1, 1]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn repeat_each<const K: usize, const OUTPUT_LANES: usize>(self) -> Simd<T, OUTPUT_LANES>
+    where
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        const fn repeat_index<const K: usize, const LANES: usize, const OUTPUT_LANES: usize>(
+        ) -> [usize; OUTPUT_LANES] {
+            assert!(
+                K * LANES == OUTPUT_LANES,
+                "output lanes must be `LANES * K`"
+            );
+            let mut index = [0; OUTPUT_LANES];
+            let mut i = 0;
+            while i < OUTPUT_LANES {
+                index[i] = i / K;
+                i += 1;
+            }
+            index
+        }
+
+        struct RepeatEach<const K: usize>;
+
+        impl<const K: usize, const LANES: usize, const OUTPUT_LANES: usize>
+            Swizzle<LANES, OUTPUT_LANES> for RepeatEach<K>
+        {
+            const INDEX: [usize; OUTPUT_LANES] = repeat_index::<K, LANES, OUTPUT_LANES>();
+        }
+
+        RepeatEach::<K>::swizzle(self)
+    }
+
+    /// Takes every `K`th lane, starting with the first, into a vector of `OUTPUT_LANES` lanes.
+    ///
+    /// Lane `i` of the output is lane `i * K` of `self`, which reverses
+    /// [`repeat_each`](Self::repeat_each), or downsamples a signal by a factor of `K`.
+    /// `OUTPUT_LANES` must be `LANES / K`, which is checked at compile time.
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let a = Simd::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    /// assert_eq!(a.take_every::<2, 4>().to_array(), [0, 2, 4, 6]);
+    /// assert_eq!(a.repeat_each::<4, 32>().take_every::<4, 8>(), a);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn take_every<const K: usize, const OUTPUT_LANES: usize>(self) -> Simd<T, OUTPUT_LANES>
+    where
+        LaneCount<OUTPUT_LANES>: SupportedLaneCount,
+    {
+        const fn take_index<const K: usize, const LANES: usize, const OUTPUT_LANES: usize>(
+        ) -> [usize; OUTPUT_LANES] {
+            assert!(
+                K * OUTPUT_LANES == LANES,
+                "output lanes must be `LANES / K`"
+            );
+            let mut index = [0; OUTPUT_LANES];
+            let mut i = 0;
+            while i < OUTPUT_LANES {
+                index[i] = i * K;
+                i += 1;
+            }
+            index
+        }
+
+        struct TakeEvery<const K: usize>;
+
+        impl<const K: usize, const LANES: usize, const OUTPUT_LANES: usize>
+            Swizzle<LANES, OUTPUT_LANES> for TakeEvery<K>
+        {
+            const INDEX: [usize; OUTPUT_LANES] = take_index::<K, LANES, OUTPUT_LANES>();
+        }
+
+        TakeEvery::<K>::swizzle(self)
+    }
 }
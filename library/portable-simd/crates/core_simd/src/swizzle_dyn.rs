@@ -0,0 +1,113 @@
+use crate::simd::{LaneCount, Simd, SimdPartialOrd, SupportedLaneCount};
+
+impl<const N: usize> Simd<u8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Swizzle a vector of bytes according to the index vector, which is only known at runtime.
+    ///
+    /// Each lane of the result is the lane of `self` selected by the corresponding lane of
+    /// `idxs`.  Indices that are out of bounds select 0.
+    ///
+    /// On targets with a byte shuffle instruction (such as `pshufb` or `tbl`) for vectors of
+    /// `N` bytes, this is a single instruction, if the instruction is enabled when the standard
+    /// library is built.  Otherwise, the lanes are selected one at a time.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// // A lookup table of hexadecimal digits
+    /// let digits = Simd::from_array(*b"0123456789abcdef");
+    /// let nibbles = Simd::from_array([
+    ///     0xd, 0xe, 0xa, 0xd, 0xb, 0xe, 0xe, 0xf, 0x0, 0x1, 0x2, 0x3, 200, 16, 0xf, 0x0,
+    /// ]);
+    /// assert_eq!(digits.swizzle_dyn(nibbles).to_array(), *b"deadbeef0123\0\0f0");
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn swizzle_dyn(self, idxs: Simd<u8, N>) -> Self {
+        #![allow(unused_imports, unused_unsafe)]
+        #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+        use core::arch::aarch64::{vqtbl1q_u8, vtbl1_u8};
+        #[cfg(target_arch = "wasm32")]
+        use core::arch::wasm32 as wasm;
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64 as x86;
+
+        // Safety: each intrinsic is only used when its target feature is enabled, and on
+        // vectors of the same size as `Simd<u8, N>`.
+        unsafe {
+            match N {
+                #[cfg(all(
+                    target_arch = "aarch64",
+                    target_endian = "little",
+                    target_feature = "neon"
+                ))]
+                8 => transize(vtbl1_u8, self, idxs),
+                #[cfg(all(
+                    target_arch = "aarch64",
+                    target_endian = "little",
+                    target_feature = "neon"
+                ))]
+                16 => transize(vqtbl1q_u8, self, idxs),
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    target_feature = "ssse3"
+                ))]
+                16 => transize(x86::_mm_shuffle_epi8, self, zeroing_idxs(idxs)),
+                #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+                16 => transize(wasm::i8x16_swizzle, self, idxs),
+                _ => {
+                    let mut array = [0; N];
+                    for (i, k) in idxs.to_array().into_iter().enumerate() {
+                        if (k as usize) < N {
+                            array[i] = self[k as usize];
+                        }
+                    }
+                    array.into()
+                }
+            }
+        }
+    }
+}
+
+/// Calls a vendor byte shuffle on vectors of the same size as `Simd<u8, N>`.
+///
+/// # Safety
+/// `T` must be a vector of `N` bytes, and `f` must be supported by the target.
+#[allow(unused)]
+#[inline(always)]
+unsafe fn transize<T, const N: usize>(
+    f: unsafe fn(T, T) -> T,
+    bytes: Simd<u8, N>,
+    idxs: Simd<u8, N>,
+) -> Simd<u8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    // Safety: the caller ensures `T` has the same size, and every bit pattern is a valid vector
+    // of bytes.
+    unsafe {
+        let result = f(
+            core::mem::transmute_copy(&bytes),
+            core::mem::transmute_copy(&idxs),
+        );
+        core::mem::transmute_copy(&result)
+    }
+}
+
+/// Sets the high bit of out of bounds indices, which `pshufb` zeroes.
+#[allow(unused)]
+#[inline(always)]
+fn zeroing_idxs<const N: usize>(idxs: Simd<u8, N>) -> Simd<u8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    idxs.simd_lt(Simd::splat(N as u8))
+        .select(idxs, Simd::splat(u8::MAX))
+}
@@ -0,0 +1,146 @@
+//! Transcoding between UTF-8 and UTF-16.
+//!
+//! Runs of ASCII characters, the common case for most text, are converted a vector at a time.
+//! Other characters are converted one at a time, including validation and surrogate pairs.
+
+use crate::simd::{u16x16, u8x16, SimdPartialOrd};
+use core::fmt;
+
+/// The number of code units converted at a time.
+const LANES: usize = 16;
+
+/// An error returned when transcoding input that isn't valid UTF-8 or UTF-16.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TranscodeError {
+    valid_up_to: usize,
+}
+
+impl TranscodeError {
+    /// Returns the number of code units at the beginning of the input that are valid.
+    #[inline]
+    #[must_use]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl fmt::Display for TranscodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid code unit sequence at index {}",
+            self.valid_up_to
+        )
+    }
+}
+
+/// Converts UTF-8 to UTF-16, returning the number of code units written to `dst`.
+///
+/// If `src` isn't valid UTF-8, returns an error and the contents of `dst` are unspecified.
+///
+/// # Panics
+///
+/// Panics if `dst` is too small to hold the converted text.
+/// A `dst` as long as `src` is always large enough.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::unicode;
+/// let text = "Grüße, 世界! 🦀";
+/// let mut utf16 = [0; 32];
+/// let len = unicode::utf8_to_utf16(text.as_bytes(), &mut utf16).unwrap();
+/// assert!(utf16[..len].iter().copied().eq(text.encode_utf16()));
+///
+/// let error = unicode::utf8_to_utf16(b"abc\xff", &mut utf16).unwrap_err();
+/// assert_eq!(error.valid_up_to(), 3);
+/// ```
+#[inline]
+pub fn utf8_to_utf16(src: &[u8], dst: &mut [u16]) -> Result<usize, TranscodeError> {
+    let src = core::str::from_utf8(src).map_err(|error| TranscodeError {
+        valid_up_to: error.valid_up_to(),
+    })?;
+    let bytes = src.as_bytes();
+
+    let mut i = 0;
+    let mut len = 0;
+    while i < bytes.len() {
+        if let Some(block) = bytes.get(i..i + LANES) {
+            let block = u8x16::from_slice(block);
+            if block.simd_lt(u8x16::splat(0x80)).all() {
+                dst[len..len + LANES].copy_from_slice(block.cast::<u16>().as_array());
+                i += LANES;
+                len += LANES;
+                continue;
+            }
+        }
+
+        // Convert the rest of the block one character at a time
+        let block_end = i + LANES;
+        for c in src[i..].chars() {
+            len += c.encode_utf16(&mut dst[len..]).len();
+            i += c.len_utf8();
+            if i >= block_end {
+                break;
+            }
+        }
+    }
+    Ok(len)
+}
+
+/// Converts UTF-16 to UTF-8, returning the number of bytes written to `dst`.
+///
+/// If `src` isn't valid UTF-16, such as when it contains an unpaired surrogate, returns an error
+/// and the contents of `dst` are unspecified.
+///
+/// # Panics
+///
+/// Panics if `dst` is too small to hold the converted text.
+/// A `dst` three times as long as `src` is always large enough.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::unicode;
+/// let text = "Grüße, 世界! 🦀";
+/// let utf16: Vec<u16> = text.encode_utf16().collect();
+/// let mut utf8 = [0; 64];
+/// let len = unicode::utf16_to_utf8(&utf16, &mut utf8).unwrap();
+/// assert_eq!(&utf8[..len], text.as_bytes());
+///
+/// // An unpaired surrogate
+/// let error = unicode::utf16_to_utf8(&[0x61, 0xd800, 0x62], &mut utf8).unwrap_err();
+/// assert_eq!(error.valid_up_to(), 1);
+/// ```
+#[inline]
+pub fn utf16_to_utf8(src: &[u16], dst: &mut [u8]) -> Result<usize, TranscodeError> {
+    let mut i = 0;
+    let mut len = 0;
+    while i < src.len() {
+        if let Some(block) = src.get(i..i + LANES) {
+            let block = u16x16::from_slice(block);
+            if block.simd_lt(u16x16::splat(0x80)).all() {
+                dst[len..len + LANES].copy_from_slice(block.cast::<u8>().as_array());
+                i += LANES;
+                len += LANES;
+                continue;
+            }
+        }
+
+        // Convert the rest of the block one character at a time
+        let block_end = i + LANES;
+        for c in char::decode_utf16(src[i..].iter().copied()) {
+            let c = c.map_err(|_| TranscodeError { valid_up_to: i })?;
+            len += c.encode_utf8(&mut dst[len..]).len();
+            i += c.len_utf16();
+            if i >= block_end {
+                break;
+            }
+        }
+    }
+    Ok(len)
+}
@@ -0,0 +1,155 @@
+//! LEB128 variable-length integers, as used by Protocol Buffers and many columnar formats.
+//!
+//! Each byte holds seven bits of the integer, least significant first, and has its high bit set
+//! if more bytes follow.  The ends of the integers in a block of bytes are found from a mask of
+//! the bytes without that continuation bit.  Then the bytes of two integers at a time are moved
+//! into the lanes of a vector with a dynamic shuffle, and the continuation bits are squeezed out
+//! of both at once.
+
+use crate::simd::{u64x2, u8x16, SimdPartialOrd, ToBitMask};
+
+/// The number of bytes in each block.
+const BLOCK: usize = 16;
+
+/// The longest encoding of a `u64`.
+const MAX_LEN: usize = 10;
+
+/// Squeezes the continuation bits out of each lane of up to eight bytes.
+#[inline]
+fn compact(words: u64x2) -> u64x2 {
+    let x = words & u64x2::splat(0x7f7f_7f7f_7f7f_7f7f);
+    let x = (x & u64x2::splat(0x007f_007f_007f_007f))
+        | ((x & u64x2::splat(0x7f00_7f00_7f00_7f00)) >> u64x2::splat(1));
+    let x = (x & u64x2::splat(0x0000_3fff_0000_3fff))
+        | ((x & u64x2::splat(0x3fff_0000_3fff_0000)) >> u64x2::splat(2));
+    (x & u64x2::splat(0x0000_0000_0fff_ffff))
+        | ((x & u64x2::splat(0x0fff_ffff_0000_0000)) >> u64x2::splat(4))
+}
+
+/// Decodes the integer at the start of `input`, returning it and the length of its encoding.
+///
+/// Returns `None` if the integer is incomplete or longer than [`MAX_LEN`].
+#[inline]
+fn decode_one(input: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0;
+    for (i, byte) in input.iter().take(MAX_LEN).enumerate() {
+        value |= ((byte & 0x7f) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// Decodes the two integers at `start..start + len` in `block`, where each is at most eight
+/// bytes long.
+#[inline]
+fn decode_pair(block: u8x16, start: [u8; 2], len: [u8; 2]) -> u64x2 {
+    let lane = u8x16::from_array([0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7]);
+    let mut starts = [start[0]; BLOCK];
+    let mut lens = [len[0]; BLOCK];
+    starts[BLOCK / 2..].fill(start[1]);
+    lens[BLOCK / 2..].fill(len[1]);
+
+    // Bytes past the end of each integer are zeroed by out of bounds indices
+    let idxs = lane
+        .simd_lt(u8x16::from_array(lens))
+        .select(lane + u8x16::from_array(starts), u8x16::splat(u8::MAX));
+    let bytes = block.swizzle_dyn(idxs).to_array();
+    let (low, high) = bytes.split_at(BLOCK / 2);
+    compact(u64x2::from_array([
+        u64::from_le_bytes(low.try_into().unwrap()),
+        u64::from_le_bytes(high.try_into().unwrap()),
+    ]))
+}
+
+/// Decodes the integers at the start of `input` into `output`, returning the number of integers
+/// decoded and the number of bytes they were encoded in.
+///
+/// Decoding stops when `output` is full, at the end of `input`, or at an integer that is
+/// incomplete or longer than the ten bytes a `u64` needs.  The remaining bytes can be decoded
+/// later, such as when more input arrives.  Bits of an integer beyond the 64 that fit in a `u64`
+/// are ignored.
+///
+/// Blocks of 16 integers of a single byte each, common with small values, are decoded at once.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::varint;
+/// // 1, 300 and 2^32, followed by the first byte of an incomplete integer
+/// let input = [0x01, 0xac, 0x02, 0x80, 0x80, 0x80, 0x80, 0x10, 0xff];
+/// let mut output = [0; 8];
+/// assert_eq!(varint::decode_batch(&input, &mut output), (3, 8));
+/// assert_eq!(output[..3], [1, 300, 1 << 32]);
+/// ```
+#[inline]
+pub fn decode_batch(input: &[u8], output: &mut [u64]) -> (usize, usize) {
+    let mut read = 0;
+    let mut written = 0;
+
+    // Every integer ending in a block fits in `output`
+    'blocks: while read + BLOCK <= input.len() && written + BLOCK <= output.len() {
+        let block = u8x16::from_slice(&input[read..]);
+        let mut ends = !block.simd_ge(u8x16::splat(0x80)).to_bitmask();
+        if ends == u16::MAX {
+            output[written..written + BLOCK].copy_from_slice(block.cast::<u64>().as_array());
+            read += BLOCK;
+            written += BLOCK;
+            continue;
+        }
+        if ends == 0 {
+            // Too long to be valid
+            break;
+        }
+
+        // Decode the integers that end in the block, two at a time
+        let mut start = 0;
+        while ends != 0 {
+            let end = ends.trailing_zeros() as usize;
+            ends &= ends - 1;
+            let mut pair = [(start, end + 1 - start), (end + 1, 0)];
+            if ends != 0 {
+                pair[1].1 = ends.trailing_zeros() as usize - end;
+                ends &= ends - 1;
+            }
+            let count = if pair[1].1 == 0 { 1 } else { 2 };
+
+            if pair[0].1 <= 8 && pair[1].1 <= 8 {
+                let values = decode_pair(
+                    block,
+                    pair.map(|(start, _)| start as u8),
+                    pair.map(|(_, len)| len as u8),
+                );
+                output[written..written + count].copy_from_slice(&values[..count]);
+            } else {
+                for (i, (start, len)) in pair.into_iter().take(count).enumerate() {
+                    match decode_one(&input[read + start..read + start + len]) {
+                        Some((value, _)) => output[written + i] = value,
+                        None => {
+                            written += i;
+                            read += start;
+                            break 'blocks;
+                        }
+                    }
+                }
+            }
+            written += count;
+            start = pair[1].0 + pair[1].1;
+        }
+        read += start;
+    }
+
+    // Decode the rest one at a time
+    while written < output.len() {
+        let Some((value, len)) = decode_one(&input[read..]) else {
+            break;
+        };
+        output[written] = value;
+        written += 1;
+        read += len;
+    }
+    (written, read)
+}
@@ -6,13 +6,13 @@
 pub use int::*;
 pub use uint::*;
 
-// Vectors of pointers are not for public use at the current time.
-pub(crate) mod ptr;
-
 use crate::simd::{
-    intrinsics, LaneCount, Mask, MaskElement, SimdPartialOrd, SupportedLaneCount, Swizzle,
+    intrinsics, mem::AlignedSlice, LaneCount, Mask, MaskElement, SameBits, SimdConstPtr, SimdInt,
+    SimdMutPtr, SimdPartialEq, SimdPartialOrd, SimdUint, SupportedLaneCount,
 };
 
+use super::swizzle::broadcast;
+
 /// A SIMD vector of `LANES` elements of type `T`. `Simd<T, N>` has the same shape as [`[T; N]`](array), but operates like `T`.
 ///
 /// Two vectors of the same type and length will, by convention, support the operators (+, *, etc.) that `T` does.
@@ -124,14 +124,34 @@ pub const fn lanes(&self) -> usize {
     /// let v = u32x4::splat(8);
     /// assert_eq!(v.as_array(), &[8, 8, 8, 8]);
     /// ```
+    #[inline(always)]
     pub fn splat(value: T) -> Self {
         // This is preferred over `[value; LANES]`, since it's explicitly a splat:
         // https://github.com/rust-lang/rust/issues/97804
-        struct Splat;
-        impl<const LANES: usize> Swizzle<1, LANES> for Splat {
-            const INDEX: [usize; LANES] = [0; LANES];
-        }
-        Splat::swizzle(Simd::<T, 1>::from([value]))
+        // Constructing the vector directly, rather than with `From`, and broadcasting it with
+        // a shuffle that's always inlined leaves nothing but the broadcast in unoptimized builds
+        broadcast(Simd::<T, 1>([value]))
+    }
+
+    /// Constructs a new SIMD vector with all lanes set to the value behind `value`.
+    ///
+    /// Where the target can broadcast from memory, such as with `vbroadcastss` on x86 with AVX, the
+    /// load folds into the broadcast rather than going through a register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::f32x4;
+    /// let scale = 0.5;
+    /// let v = f32x4::splat_ref(&scale);
+    /// assert_eq!(v.as_array(), &[0.5; 4]);
+    /// ```
+    #[inline(always)]
+    pub fn splat_ref(value: &T) -> Self {
+        Self::splat(*value)
     }
 
     /// Returns an array reference containing the entire SIMD vector.
@@ -190,6 +210,34 @@ pub const fn from_slice(slice: &[T]) -> Self {
         Self(array)
     }
 
+    /// Converts an aligned slice to a SIMD vector containing `slice[..LANES]`.
+    ///
+    /// Unlike [`from_slice`](Self::from_slice), this is an aligned load, since the slice's
+    /// alignment was checked when the [`AlignedSlice`] was created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than the vector's `Simd::LANES`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{mem::AlignedSlice, u32x4};
+    /// let vectors = [u32x4::from_array([1, 2, 3, 4]), u32x4::from_array([5, 6, 7, 8])];
+    /// let slice = AlignedSlice::from_vectors(&vectors);
+    /// assert_eq!(u32x4::from_slice_aligned(slice.split_at(1).1), vectors[1]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub fn from_slice_aligned(slice: &AlignedSlice<T, LANES>) -> Self {
+        assert!(slice.len() >= LANES, "slice length must be at least the number of lanes");
+        // Safety: the slice is aligned to `Self` and holds at least `LANES` elements
+        unsafe { slice.as_ptr().cast::<Self>().read() }
+    }
+
     /// Performs lanewise conversion of a SIMD vector's elements to another SIMD-valid type.
     ///
     /// This follows the semantics of Rust's `as` conversion for casting
@@ -220,6 +268,57 @@ pub fn cast<U: SimdElement>(self) -> Simd<U, LANES> {
         unsafe { intrinsics::simd_as(self) }
     }
 
+    /// Reinterprets the bits of the vector as a vector with a different element type and number
+    /// of lanes.
+    ///
+    /// This is equivalent to a [`transmute`], so the lanes of the result depend on the target's
+    /// byte order.  Both vectors must be vectors of numbers with the same size, as required by the
+    /// [`SameBits`] bound, so reshaping to a vector of a different size fails to compile.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let bytes = Simd::<u8, 16>::splat(0x01);
+    /// let words: Simd<u32, 4> = bytes.try_reshape();
+    /// assert_eq!(words, Simd::splat(0x0101_0101));
+    /// assert_eq!(words.try_reshape::<u8, 16>(), bytes);
+    /// ```
+    ///
+    /// Reshaping 128 bits to 256 bits is an error:
+    /// ```compile_fail
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let words: Simd<u32, 8> = Simd::<u8, 16>::splat(0x01).try_reshape();
+    /// ```
+    ///
+    /// As is reshaping 256 bits to 128 bits:
+    /// ```compile_fail
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let words: Simd<u32, 4> = Simd::<u64, 4>::splat(0x01).try_reshape();
+    /// ```
+    ///
+    /// [`transmute`]: core::mem::transmute
+    #[must_use]
+    #[inline]
+    pub fn try_reshape<U, const M: usize>(self) -> Simd<U, M>
+    where
+        U: SimdElement,
+        LaneCount<M>: SupportedLaneCount,
+        Self: SameBits<Simd<U, M>>,
+    {
+        // Safety: `SameBits` is only implemented for vectors of numbers with the same size, and
+        // every bit pattern is a valid vector of numbers.
+        unsafe { core::mem::transmute_copy(&self) }
+    }
+
     /// Rounds toward zero and converts to the same-width integer type, assuming that
     /// the value is finite and fits in that type.
     ///
@@ -316,6 +415,46 @@ pub fn gather_select(
         unsafe { Self::gather_select_unchecked(slice, enable, idxs, or) }
     }
 
+    /// Reads from potentially discontiguous indices in `slice` to construct a SIMD vector,
+    /// like [`Simd::gather_select`], and also returns the number of enabled lanes that were
+    /// out-of-bounds.
+    ///
+    /// The count reuses the bounds check that masks-off the out-of-bounds lanes, so validating
+    /// the indices doesn't need a second comparison.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// let vec: Vec<i32> = vec![10, 11, 12, 13, 14, 15, 16, 17, 18];
+    /// let idxs = Simd::from_array([9, 3, 0, 12]);
+    /// let alt = Simd::from_array([-5, -4, -3, -2]);
+    /// let enable = Mask::from_array([true, true, true, false]); // Note the mask of the last lane.
+    ///
+    /// // Only the first lane is both enabled and out-of-bounds.
+    /// let (result, out_of_bounds) = Simd::gather_select_counted(&vec, enable, idxs, alt);
+    /// assert_eq!(result, Simd::from_array([-5, 13, 10, -2]));
+    /// assert_eq!(out_of_bounds, 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn gather_select_counted(
+        slice: &[T],
+        enable: Mask<isize, LANES>,
+        idxs: Simd<usize, LANES>,
+        or: Self,
+    ) -> (Self, usize) {
+        let in_bounds: Mask<isize, LANES> = idxs.simd_lt(Simd::splat(slice.len()));
+        // Set lanes are -1 as integers
+        let out_of_bounds = -(enable & !in_bounds).to_int().reduce_sum() as usize;
+        // Safety: We have masked-off out-of-bounds lanes.
+        let gathered =
+            unsafe { Self::gather_select_unchecked(slice, enable & in_bounds, idxs, or) };
+        (gathered, out_of_bounds)
+    }
+
     /// Reads from potentially discontiguous indices in `slice` to construct a SIMD vector.
     /// The mask `enable`s all `true` lanes and disables all `false` lanes.
     /// If an index is disabled, the lane is selected from the `or` vector.
@@ -351,11 +490,64 @@ pub unsafe fn gather_select_unchecked(
         idxs: Simd<usize, LANES>,
         or: Self,
     ) -> Self {
-        let base_ptr = crate::simd::ptr::SimdConstPtr::splat(slice.as_ptr());
+        let base_ptr = Simd::<*const T, LANES>::splat(slice.as_ptr());
         // Ferris forgive me, I have done pointer arithmetic here.
         let ptrs = base_ptr.wrapping_add(idxs);
         // Safety: The ptrs have been bounds-masked to prevent memory-unsafe reads insha'allah
-        unsafe { intrinsics::simd_gather(or, ptrs, enable.to_int()) }
+        unsafe { Self::gather_select_ptr(ptrs, enable, or) }
+    }
+
+    /// Reads from potentially discontiguous byte offsets from `base` to construct a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled, the lane is selected from the `or` vector.
+    ///
+    /// Since the offsets are in bytes rather than elements of `T`, this can read a single field
+    /// out of an array of structs without first converting indices into pointers.
+    ///
+    /// # Safety
+    ///
+    /// For every `enable`d lane, `base` offset by that lane's byte offset must be valid for
+    /// reads of a `T` and properly aligned for `T`, otherwise this is *[undefined behavior]*.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// #[repr(C)]
+    /// struct Particle {
+    ///     id: u32,
+    ///     mass: f32,
+    /// }
+    ///
+    /// let particles: Vec<Particle> = (0..8).map(|id| Particle { id, mass: id as f32 * 0.5 }).collect();
+    ///
+    /// // The byte offset of `mass` within particles 1, 3, 5 and 7
+    /// let idxs = Simd::from_array([1, 3, 5, 7]);
+    /// let offsets = idxs * Simd::splat(core::mem::size_of::<Particle>())
+    ///     + Simd::splat(core::mem::size_of::<u32>());
+    /// let enable = Mask::from_array([true, true, true, false]); // Note the mask of the last lane.
+    ///
+    /// let masses: Simd<f32, 4> = unsafe {
+    ///     Simd::gather_select_byte_offsets(particles.as_ptr(), enable, offsets, Simd::splat(-1.0))
+    /// };
+    /// assert_eq!(masses, Simd::from_array([0.5, 1.5, 2.5, -1.0]));
+    /// ```
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[must_use]
+    #[inline]
+    pub unsafe fn gather_select_byte_offsets<B>(
+        base: *const B,
+        enable: Mask<isize, LANES>,
+        byte_offsets: Simd<usize, LANES>,
+        or: Self,
+    ) -> Self {
+        let base_ptr = Simd::<*const u8, LANES>::splat(base.cast::<u8>());
+        // Safety: both vectors are vectors of thin pointers
+        let ptrs = unsafe { intrinsics::simd_cast_ptr(base_ptr.wrapping_add(byte_offsets)) };
+        // Safety: The caller is responsible for the validity of the enabled ptrs
+        unsafe { Self::gather_select_ptr(ptrs, enable, or) }
     }
 
     /// Writes the values in a SIMD vector to potentially discontiguous indices in `slice`.
@@ -458,14 +650,315 @@ pub unsafe fn scatter_select_unchecked(
         // 3. &mut [T] which will become our base ptr.
         unsafe {
             // Now Entering ☢️ *mut T Zone
-            let base_ptr = crate::simd::ptr::SimdMutPtr::splat(slice.as_mut_ptr());
+            let base_ptr = Simd::<*mut T, LANES>::splat(slice.as_mut_ptr());
             // Ferris forgive me, I have done pointer arithmetic here.
             let ptrs = base_ptr.wrapping_add(idxs);
             // The ptrs have been bounds-masked to prevent memory-unsafe writes insha'allah
-            intrinsics::simd_scatter(self, ptrs, enable.to_int())
+            self.scatter_select_ptr(ptrs, enable)
             // Cleared ☢️ *mut T Zone
         }
     }
+
+    /// Returns a mask of the lanes within the first `len` lanes.
+    #[inline]
+    fn lanes_below(len: usize) -> Mask<<T as SimdElement>::Mask, LANES> {
+        let lanes: Simd<usize, LANES> = Simd::from_array(core::array::from_fn(|i| i));
+        lanes.simd_lt(Simd::splat(len)).cast()
+    }
+
+    /// Reads contiguous elements from the start of `slice` to construct a SIMD vector.
+    /// If the slice is too short, the lanes past its end are selected from the `or` vector.
+    ///
+    /// Unlike [`Simd::from_slice`], this doesn't panic on short slices, so it can load the tail
+    /// of a loop without first copying it into a padded array.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let vec: Vec<i32> = vec![10, 11];
+    /// let alt = Simd::from_array([-5, -4, -3, -2]);
+    ///
+    /// let result = Simd::load_or(&vec, alt);
+    /// assert_eq!(result, Simd::from_array([10, 11, -3, -2]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn load_or(slice: &[T], or: Self) -> Self {
+        Self::load_select(slice, Mask::splat(true), or)
+    }
+
+    /// Reads contiguous elements from the start of `slice` to construct a SIMD vector.
+    /// If the slice is too short, the lanes past its end are set to the default value for the
+    /// type.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let vec: Vec<f32> = (0..10).map(|x| x as f32).collect();
+    ///
+    /// // Sum the slice, including its tail
+    /// let mut sum = Simd::<f32, 4>::splat(0.0);
+    /// for i in (0..vec.len()).step_by(4) {
+    ///     sum += Simd::load_or_default(&vec[i..]);
+    /// }
+    /// assert_eq!(sum, Simd::from_array([12.0, 15.0, 8.0, 10.0]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn load_or_default(slice: &[T]) -> Self
+    where
+        T: Default,
+    {
+        Self::load_or(slice, Self::splat(T::default()))
+    }
+
+    /// Reads contiguous elements from the start of `slice` to construct a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled or past the end of the slice, the lane is selected from the `or`
+    /// vector.
+    ///
+    /// This lowers to the target's masked load instructions where it has them, such as
+    /// `vpmaskmovd` on x86 with AVX2 for 32-bit and 64-bit elements, or masked `vmovdqu8` with
+    /// AVX-512BW for bytes.  Elsewhere, each enabled lane is loaded separately.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// let vec: Vec<i32> = vec![10, 11, 12];
+    /// let alt = Simd::from_array([-5, -4, -3, -2]);
+    /// let enable = Mask::from_array([true, false, true, true]); // Note the mask of the second lane.
+    ///
+    /// let result = Simd::load_select(&vec, enable, alt); // Note the lane past the end.
+    /// assert_eq!(result, Simd::from_array([10, -4, 12, -2]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn load_select(
+        slice: &[T],
+        enable: Mask<<T as SimdElement>::Mask, LANES>,
+        or: Self,
+    ) -> Self {
+        let enable = enable & Self::lanes_below(slice.len());
+        // Safety: We have masked-off out-of-bounds lanes.
+        unsafe { Self::load_select_unchecked(slice, enable, or) }
+    }
+
+    /// Reads contiguous elements from the start of `slice` to construct a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled, the lane is selected from the `or` vector.
+    ///
+    /// # Safety
+    ///
+    /// Enabling a lane past the end of `slice` is *[undefined behavior]*, even if the resulting
+    /// value is not used.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// let vec: Vec<i32> = vec![10, 11, 12];
+    /// let alt = Simd::from_array([-5, -4, -3, -2]);
+    /// let enable = Mask::from_array([true, false, true, false]); // Note the final mask lane.
+    ///
+    /// // The lane past the end is masked, so it's safe to load.
+    /// let result = unsafe { Simd::load_select_unchecked(&vec, enable, alt) };
+    /// assert_eq!(result, Simd::from_array([10, -4, 12, -2]));
+    /// ```
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[must_use]
+    #[inline]
+    pub unsafe fn load_select_unchecked(
+        slice: &[T],
+        enable: Mask<<T as SimdElement>::Mask, LANES>,
+        or: Self,
+    ) -> Self {
+        // Safety: The caller is responsible for masking-off lanes past the end of the slice
+        unsafe { intrinsics::simd_masked_load(enable.to_int(), slice.as_ptr(), or) }
+    }
+
+    /// Writes the values in a SIMD vector to contiguous elements at the start of `slice`.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled or past the end of the slice, the lane is not written.
+    ///
+    /// This lowers to the target's masked store instructions where it has them, such as
+    /// `vpmaskmovd` on x86 with AVX2 for 32-bit and 64-bit elements, or masked `vmovdqu8` with
+    /// AVX-512BW for bytes.  Elsewhere, each enabled lane is stored separately.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// let mut vec: Vec<i32> = vec![10, 11, 12];
+    /// let vals = Simd::from_array([-27, 82, -41, 124]);
+    /// let enable = Mask::from_array([false, true, true, true]); // Note the mask of the first lane.
+    ///
+    /// vals.store_select(&mut vec, enable); // The lane past the end is omitted.
+    /// assert_eq!(vec, vec![10, 82, -41]);
+    /// ```
+    #[inline]
+    pub fn store_select(self, slice: &mut [T], enable: Mask<<T as SimdElement>::Mask, LANES>) {
+        let enable = enable & Self::lanes_below(slice.len());
+        // Safety: We have masked-off out-of-bounds lanes.
+        unsafe { self.store_select_unchecked(slice, enable) }
+    }
+
+    /// Writes the values in a SIMD vector to contiguous elements at the start of `slice`.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    ///
+    /// # Safety
+    ///
+    /// Enabling a lane past the end of `slice` is *[undefined behavior]*, and may lead to memory
+    /// corruption.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// let mut vec: Vec<i32> = vec![10, 11, 12];
+    /// let vals = Simd::from_array([-27, 82, -41, 124]);
+    /// let enable = Mask::from_array([true, false, true, false]); // Note the final mask lane.
+    ///
+    /// // The lane past the end is masked, so it's safe to store.
+    /// unsafe { vals.store_select_unchecked(&mut vec, enable) };
+    /// assert_eq!(vec, vec![-27, 11, -41]);
+    /// ```
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[inline]
+    pub unsafe fn store_select_unchecked(
+        self,
+        slice: &mut [T],
+        enable: Mask<<T as SimdElement>::Mask, LANES>,
+    ) {
+        // Safety: The caller is responsible for masking-off lanes past the end of the slice
+        unsafe { intrinsics::simd_masked_store(enable.to_int(), slice.as_mut_ptr(), self) }
+    }
+
+    /// Reads elementwise from pointers into a SIMD vector.
+    ///
+    /// # Safety
+    ///
+    /// Each read must satisfy the same conditions as [`core::ptr::read`].
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdConstPtr};
+    /// let values = [6, 2, 4, 9];
+    /// let offsets = Simd::from_array([1, 0, 0, 3]);
+    /// let source = Simd::splat(values.as_ptr()).wrapping_add(offsets);
+    /// let gathered = unsafe { Simd::gather_ptr(source) };
+    /// assert_eq!(gathered, Simd::from_array([2, 6, 6, 9]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn gather_ptr(source: Simd<*const T, LANES>) -> Self
+    where
+        T: Default,
+    {
+        // TODO: add an intrinsic that doesn't use a passthru vector, and remove the T: Default bound
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { Self::gather_select_ptr(source, Mask::splat(true), Self::default()) }
+    }
+
+    /// Conditionally read elementwise from pointers into a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled, the lane is selected from the `or` vector.
+    ///
+    /// # Safety
+    ///
+    /// Enabled lanes must satisfy the same conditions as [`core::ptr::read`].
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdConstPtr};
+    /// let values = [6, 2, 4, 9];
+    /// let enable = Mask::from_array([true, true, false, true]);
+    /// let offsets = Simd::from_array([1, 0, 0, 3]);
+    /// let source = Simd::splat(values.as_ptr()).wrapping_add(offsets);
+    /// let gathered = unsafe { Simd::gather_select_ptr(source, enable, Simd::splat(0)) };
+    /// assert_eq!(gathered, Simd::from_array([2, 6, 0, 9]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn gather_select_ptr(
+        source: Simd<*const T, LANES>,
+        enable: Mask<isize, LANES>,
+        or: Self,
+    ) -> Self {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { intrinsics::simd_gather(or, source, enable.to_int()) }
+    }
+
+    /// Writes pointers elementwise into a SIMD vector.
+    ///
+    /// # Safety
+    ///
+    /// Each write must satisfy the same conditions as [`core::ptr::write`].
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdMutPtr};
+    /// let mut values = [0; 4];
+    /// let offset = Simd::from_array([3, 2, 1, 0]);
+    /// let ptrs = Simd::splat(values.as_mut_ptr()).wrapping_add(offset);
+    /// unsafe { Simd::from_array([6, 3, 5, 7]).scatter_ptr(ptrs); }
+    /// assert_eq!(values, [7, 5, 3, 6]);
+    /// ```
+    #[inline]
+    pub unsafe fn scatter_ptr(self, dest: Simd<*mut T, LANES>) {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { self.scatter_select_ptr(dest, Mask::splat(true)) }
+    }
+
+    /// Conditionally write pointers elementwise into a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled, the write to that lane is skipped.
+    ///
+    /// # Safety
+    ///
+    /// Enabled lanes must satisfy the same conditions as [`core::ptr::write`].
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdMutPtr};
+    /// let mut values = [0; 4];
+    /// let offset = Simd::from_array([3, 2, 1, 0]);
+    /// let ptrs = Simd::splat(values.as_mut_ptr()).wrapping_add(offset);
+    /// let enable = Mask::from_array([true, true, false, false]);
+    /// unsafe { Simd::from_array([6, 3, 5, 7]).scatter_select_ptr(ptrs, enable); }
+    /// assert_eq!(values, [0, 0, 3, 6]);
+    /// ```
+    #[inline]
+    pub unsafe fn scatter_select_ptr(self, dest: Simd<*mut T, LANES>, enable: Mask<isize, LANES>) {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { intrinsics::simd_scatter(self, dest, enable.to_int()) }
+    }
 }
 
 impl<T, const LANES: usize> Copy for Simd<T, LANES>
@@ -490,7 +983,7 @@ impl<T, const LANES: usize> Default for Simd<T, LANES>
     LaneCount<LANES>: SupportedLaneCount,
     T: SimdElement + Default,
 {
-    #[inline]
+    #[inline(always)]
     fn default() -> Self {
         Self::splat(T::default())
     }
@@ -503,27 +996,110 @@ impl<T, const LANES: usize> PartialEq for Simd<T, LANES>
 {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        // Safety: All SIMD vectors are SimdPartialEq, and the comparison produces a valid mask.
-        let mask = unsafe {
-            let tfvec: Simd<<T as SimdElement>::Mask, LANES> = intrinsics::simd_eq(*self, *other);
-            Mask::from_int_unchecked(tfvec)
-        };
-
-        // Two vectors are equal if all lanes tested true for vertical equality.
-        mask.all()
+        // Two vectors are equal if no lane tested true for vertical non-equality.
+        !T::lanes_ne(*self, *other).any()
     }
 
     #[allow(clippy::partialeq_ne_impl)]
     #[inline]
     fn ne(&self, other: &Self) -> bool {
-        // Safety: All SIMD vectors are SimdPartialEq, and the comparison produces a valid mask.
-        let mask = unsafe {
-            let tfvec: Simd<<T as SimdElement>::Mask, LANES> = intrinsics::simd_ne(*self, *other);
-            Mask::from_int_unchecked(tfvec)
-        };
-
         // Two vectors are non-equal if any lane tested true for vertical non-equality.
-        mask.any()
+        T::lanes_ne(*self, *other).any()
+    }
+}
+
+impl<T, const LANES: usize> Simd<T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+    T: SimdElement,
+{
+    /// Tests if each lane is equal to the corresponding lane in `other`.
+    ///
+    /// This is the same comparison as [`SimdPartialEq::simd_eq`], but only requires the lanes to
+    /// be vector elements, as the [`PartialEq`] implementation does.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd};
+    /// let a = Simd::from_array([0.0, 1.0, f32::NAN, -0.0]);
+    /// let b = Simd::from_array([0.0, 2.0, f32::NAN, 0.0]);
+    /// assert_eq!(a.lanes_eq(b), Mask::from_array([true, false, false, true]));
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn lanes_eq(self, other: Self) -> Mask<T::Mask, LANES> {
+        !T::lanes_ne(self, other)
+    }
+
+    /// Tests if each lane is not equal to the corresponding lane in `other`.
+    ///
+    /// This is the same comparison as [`SimdPartialEq::simd_ne`], but only requires the lanes to
+    /// be vector elements, as the [`PartialEq`] implementation does.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd};
+    /// let a = Simd::from_array([0.0, 1.0, f32::NAN, -0.0]);
+    /// let b = Simd::from_array([0.0, 2.0, f32::NAN, 0.0]);
+    /// assert_eq!(a.lanes_ne(b), Mask::from_array([false, true, true, false]));
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn lanes_ne(self, other: Self) -> Mask<T::Mask, LANES> {
+        T::lanes_ne(self, other)
+    }
+
+    /// Tests if each lane is less than the corresponding lane in `other`.
+    ///
+    /// This is the same comparison as [`SimdPartialOrd::simd_lt`], but only requires the lanes to
+    /// be vector elements, as the [`PartialOrd`] implementation does.  Pointers compare their
+    /// addresses.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd};
+    /// let a = Simd::from_array([0.0, 1.0, f32::NAN, -0.0]);
+    /// let b = Simd::from_array([0.0, 2.0, 3.0, 0.0]);
+    /// assert_eq!(a.lanes_lt(b), Mask::from_array([false, true, false, false]));
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn lanes_lt(self, other: Self) -> Mask<T::Mask, LANES> {
+        T::lanes_lt(self, other)
+    }
+
+    /// Returns the index of the first lane that isn't equal in both vectors, or `None` if the
+    /// vectors are equal.
+    ///
+    /// Vectors compare lexicographically, so only this lane decides their order.  Lanes that are
+    /// NaN are never equal.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let a = Simd::from_array([1, 2, 3, 4]);
+    /// assert_eq!(a.first_ne(Simd::from_array([1, 2, 0, 0])), Some(2));
+    /// assert_eq!(a.first_ne(a), None);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    pub fn first_ne(self, other: Self) -> Option<usize> {
+        let ne = self.lanes_ne(other).cast::<isize>();
+        let lanes = Simd::from_array(core::array::from_fn(|i| i));
+        let first = ne.select(lanes, Simd::splat(LANES)).reduce_min();
+        (first < LANES).then_some(first)
     }
 }
 
@@ -534,8 +1110,14 @@ impl<T, const LANES: usize> PartialOrd for Simd<T, LANES>
 {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        // TODO use SIMD equality
-        self.to_array().partial_cmp(other.as_ref())
+        // Only the first unequal lane decides the order, and it's unordered if neither lane is
+        // less than the other.
+        match self.first_ne(*other) {
+            Some(lane) if self.lanes_lt(*other).test(lane) => Some(core::cmp::Ordering::Less),
+            Some(lane) if other.lanes_lt(*self).test(lane) => Some(core::cmp::Ordering::Greater),
+            Some(_) => None,
+            None => Some(core::cmp::Ordering::Equal),
+        }
     }
 }
 
@@ -553,8 +1135,12 @@ impl<T, const LANES: usize> Ord for Simd<T, LANES>
 {
     #[inline]
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        // TODO use SIMD equality
-        self.to_array().cmp(other.as_ref())
+        // Only the first unequal lane decides the order.
+        match self.first_ne(*other) {
+            Some(lane) if self.lanes_lt(*other).test(lane) => core::cmp::Ordering::Less,
+            Some(_) => core::cmp::Ordering::Greater,
+            None => core::cmp::Ordering::Equal,
+        }
     }
 }
 
@@ -640,7 +1226,47 @@ fn from(vector: Simd<T, LANES>) -> Self {
 }
 
 mod sealed {
-    pub trait Sealed {}
+    use super::{intrinsics, LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+
+    pub trait Sealed {
+        /// Returns a mask of the lanes of `a` that aren't equal to the corresponding lane of `b`.
+        ///
+        /// The comparison intrinsics only support numbers, so pointers override this.
+        #[inline]
+        fn lanes_ne<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Mask<<Self as SimdElement>::Mask, LANES>
+        where
+            Self: SimdElement,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            // Safety: `a` and `b` are vectors of numbers, and the comparison produces a valid mask.
+            unsafe {
+                let tfvec: Simd<<Self as SimdElement>::Mask, LANES> = intrinsics::simd_ne(a, b);
+                Mask::from_int_unchecked(tfvec)
+            }
+        }
+
+        /// Returns a mask of the lanes of `a` that are less than the corresponding lane of `b`.
+        ///
+        /// As with `lanes_ne`, pointers override this.
+        #[inline]
+        fn lanes_lt<const LANES: usize>(
+            a: Simd<Self, LANES>,
+            b: Simd<Self, LANES>,
+        ) -> Mask<<Self as SimdElement>::Mask, LANES>
+        where
+            Self: SimdElement,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            // Safety: `a` and `b` are vectors of numbers, and the comparison produces a valid mask.
+            unsafe {
+                let tfvec: Simd<<Self as SimdElement>::Mask, LANES> = intrinsics::simd_lt(a, b);
+                Mask::from_int_unchecked(tfvec)
+            }
+        }
+    }
 }
 use sealed::Sealed;
 
@@ -740,3 +1366,63 @@ impl Sealed for f64 {}
 unsafe impl SimdElement for f64 {
     type Mask = i64;
 }
+
+impl<T> Sealed for *const T {
+    #[inline]
+    fn lanes_ne<const LANES: usize>(
+        a: Simd<Self, LANES>,
+        b: Simd<Self, LANES>,
+    ) -> Mask<<Self as SimdElement>::Mask, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        a.simd_ne(b)
+    }
+
+    #[inline]
+    fn lanes_lt<const LANES: usize>(
+        a: Simd<Self, LANES>,
+        b: Simd<Self, LANES>,
+    ) -> Mask<<Self as SimdElement>::Mask, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        a.addr().simd_lt(b.addr())
+    }
+}
+
+// Safety: *const T is a valid SIMD element type, and is supported by this API
+// Ideally, this would be `impl<T: ?Sized>`, but pointers to unsized types aren't thin pointers
+unsafe impl<T> SimdElement for *const T {
+    type Mask = isize;
+}
+
+impl<T> Sealed for *mut T {
+    #[inline]
+    fn lanes_ne<const LANES: usize>(
+        a: Simd<Self, LANES>,
+        b: Simd<Self, LANES>,
+    ) -> Mask<<Self as SimdElement>::Mask, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        a.simd_ne(b)
+    }
+
+    #[inline]
+    fn lanes_lt<const LANES: usize>(
+        a: Simd<Self, LANES>,
+        b: Simd<Self, LANES>,
+    ) -> Mask<<Self as SimdElement>::Mask, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        a.addr().simd_lt(b.addr())
+    }
+}
+
+// Safety: *mut T is a valid SIMD element type, and is supported by this API
+// Ideally, this would be `impl<T: ?Sized>`, but pointers to unsized types aren't thin pointers
+unsafe impl<T> SimdElement for *mut T {
+    type Mask = isize;
+}
@@ -0,0 +1,84 @@
+use crate::simd::{mem::AlignedSlice, LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+
+/// Calls `f` with each window of `LANES` corresponding elements of `a` and `b`, in order.
+///
+/// This is the loop around a kernel combining two slices element by element, such as adding two
+/// arrays or comparing two columns.  The elements before the first aligned vector of `a`, and
+/// the elements after its last whole vector, are passed in partial windows, so every window in
+/// between is read with aligned loads from `a`.
+///
+/// Along with the windows of `a` and `b`, `f` receives a mask of the lanes holding elements of
+/// the slices.  The lanes of a partial window past the end of its elements are set to the
+/// default value for the type, and disabled in the mask.  Only the leading lanes of a window
+/// are ever enabled, so the enabled lanes of each window start where the previous window's
+/// ended.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{u32x8, Mask, SimdPartialEq, ToBitMask};
+/// let a: Vec<u32> = (0..100).collect();
+/// let b: Vec<u32> = (0..100).map(|x| x % 10 * 11).collect();
+///
+/// let mut sums = Vec::new();
+/// let mut equal = 0;
+/// simd::zip_slices(&a[1..], &b[1..], |va: u32x8, vb: u32x8, valid: Mask<i32, 8>| {
+///     let len = valid.to_bitmask().count_ones() as usize;
+///     sums.extend_from_slice(&(va + vb)[..len]);
+///     equal += (va.simd_eq(vb) & valid).to_bitmask().count_ones();
+/// });
+///
+/// let expected: Vec<u32> = a[1..].iter().zip(&b[1..]).map(|(a, b)| a + b).collect();
+/// assert_eq!(sums, expected);
+/// assert_eq!(equal, 9);
+/// ```
+#[inline]
+#[track_caller]
+pub fn zip_slices<T, U, F, const LANES: usize>(a: &[T], b: &[U], mut f: F)
+where
+    T: SimdElement + Default,
+    U: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+    F: FnMut(Simd<T, LANES>, Simd<U, LANES>, Mask<T::Mask, LANES>),
+{
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let partial = |a: &[T], b: &[U], f: &mut F| {
+        if !a.is_empty() {
+            let valid = Mask::from_array(core::array::from_fn(|i| i < a.len()));
+            f(Simd::load_or_default(a), Simd::load_or_default(b), valid);
+        }
+    };
+
+    // `align_offset` may fail to find the offset, in which case every load is unaligned
+    let head = a
+        .as_ptr()
+        .align_offset(core::mem::align_of::<Simd<T, LANES>>());
+    let head = if head < LANES { head.min(a.len()) } else { 0 };
+    let (a_head, a) = a.split_at(head);
+    let (b_head, b) = b.split_at(head);
+    partial(a_head, b_head, &mut f);
+
+    let whole = a.len() / LANES * LANES;
+    let (a, a_tail) = a.split_at(whole);
+    let (b, b_tail) = b.split_at(whole);
+    let b_chunks = b.chunks_exact(LANES).map(Simd::from_slice);
+    match AlignedSlice::<T, LANES>::new(a) {
+        Some(a) => {
+            for (&va, vb) in a.as_vectors().0.iter().zip(b_chunks) {
+                f(va, vb, Mask::splat(true));
+            }
+        }
+        None => {
+            for (va, vb) in a.chunks_exact(LANES).map(Simd::from_slice).zip(b_chunks) {
+                f(va, vb, Mask::splat(true));
+            }
+        }
+    }
+    partial(a_tail, b_tail, &mut f);
+}
@@ -0,0 +1,40 @@
+#![feature(portable_simd)]
+use core_simd::Simd;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! test_accumulate {
+    { $($name:ident: $wide:ty => $narrow:ty;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            test_helpers::test_binary_elementwise(
+                &|x: Simd<$wide, 8>, acc: Simd<$narrow, 8>| {
+                    let mut acc = acc;
+                    x.accumulate_saturating(&mut acc);
+                    acc
+                },
+                &|x: $wide, acc: $narrow| {
+                    let sum = i128::from(x) + i128::from(acc);
+                    sum.clamp(<$narrow>::MIN.into(), <$narrow>::MAX.into()) as $narrow
+                },
+                &|_, _| true,
+            );
+        }
+        )*
+    }
+}
+
+test_accumulate! {
+    i16_to_i8: i16 => i8;
+    i32_to_i16: i32 => i16;
+    i64_to_i32: i64 => i32;
+    u16_to_u8: u16 => u8;
+    u32_to_u16: u32 => u16;
+    u64_to_u32: u64 => u32;
+}
@@ -0,0 +1,58 @@
+#![feature(portable_simd)]
+use core_simd::{automata::Dfa, u8x16};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// A pseudo-random automaton with all 16 states.
+fn transition(state: u8, byte: u8) -> u8 {
+    let mut random = Xorshift::with_seed((state as u64) << 8 | byte as u64);
+    (random.next_u64() % 16) as u8
+}
+
+fn input(len: usize) -> Vec<u8> {
+    Xorshift::new().take(len).map(|x| x as u8).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn run() {
+    let dfa = Dfa::new(transition);
+    let input = input(1000);
+    let states = dfa.run(Dfa::all_states(), &input);
+    for start in 0..16 {
+        let end = input
+            .iter()
+            .fold(start, |state, byte| transition(state, *byte));
+        assert_eq!(states[start as usize], end);
+    }
+
+    // Unreachable states move to state 0
+    let states = u8x16::from_array([0, 16, 200, 255, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    let next = dfa.step(states, b'x');
+    assert_eq!(next[1..4], [0; 3]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn compose() {
+    let dfa = Dfa::new(transition);
+    let input = input(1000);
+    let whole = dfa.run(Dfa::all_states(), &input);
+    for split in [0, 1, 15, 500, 999, 1000] {
+        let (first, second) = input.split_at(split);
+        let first = dfa.run(Dfa::all_states(), first);
+        let second = dfa.run(Dfa::all_states(), second);
+        assert_eq!(Dfa::compose(first, second), whole);
+    }
+}
+
+#[test]
+#[should_panic]
+fn too_many_states() {
+    let _ = Dfa::new(|state, _| state + 1);
+}
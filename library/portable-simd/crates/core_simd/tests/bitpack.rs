@@ -0,0 +1,64 @@
+#![feature(portable_simd)]
+use core_simd::bitpack;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn values(len: usize) -> Vec<u32> {
+    Xorshift::new().take(len).map(|x| x as u32).collect()
+}
+
+/// Packs `values` one bit at a time.
+fn pack_bits(values: &[u32], width: u32) -> Vec<u8> {
+    let mut output = vec![0; bitpack::packed_len(values.len(), width)];
+    for (i, value) in values.iter().enumerate() {
+        for bit in 0..width as usize {
+            let position = i * width as usize + bit;
+            output[position / 8] |= ((value >> bit & 1) as u8) << (position % 8);
+        }
+    }
+    output
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roundtrip() {
+    let values = values(100);
+    for width in 1..=32 {
+        let mask = (u64::MAX >> (64 - width)) as u32;
+        for len in [0, 1, 7, 8, 9, 31, 100] {
+            let values = &values[..len];
+            let mut packed = vec![0xff; bitpack::packed_len(len, width)];
+            bitpack::pack(values, width, &mut packed);
+            assert_eq!(packed, pack_bits(values, width), "width {width}");
+
+            // Trailing bytes are ignored
+            packed.push(0xff);
+            let mut unpacked = vec![0; len];
+            bitpack::unpack(&packed, width, &mut unpacked);
+            assert!(unpacked.iter().zip(values).all(|(u, v)| *u == v & mask));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn pack_wide() {
+    bitpack::pack(&[1], 33, &mut [0; 5]);
+}
+
+#[test]
+#[should_panic]
+fn pack_wrong_length() {
+    bitpack::pack(&[1; 8], 4, &mut [0; 5]);
+}
+
+#[test]
+#[should_panic]
+fn unpack_short() {
+    bitpack::unpack(&[0; 3], 4, &mut [0; 8]);
+}
@@ -0,0 +1,83 @@
+#![feature(portable_simd)]
+use core_simd::{bitset::SimdBitSet, u64x8};
+use std::collections::BTreeSet;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(n: usize) -> Vec<usize> {
+    Xorshift::new()
+        .take(n)
+        .map(|x| (x >> 55) as usize)
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn matches_btree_set() {
+    let values = random(600);
+    let (a_values, b_values) = values.split_at(200);
+    let mut a = SimdBitSet::<8>::new();
+    let mut expected_a = BTreeSet::new();
+    for value in a_values {
+        assert_eq!(a.insert(*value), expected_a.insert(*value));
+    }
+    let b: SimdBitSet<8> = b_values.iter().copied().collect();
+    let expected_b: BTreeSet<usize> = b_values.iter().copied().collect();
+
+    assert_eq!(a.len(), expected_a.len());
+    assert!(a.iter().eq(expected_a.iter().copied()));
+    assert_eq!(
+        a.intersection_len(&b),
+        expected_a.intersection(&expected_b).count()
+    );
+    assert!((a & b)
+        .iter()
+        .eq(expected_a.intersection(&expected_b).copied()));
+    assert!((a | b).iter().eq(expected_a.union(&expected_b).copied()));
+    assert!((a ^ b)
+        .iter()
+        .eq(expected_a.symmetric_difference(&expected_b).copied()));
+    for value in 0..SimdBitSet::<8>::CAPACITY {
+        assert_eq!(a.contains(value), expected_a.contains(&value));
+    }
+
+    let mut c = a;
+    c &= b;
+    assert_eq!(c, a & b);
+    c |= a;
+    assert_eq!(c, a);
+    c ^= a;
+    assert!(c.is_empty());
+
+    for value in a_values {
+        assert_eq!(a.remove(*value), expected_a.remove(value));
+    }
+    assert_eq!(a, SimdBitSet::default());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn words() {
+    let mut words = u64x8::splat(0);
+    words[0] = 1;
+    words[7] = 1 << 63;
+    let set = SimdBitSet::from_words(words);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0, 511]);
+    assert_eq!((&set).into_iter().count(), 2);
+
+    let full = SimdBitSet::from_words(u64x8::splat(u64::MAX));
+    assert_eq!(full.len(), 512);
+    assert!(full.iter().eq(0..512));
+    assert_eq!(SimdBitSet::<1>::new().iter().next(), None);
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_range() {
+    SimdBitSet::<2>::new().insert(128);
+}
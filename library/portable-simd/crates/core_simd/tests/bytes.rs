@@ -0,0 +1,85 @@
+#![feature(portable_simd)]
+use core_simd::bytes;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn to_ascii_lowercase() {
+    let all: Vec<u8> = (0..=255).collect();
+    for chunk in all.chunks(64) {
+        let lower = bytes::to_ascii_lowercase(core_simd::u8x64::from_slice(chunk));
+        let expected: Vec<u8> = chunk.iter().map(u8::to_ascii_lowercase).collect();
+        assert_eq!(lower.as_array()[..], expected[..]);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn eq_ignore_ascii_case() {
+    let upper: Vec<u8> = (0..100).map(|i| b'A' + i % 26).collect();
+    let lower: Vec<u8> = upper.iter().map(u8::to_ascii_lowercase).collect();
+    for len in 0..upper.len() {
+        assert!(bytes::eq_ignore_ascii_case(&upper[..len], &lower[..len]));
+        assert!(!bytes::eq_ignore_ascii_case(
+            &upper[..len],
+            &lower[..len + 1]
+        ));
+    }
+    for i in 0..upper.len() {
+        let mut different = lower.clone();
+        different[i] = b'@';
+        assert!(!bytes::eq_ignore_ascii_case(&upper, &different));
+    }
+    // Only ASCII letters are folded
+    assert!(!bytes::eq_ignore_ascii_case(b"[\\]^_`", b"{|}~\x7f@"));
+    assert!(!bytes::eq_ignore_ascii_case(&[0xc0; 40], &[0xe0; 40]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn starts_with_ignore_case() {
+    let header = b"Sec-WebSocket-Extensions: permessage-deflate; client_max_window_bits";
+    assert!(bytes::starts_with_ignore_case(header, b""));
+    assert!(bytes::starts_with_ignore_case(
+        header,
+        b"sec-websocket-extensions:"
+    ));
+    assert!(bytes::starts_with_ignore_case(
+        header,
+        &header.to_ascii_uppercase()
+    ));
+    assert!(!bytes::starts_with_ignore_case(
+        header,
+        b"sec-websocket-key:"
+    ));
+    assert!(!bytes::starts_with_ignore_case(b"Sec", b"sec-websocket"));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn match_any() {
+    let text = b"GET /a HTTP/1.1\r\nHost: example.com\r\nContent-Length: 12\r\nAccept: */*\r\n\r\n";
+    let needles: [&[u8]; 5] = [b"\r\n", b"HTTP", b"Content-", b": ", b"e"];
+    for start in 0..text.len() {
+        for end in start..=text.len() {
+            let haystack = &text[start..end];
+            let mut expected = 0;
+            for i in 0..haystack.len().min(64) {
+                if needles.iter().any(|n| haystack[i..].starts_with(n)) {
+                    expected |= 1 << i;
+                }
+            }
+            assert_eq!(bytes::match_any(haystack, &needles), expected);
+        }
+    }
+
+    // Empty needles match at every position, including the end
+    assert_eq!(bytes::match_any(b"abc", &[b""]), 0b1111);
+    assert_eq!(bytes::match_any(&[0; 100], &[b""]), u64::MAX);
+    assert_eq!(bytes::match_any(b"abc", &[]), 0);
+}
@@ -24,9 +24,9 @@ fn cast_as<const N: usize>() {
 
 // The hypothesis is that widening conversions aren't terribly interesting.
 cast_types!(f32, f64, i8, u8, usize, isize);
-cast_types!(f64, f32, i8, u8, usize, isize);
+cast_types!(f64, f32, i8, u8, i64, usize, isize);
 cast_types!(i8, u8, f32);
-cast_types!(u8, i8, f32);
+cast_types!(u8, i8, u32, f32);
 cast_types!(i16, u16, i8, u8, f32);
 cast_types!(u16, i16, i8, u8, f32);
 cast_types!(i32, u32, i8, u8, f32, f64);
@@ -0,0 +1,95 @@
+#![feature(portable_simd)]
+use core_simd::checksum::{Adler32, Fletcher32};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn adler32_scalar(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+fn fletcher32_scalar(data: &[u16]) -> u32 {
+    let (mut a, mut b) = (0u32, 0u32);
+    for word in data {
+        a = (a + *word as u32) % 65535;
+        b = (b + a) % 65535;
+    }
+    (b << 16) | a
+}
+
+/// Input long enough to span several blocks, with the largest values to test for overflow.
+fn input(len: usize) -> Vec<u16> {
+    let mut random = Xorshift::new();
+    (0..len)
+        .map(|i| {
+            if i < len / 2 {
+                return u16::MAX;
+            }
+            random.next_u64() as u16
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn adler32() {
+    let data: Vec<u8> = input(40000).iter().map(|word| *word as u8).collect();
+    for len in (0..100).chain([5535, 5536, 5537, 11072, 40000]) {
+        let mut adler = Adler32::new();
+        adler.update(&data[..len]);
+        assert_eq!(adler.finish(), adler32_scalar(&data[..len]), "len {len}");
+    }
+    assert_eq!(Adler32::default().finish(), 1);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn adler32_streaming() {
+    let data: Vec<u8> = input(20000).iter().map(|word| *word as u8).collect();
+    let expected = adler32_scalar(&data);
+    for split in [1, 31, 32, 33, 5536, 7000, 19999] {
+        let mut adler = Adler32::new();
+        for chunk in data.chunks(split) {
+            adler.update(chunk);
+        }
+        assert_eq!(adler.finish(), expected, "split {split}");
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fletcher32() {
+    let data = input(40000);
+    for len in (0..100).chain([4095, 4096, 4097, 8192, 40000]) {
+        let mut fletcher = Fletcher32::new();
+        fletcher.update(&data[..len]);
+        assert_eq!(
+            fletcher.finish(),
+            fletcher32_scalar(&data[..len]),
+            "len {len}"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fletcher32_streaming() {
+    let data = input(20000);
+    let expected = fletcher32_scalar(&data);
+    for split in [1, 15, 16, 17, 4096, 7000, 19999] {
+        let mut fletcher = Fletcher32::new();
+        for chunk in data.chunks(split) {
+            fletcher.update(chunk);
+        }
+        assert_eq!(fletcher.finish(), expected, "split {split}");
+    }
+}
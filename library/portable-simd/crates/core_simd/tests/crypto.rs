@@ -0,0 +1,88 @@
+#![feature(portable_simd)]
+use core_simd::crypto;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random_state() -> [u32; 16] {
+    let mut random = Xorshift::new();
+    core::array::from_fn(|_| (random.next_u64() >> 32) as u32)
+}
+
+/// The ChaCha20 block function, a word at a time.
+fn reference_block(state: &[u32; 16]) -> [u32; 16] {
+    let mut x = *state;
+    let mut quarter_round = |a: usize, b: usize, c: usize, d: usize| {
+        x[a] = x[a].wrapping_add(x[b]);
+        x[d] = (x[d] ^ x[a]).rotate_left(16);
+        x[c] = x[c].wrapping_add(x[d]);
+        x[b] = (x[b] ^ x[c]).rotate_left(12);
+        x[a] = x[a].wrapping_add(x[b]);
+        x[d] = (x[d] ^ x[a]).rotate_left(8);
+        x[c] = x[c].wrapping_add(x[d]);
+        x[b] = (x[b] ^ x[c]).rotate_left(7);
+    };
+    for _ in 0..10 {
+        quarter_round(0, 4, 8, 12);
+        quarter_round(1, 5, 9, 13);
+        quarter_round(2, 6, 10, 14);
+        quarter_round(3, 7, 11, 15);
+        quarter_round(0, 5, 10, 15);
+        quarter_round(1, 6, 11, 12);
+        quarter_round(2, 7, 8, 13);
+        quarter_round(3, 4, 9, 14);
+    }
+    core::array::from_fn(|i| x[i].wrapping_add(state[i]))
+}
+
+fn check_blocks<const LANES: usize>(state: &[u32; 16])
+where
+    core_simd::LaneCount<LANES>: core_simd::SupportedLaneCount,
+{
+    let blocks = crypto::chacha_block::<LANES>(state);
+    let mut state = *state;
+    for lane in 0..LANES {
+        assert_eq!(blocks.map(|word| word[lane]), reference_block(&state));
+        state[12] = state[12].wrapping_add(1);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_block() {
+    let mut state = random_state();
+    check_blocks::<1>(&state);
+    check_blocks::<4>(&state);
+    check_blocks::<16>(&state);
+
+    // The counter wraps around
+    state[12] = u32::MAX - 2;
+    check_blocks::<8>(&state);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chacha_keystream() {
+    let state = random_state();
+    let mut expected = Vec::new();
+    let mut block_state = state;
+    for _ in 0..20 {
+        expected.extend(
+            reference_block(&block_state)
+                .iter()
+                .flat_map(|word| word.to_le_bytes()),
+        );
+        block_state[12] += 1;
+    }
+
+    for len in [0, 1, 63, 64, 65, 511, 512, 513, 1000, 1280] {
+        let mut keystream = vec![0; len];
+        let next = crypto::chacha_keystream(&state, &mut keystream);
+        assert_eq!(keystream, expected[..len]);
+        assert_eq!(next[12], state[12] + keystream.chunks(64).count() as u32);
+    }
+}
@@ -0,0 +1,52 @@
+#![feature(portable_simd)]
+use core_simd::{ct, Mask, Simd};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ct_eq() {
+    let a: Vec<u8> = (0..100).map(|i| (i * 37) as u8).collect();
+    for len in 0..a.len() {
+        let a = &a[..len];
+        assert!(ct::ct_eq(a, a));
+        if len > 0 {
+            assert!(!ct::ct_eq(a, &a[..len - 1]));
+        }
+        // A single flipped bit anywhere, including the remainder
+        for i in 0..len {
+            let mut b = a.to_vec();
+            b[i] ^= 1 << (i % 8);
+            assert!(!ct::ct_eq(a, &b));
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ct_select() {
+    let a = Simd::from_array([1i16, 2, 3, 4, 5, 6, 7, 8]);
+    let b = -a;
+    for bits in 0..=u8::MAX {
+        let mask = Mask::from_array(core::array::from_fn(|lane| bits & 1 << lane != 0));
+        assert_eq!(ct::ct_select(mask, a, b), mask.select(a, b));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cond_swap() {
+    let a = Simd::from_array([0.5f64, -1.0, f64::INFINITY, 4.0]);
+    let b = Simd::from_array([-0.5f64, 2.0, 3.0, f64::MIN]);
+    for bits in 0..16u8 {
+        let mask = Mask::from_array(core::array::from_fn(|lane| bits & 1 << lane != 0));
+        let (mut x, mut y) = (a, b);
+        ct::cond_swap(mask, &mut x, &mut y);
+        assert_eq!(x, mask.select(b, a));
+        assert_eq!(y, mask.select(a, b));
+    }
+}
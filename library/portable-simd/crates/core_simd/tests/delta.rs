@@ -0,0 +1,64 @@
+#![feature(portable_simd)]
+use core_simd::delta;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn values(len: usize) -> Vec<u64> {
+    Xorshift::new().take(len).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn encode_decode() {
+    let values = values(100);
+    for len in [0, 1, 15, 16, 17, 100] {
+        let values = &values[..len];
+        let mut deltas = values.to_vec();
+        delta::encode(&mut deltas);
+        for i in 0..len {
+            let previous = if i == 0 { 0 } else { values[i - 1] };
+            assert_eq!(deltas[i], values[i].wrapping_sub(previous));
+        }
+        delta::decode(&mut deltas);
+        assert_eq!(deltas, values);
+
+        // Smaller integers wrap
+        let values: Vec<i8> = values.iter().map(|x| *x as i8).collect();
+        let mut deltas = values.clone();
+        delta::encode(&mut deltas);
+        if len > 1 {
+            assert_eq!(deltas[1], values[1].wrapping_sub(values[0]));
+        }
+        delta::decode(&mut deltas);
+        assert_eq!(deltas, values);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn delta_of_delta() {
+    let values = values(100);
+    for len in [0, 1, 2, 3, 16, 17, 33, 100] {
+        let values = &values[..len];
+        let mut encoded = values.to_vec();
+        delta::encode_delta_of_delta(&mut encoded);
+        for i in 2..len {
+            let delta = values[i].wrapping_sub(values[i - 1]);
+            let previous = values[i - 1].wrapping_sub(values[i - 2]);
+            assert_eq!(encoded[i], delta.wrapping_sub(previous));
+        }
+        delta::decode_delta_of_delta(&mut encoded);
+        assert_eq!(encoded, values);
+    }
+
+    // A steady rate encodes to zeros
+    let mut timestamps: Vec<u32> = (0..50).map(|i| 7 + 3 * i).collect();
+    delta::encode_delta_of_delta(&mut timestamps);
+    assert_eq!(timestamps[..2], [7, 3]);
+    assert!(timestamps[2..].iter().all(|x| *x == 0));
+}
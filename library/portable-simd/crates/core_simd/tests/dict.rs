@@ -0,0 +1,61 @@
+#![feature(portable_simd)]
+use core_simd::dict;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn codes(len: usize, dict_len: u32) -> Vec<u32> {
+    Xorshift::new()
+        .take(len)
+        .map(|x| (x % dict_len as u64) as u32)
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn decode() {
+    let dict: Vec<u64> = (0..37).map(|i| i * i + 1000).collect();
+    for len in [0, 1, 15, 16, 17, 100] {
+        let codes = codes(len, dict.len() as u32);
+        let mut out = vec![0; len];
+        dict::decode(&codes, &dict, &mut out);
+        assert!(codes.iter().zip(&out).all(|(c, o)| dict[*c as usize] == *o));
+
+        let bytes = b"abcdefghijklmnopqrstuvwxyz0123456789_";
+        let mut out = vec![0; len];
+        dict::decode(&codes, bytes, &mut out);
+        assert!(codes
+            .iter()
+            .zip(&out)
+            .all(|(c, o)| bytes[*c as usize] == *o));
+    }
+
+    // Nothing to decode from an empty dictionary
+    dict::decode::<f32>(&[], &[], &mut []);
+}
+
+#[test]
+#[should_panic(expected = "code 3 is out of bounds")]
+fn decode_out_of_bounds() {
+    let mut codes = vec![0; 40];
+    codes[20] = 3;
+    dict::decode(&codes, &[1, 2, 3], &mut [0; 40]);
+}
+
+#[test]
+#[should_panic(expected = "code 5 is out of bounds")]
+fn decode_out_of_bounds_tail() {
+    let mut codes = vec![1; 20];
+    codes[18] = 5;
+    dict::decode(&codes, &[1, 2, 3], &mut [0; 20]);
+}
+
+#[test]
+#[should_panic]
+fn decode_different_lengths() {
+    dict::decode(&[0; 4], &[1, 2, 3], &mut [0; 3]);
+}
@@ -0,0 +1,174 @@
+#![feature(portable_simd)]
+use core_simd::{distance, Simd};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn hamming() {
+    let a: Vec<u8> = (0..5000u32).map(|i| (i * 7 + i / 3) as u8).collect();
+    let b: Vec<u8> = (0..5000u32).map(|i| (i * 13 + 5) as u8).collect();
+    for len in [0, 1, 63, 64, 65, 1983, 1984, 1985, 5000] {
+        let expected: u64 = a[..len]
+            .iter()
+            .zip(&b[..len])
+            .map(|(a, b)| (a ^ b).count_ones() as u64)
+            .sum();
+        assert_eq!(distance::hamming(&a[..len], &b[..len]), expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn hamming_all_bits_differ() {
+    let a = vec![0u8; 100_000];
+    let b = vec![0xffu8; 100_000];
+    assert_eq!(distance::hamming(&a, &b), 800_000);
+    assert_eq!(distance::hamming(&a, &a), 0);
+}
+
+#[test]
+#[should_panic]
+fn hamming_different_lengths() {
+    let _ = distance::hamming(&[0; 4], &[0; 5]);
+}
+
+fn random(n: usize) -> Vec<u64> {
+    Xorshift::new().take(n).collect()
+}
+
+macro_rules! test_xor_popcount {
+    { $($name:ident: $ty:ty, $lanes:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            let random = random(100 * $lanes);
+            let vectors: Vec<Simd<$ty, $lanes>> = random
+                .chunks_exact($lanes)
+                .map(|x| Simd::from_array(core::array::from_fn(|i| x[i] as $ty)))
+                .collect();
+            let (a, b) = vectors.split_at(50);
+            for len in [0, 1, 15, 16, 17, 31, 32, 33, 50] {
+                let mut acc = Simd::from_array(core::array::from_fn(|i| i as u64));
+                distance::xor_popcount_accumulate(&a[..len], &b[..len], &mut acc);
+                for lane in 0..$lanes {
+                    let expected: u32 = a[..len]
+                        .iter()
+                        .zip(&b[..len])
+                        .map(|(a, b)| (a[lane] ^ b[lane]).count_ones())
+                        .sum();
+                    assert_eq!(acc[lane], lane as u64 + expected as u64, "{len}");
+                }
+            }
+        }
+        )*
+    }
+}
+
+test_xor_popcount! {
+    xor_popcount_u8x16: u8, 16;
+    xor_popcount_u8x64: u8, 64;
+    xor_popcount_u64x4: u64, 4;
+    xor_popcount_u64x8: u64, 8;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn xor_popcount_all_bits_differ() {
+    let a = vec![Simd::<u8, 64>::splat(0); 10_000];
+    let b = vec![Simd::<u8, 64>::splat(0xff); 10_000];
+    let mut acc = Simd::splat(0);
+    distance::xor_popcount_accumulate(&a, &b, &mut acc);
+    assert_eq!(acc, Simd::splat(80_000));
+    distance::xor_popcount_accumulate(&a, &a, &mut acc);
+    assert_eq!(acc, Simd::splat(80_000));
+}
+
+#[test]
+#[should_panic]
+fn xor_popcount_different_lengths() {
+    let mut acc = Simd::splat(0);
+    distance::xor_popcount_accumulate(
+        &[Simd::<u64, 2>::splat(0); 4],
+        &[Simd::splat(0); 5],
+        &mut acc,
+    );
+}
+
+/// Small integers, whose sums and products are exact in `f32`, so the results don't depend on
+/// the order of the sums.
+fn integers(len: usize, seed: u32) -> Vec<f32> {
+    (0..len as u32)
+        .map(|i| ((i * 37 + seed) % 17) as f32 - 8.0)
+        .collect()
+}
+
+const LENS: [usize; 10] = [0, 1, 7, 8, 9, 31, 32, 33, 100, 1000];
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn l2_squared() {
+    let (a, b) = (integers(1000, 1), integers(1000, 5));
+    for len in LENS {
+        let expected: f32 = a[..len]
+            .iter()
+            .zip(&b[..len])
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum();
+        assert_eq!(distance::l2_squared(&a[..len], &b[..len]), expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cosine() {
+    let (a, b) = (integers(1000, 2), integers(1000, 9));
+    for len in &LENS[1..] {
+        let (a, b) = (&a[..*len], &b[..*len]);
+        let dot: f32 = a.iter().zip(b).map(|(a, b)| a * b).sum();
+        let a_squared: f32 = a.iter().map(|a| a * a).sum();
+        let b_squared: f32 = b.iter().map(|b| b * b).sum();
+        let expected = 1.0 - dot / (a_squared.sqrt() * b_squared.sqrt());
+        let distance = distance::cosine(a, b);
+        assert!((distance - expected).abs() < 1e-6, "{distance} {expected}");
+        assert!(distance::cosine(a, a).abs() < 1e-6);
+    }
+    assert!(distance::cosine(&[], &[]).is_nan());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn dot_i8() {
+    let a: Vec<i8> = (0..1000u32).map(|i| (i * 7 + i / 3) as i8).collect();
+    let b: Vec<i8> = (0..1000u32).map(|i| (i * 13 + 5) as i8).collect();
+    for len in LENS.into_iter().chain([15, 16, 17, 63, 64, 65]) {
+        let expected: i32 = a[..len]
+            .iter()
+            .zip(&b[..len])
+            .map(|(&a, &b)| i32::from(a) * i32::from(b))
+            .sum();
+        assert_eq!(distance::dot_i8(&a[..len], &b[..len]), expected);
+    }
+    assert_eq!(
+        distance::dot_i8(&[i8::MIN; 1000], &[i8::MAX; 1000]),
+        -16_256_000
+    );
+}
+
+#[test]
+#[should_panic]
+fn l2_squared_different_lengths() {
+    let _ = distance::l2_squared(&[0.0; 4], &[0.0; 5]);
+}
+
+#[test]
+#[should_panic]
+fn dot_i8_different_lengths() {
+    let _ = distance::dot_i8(&[0; 17], &[0; 16]);
+}
@@ -0,0 +1,109 @@
+#![feature(portable_simd)]
+use core_simd::dsp;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn signal(len: usize) -> Vec<f32> {
+    let mut rng = Xorshift::new();
+    (0..len).map(|_| rng.next_f32() * 2.0 - 1.0).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ema() {
+    let signal = signal(300);
+    for alpha in [0.0, 0.01, 0.3, 0.5, 0.9, 1.0] {
+        for len in [0, 1, 2, 15, 16, 17, 100, 300] {
+            let signal = &signal[..len];
+            let mut output = vec![0.0; len];
+            dsp::ema(signal, alpha, &mut output);
+
+            let mut y = signal.first().copied().unwrap_or(0.0);
+            for (x, output) in signal.iter().zip(&output) {
+                y += alpha * (x - y);
+                assert!((output - y).abs() < 1e-5, "{output} != {y} (alpha {alpha})");
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ema_constant() {
+    // A constant signal is its own average
+    let mut output = [0.0; 50];
+    dsp::ema(&[3.0; 50], 0.25, &mut output);
+    assert!(output.iter().all(|y| (y - 3.0).abs() < 1e-6));
+}
+
+#[test]
+#[should_panic]
+fn ema_different_lengths() {
+    dsp::ema(&[1.0; 20], 0.5, &mut [0.0; 19]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn biquad_multi() {
+    use core_simd::f32x4;
+    use dsp::{BiquadCoefficients, BiquadState};
+
+    let coeffs = BiquadCoefficients {
+        b0: f32x4::from_array([1.0, 0.2, 0.5, 0.0675]),
+        b1: f32x4::from_array([0.0, 0.4, -0.5, 0.135]),
+        b2: f32x4::from_array([0.0, 0.2, 0.0, 0.0675]),
+        a1: f32x4::from_array([0.0, -0.3, 0.1, -1.143]),
+        a2: f32x4::from_array([0.0, 0.1, 0.0, 0.4128]),
+    };
+    let samples = signal(4 * 100);
+    let mut channels: Vec<f32x4> = samples.chunks(4).map(f32x4::from_slice).collect();
+
+    // Filter in two blocks, carrying the state between them
+    let mut state = BiquadState::new();
+    let (first, second) = channels.split_at_mut(37);
+    dsp::biquad_multi(first, &coeffs, &mut state);
+    dsp::biquad_multi(second, &coeffs, &mut state);
+
+    for lane in 0..4 {
+        let [b0, b1, b2, a1, a2] =
+            [coeffs.b0, coeffs.b1, coeffs.b2, coeffs.a1, coeffs.a2].map(|c| c[lane]);
+        let (mut x1, mut x2, mut y1, mut y2) = (0.0, 0.0, 0.0, 0.0);
+        for (frame, x) in channels.iter().zip(samples.chunks(4)) {
+            let x = x[lane];
+            let y = b0 * x + b1 * x1 + b2 * x2 - a1 * y1 - a2 * y2;
+            assert!((frame[lane] - y).abs() < 1e-5, "{} != {y}", frame[lane]);
+            (x2, x1, y2, y1) = (x1, x, y1, y);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn goertzel() {
+    use core_simd::f32x8;
+    use std::f64::consts::PI;
+
+    let signal = signal(100);
+    let bins = [0.0, 1.0, 2.5, 7.0, 13.0, 25.0, 49.0, 50.0];
+    let frequencies = bins.map(|k| k / signal.len() as f64);
+    let coeffs = f32x8::from_array(frequencies.map(|f| (2.0 * (2.0 * PI * f).cos()) as f32));
+    let power = dsp::goertzel(&signal, coeffs);
+
+    for (lane, f) in frequencies.into_iter().enumerate() {
+        // The DFT at `f`, up to a phase that doesn't change its magnitude
+        let (mut re, mut im) = (0.0, 0.0);
+        for (n, x) in signal.iter().enumerate() {
+            let angle = 2.0 * PI * f * n as f64;
+            re += *x as f64 * angle.cos();
+            im -= *x as f64 * angle.sin();
+        }
+        let expected = re * re + im * im;
+        let relative = (power[lane] as f64 - expected).abs() / expected.max(1.0);
+        assert!(relative < 1e-3, "{} != {expected}", power[lane]);
+    }
+}
@@ -0,0 +1,87 @@
+#![feature(portable_simd)]
+use core_simd::{f32x8, fft};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+type Complex = (f32, f32);
+
+fn random_vectors<const K: usize>(random: &mut Xorshift) -> [f32x8; K] {
+    [(); K].map(|_| f32x8::from_array([(); 8].map(|_| random.next_f32() * 2.0 - 1.0)))
+}
+
+fn complex(v: f32x8, k: usize) -> Complex {
+    (v[2 * k], v[2 * k + 1])
+}
+
+fn mul(a: Complex, b: Complex) -> Complex {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+fn assert_close(v: f32x8, k: usize, expected: Complex) {
+    let actual = complex(v, k);
+    assert!(
+        (actual.0 - expected.0).abs() < 1e-5 && (actual.1 - expected.1).abs() < 1e-5,
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn complex_mul() {
+    let mut random = Xorshift::new();
+    for _ in 0..100 {
+        let [a, b] = random_vectors(&mut random);
+        let product = fft::complex_mul(a, b);
+        for k in 0..4 {
+            assert_close(product, k, mul(complex(a, k), complex(b, k)));
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn butterfly2() {
+    let mut random = Xorshift::new();
+    for _ in 0..100 {
+        let [x0, x1, twiddle] = random_vectors(&mut random);
+        let [y0, y1] = fft::butterfly2([x0, x1], twiddle);
+        for k in 0..4 {
+            let (a, b) = (complex(x0, k), mul(complex(x1, k), complex(twiddle, k)));
+            assert_close(y0, k, (a.0 + b.0, a.1 + b.1));
+            assert_close(y1, k, (a.0 - b.0, a.1 - b.1));
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn butterfly4() {
+    let mut random = Xorshift::new();
+    for _ in 0..100 {
+        let [x0, x1, x2, x3, w1, w2, w3] = random_vectors(&mut random);
+        let y = fft::butterfly4([x0, x1, x2, x3], [w1, w2, w3]);
+        for k in 0..4 {
+            let x = [
+                complex(x0, k),
+                mul(complex(x1, k), complex(w1, k)),
+                mul(complex(x2, k), complex(w2, k)),
+                mul(complex(x3, k), complex(w3, k)),
+            ];
+            // The 4-point DFT, with roots of unity 1, -i, -1 and i
+            let roots = [(1.0, 0.0), (0.0, -1.0), (-1.0, 0.0), (0.0, 1.0)];
+            for (output, y) in y.iter().enumerate() {
+                let mut expected = (0.0, 0.0);
+                for (n, x) in x.iter().enumerate() {
+                    let term = mul(*x, roots[output * n % 4]);
+                    expected = (expected.0 + term.0, expected.1 + term.1);
+                }
+                assert_close(*y, k, expected);
+            }
+        }
+    }
+}
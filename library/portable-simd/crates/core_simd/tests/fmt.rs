@@ -0,0 +1,96 @@
+#![feature(portable_simd)]
+use core_simd::fmt;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn check_write_u64(values: &[u64]) {
+    let mut buf = vec![0; values.len() * 21];
+    let len = fmt::write_u64(&mut buf, values, b'\n');
+    let expected: Vec<String> = values.iter().map(u64::to_string).collect();
+    assert_eq!(
+        std::str::from_utf8(&buf[..len]).unwrap(),
+        expected.join("\n")
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn write_u64_powers_of_ten() {
+    let mut values = vec![0, u64::MAX];
+    let mut power = 1u64;
+    loop {
+        values.extend([power - 1, power, power + 1]);
+        match power.checked_mul(10) {
+            Some(next) => power = next,
+            None => break,
+        }
+    }
+    check_write_u64(&values);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn write_u64_random() {
+    test_helpers::test_1(&|values: [u64; 64]| {
+        check_write_u64(&values);
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn write_u64_empty() {
+    assert_eq!(fmt::write_u64(&mut [], &[], b','), 0);
+}
+
+#[test]
+#[should_panic]
+fn write_u64_buffer_too_small() {
+    let mut buf = [0; 8];
+    fmt::write_u64(&mut buf, &[1234, 56789], b',');
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn two_digits() {
+    let values: [u8; 100] = core::array::from_fn(|i| i as u8);
+    for chunk in values.chunks_exact(4) {
+        let (first, second) = fmt::two_digits(core_simd::u8x4::from_slice(chunk));
+        let expected: String = chunk.iter().map(|x| format!("{x:02}")).collect();
+        assert_eq!(
+            [first.to_array(), second.to_array()].concat(),
+            expected.as_bytes()
+        );
+    }
+    let (first, second) = fmt::two_digits(core_simd::u8x64::splat(99));
+    assert_eq!(first, core_simd::u8x64::splat(b'9'));
+    assert_eq!(second, core_simd::u8x64::splat(b'9'));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn format_fixed_width() {
+    test_helpers::test_1(&|values: [u64; 16]| {
+        for width in 1..=20 {
+            let values = values.map(|x| x % 10u64.checked_pow(width as u32).unwrap_or(u64::MAX));
+            let mut buf = vec![0; values.len() * (width + 1)];
+            let len = fmt::format_fixed_width(&mut buf, &values, width, b' ');
+            let expected: Vec<String> = values.iter().map(|x| format!("{x:0width$}")).collect();
+            assert_eq!(
+                std::str::from_utf8(&buf[..len]).unwrap(),
+                expected.join(" ")
+            );
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[should_panic]
+fn format_fixed_width_too_many_digits() {
+    fmt::format_fixed_width(&mut [0; 16], &[100], 2, b',');
+}
@@ -0,0 +1,118 @@
+#![feature(portable_simd)]
+use core_simd::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(n: usize) -> Vec<u64> {
+    Xorshift::new().take(n).collect()
+}
+
+/// Gathers and scatters random lanes, some disabled, out of bounds or repeated, and compares
+/// them with doing the same one lane at a time.
+fn check<T, const LANES: usize>(value: impl Fn(u64) -> T)
+where
+    T: SimdElement + PartialEq + core::fmt::Debug,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let len = 40;
+    let random = random(LANES * 300);
+    let slice: Vec<T> = (0..len).map(|i| value(i as u64 + 1)).collect();
+    let or = Simd::from_array([value(0); LANES]);
+    for lanes in random.chunks_exact(LANES) {
+        let idxs = Simd::from_array(core::array::from_fn(|i| lanes[i] as usize % (len + 8)));
+        let enable = Mask::from_array(core::array::from_fn(|i| lanes[i] & (1 << 40) != 0));
+
+        let gathered = Simd::gather_select(&slice, enable, idxs, or);
+        for lane in 0..LANES {
+            let expected = match slice.get(idxs[lane]) {
+                Some(&x) if enable.test(lane) => x,
+                _ => or[lane],
+            };
+            assert_eq!(gathered[lane], expected, "{idxs:?} {enable:?}");
+        }
+
+        let (counted, out_of_bounds) = Simd::gather_select_counted(&slice, enable, idxs, or);
+        assert_eq!(counted, gathered);
+        let expected = (0..LANES)
+            .filter(|&lane| enable.test(lane) && idxs[lane] >= len)
+            .count();
+        assert_eq!(out_of_bounds, expected, "{idxs:?} {enable:?}");
+
+        let values = Simd::from_array(core::array::from_fn(|i| value(lanes[i] >> 48)));
+        let mut scattered = slice.clone();
+        values.scatter_select(&mut scattered, enable, idxs);
+        let mut expected = slice.clone();
+        for lane in 0..LANES {
+            if enable.test(lane) {
+                if let Some(x) = expected.get_mut(idxs[lane]) {
+                    *x = values[lane];
+                }
+            }
+        }
+        assert_eq!(scattered, expected, "{idxs:?} {enable:?}");
+    }
+}
+
+macro_rules! test_gather {
+    { $($name:ident: $ty:ty, $value:expr;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            check::<$ty, 1>($value);
+            check::<$ty, 2>($value);
+            check::<$ty, 4>($value);
+            check::<$ty, 8>($value);
+            check::<$ty, 16>($value);
+            check::<$ty, 64>($value);
+        }
+        )*
+    }
+}
+
+test_gather! {
+    gather_u8: u8, |x| x as u8;
+    gather_i16: i16, |x| x as i16;
+    gather_i32: i32, |x| x as i32;
+    gather_f32: f32, |x| x as f32;
+    gather_u64: u64, |x| x.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    gather_f64: f64, |x| x as f64 * -0.5;
+    gather_usize: usize, |x| x as usize;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gather_counted_bounds() {
+    let slice = [1u32, 2, 3, 4];
+    let or = Simd::splat(0);
+    let idxs = Simd::from_array([0, 3, 4, usize::MAX, 1, 100, 2, 5]);
+
+    // Disabled lanes aren't counted, wherever they point
+    let (gathered, count) = Simd::gather_select_counted(&slice, Mask::splat(false), idxs, or);
+    assert_eq!((gathered, count), (or, 0));
+
+    let (gathered, count) = Simd::gather_select_counted(&slice, Mask::splat(true), idxs, or);
+    assert_eq!(gathered, Simd::from_array([1, 4, 0, 0, 2, 0, 3, 0]));
+    assert_eq!(count, 4);
+
+    let enable = Mask::from_array([true, true, false, false, true, true, false, false]);
+    let (gathered, count) = Simd::gather_select_counted(&slice, enable, idxs, or);
+    assert_eq!(gathered, Simd::from_array([1, 4, 0, 0, 2, 0, 0, 0]));
+    assert_eq!(count, 1);
+
+    // Every lane is out of bounds of an empty slice
+    let (gathered, count) = Simd::gather_select_counted(&[], Mask::splat(true), idxs, or);
+    assert_eq!((gathered, count), (or, 8));
+    let wide = Simd::<u8, 64>::gather_select_counted(
+        &[],
+        Mask::splat(true),
+        Simd::splat(0),
+        Simd::splat(7),
+    );
+    assert_eq!(wide, (Simd::splat(7), 64));
+}
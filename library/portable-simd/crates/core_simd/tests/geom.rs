@@ -0,0 +1,147 @@
+#![feature(portable_simd)]
+use core_simd::{f32x8, geom, SimdFloat};
+use std_float::StdFloat;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct Random(Xorshift);
+
+impl Random {
+    /// Returns a random float in `-scale..scale`.
+    fn next(&mut self, scale: f32) -> f32 {
+        (self.0.next_f32() * 2.0 - 1.0) * scale
+    }
+
+    fn vector(&mut self, scale: f32) -> f32x8 {
+        f32x8::from_array([(); 8].map(|_| self.next(scale)))
+    }
+}
+
+/// Returns the point `t` along the ray.
+fn at(origin: [f32; 3], direction: [f32; 3], t: f32) -> [f32; 3] {
+    [0, 1, 2].map(|i| origin[i] + direction[i] * t)
+}
+
+/// Returns whether any of a fine sampling of the ray is inside the shape.
+fn sampled_hit(origin: [f32; 3], direction: [f32; 3], inside: impl Fn([f32; 3]) -> bool) -> bool {
+    (0..20000).any(|i| inside(at(origin, direction, i as f32 * 0.001)))
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ray_aabb() {
+    let mut random = Random(Xorshift::new());
+    for _ in 0..20 {
+        let origin = [(); 3].map(|_| random.next(4.0));
+        let direction = [(); 3].map(|_| random.next(1.0));
+        let min = [(); 3].map(|_| random.vector(4.0));
+        let max = min.map(|min| min + random.vector(1.0).abs() + f32x8::splat(0.5));
+
+        let hits = geom::ray_aabb(origin, direction, min, max);
+        for lane in 0..8 {
+            let expected = sampled_hit(origin, direction, |p| {
+                (0..3).all(|i| min[i][lane] <= p[i] && p[i] <= max[i][lane])
+            });
+            assert_eq!(hits.test(lane), expected, "lane {lane}");
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ray_aabb_axis_aligned() {
+    // Rays parallel to a face, with infinite inverse directions, and lying on a face
+    let min = [f32x8::splat(0.0); 3];
+    let max = [f32x8::splat(1.0); 3];
+    assert!(geom::ray_aabb([-1.0, 0.5, 0.5], [1.0, 0.0, 0.0], min, max).all());
+    assert!(!geom::ray_aabb([-1.0, 1.5, 0.5], [1.0, 0.0, 0.0], min, max).any());
+    assert!(geom::ray_aabb([-1.0, 1.0, 0.5], [1.0, 0.0, 0.0], min, max).all());
+    assert!(geom::ray_aabb([-1.0, 0.0, 0.0], [1.0, 0.0, 0.0], min, max).all());
+    assert!(!geom::ray_aabb([2.0, 0.5, 0.5], [1.0, 0.0, 0.0], min, max).any());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ray_sphere() {
+    let mut random = Random(Xorshift::new());
+    for _ in 0..20 {
+        let origin = [(); 3].map(|_| random.next(4.0));
+        let direction = [(); 3].map(|_| random.next(1.0));
+        let center = [(); 3].map(|_| random.vector(4.0));
+        let radius = random.vector(1.0).abs() + f32x8::splat(0.5);
+
+        let hits = geom::ray_sphere(origin, direction, center, radius);
+        for lane in 0..8 {
+            let expected = sampled_hit(origin, direction, |p| {
+                let distance: f32 = (0..3).map(|i| (p[i] - center[i][lane]).powi(2)).sum();
+                distance <= radius[lane] * radius[lane]
+            });
+            assert_eq!(hits.test(lane), expected, "lane {lane}");
+        }
+    }
+}
+
+fn assert_close<const N: usize>(a: [f32x8; N], b: [f32x8; N]) {
+    for (a, b) in a.iter().zip(&b) {
+        assert!((*a - *b).abs().reduce_max() < 1e-4, "{a:?} != {b:?}");
+    }
+}
+
+fn unit_quaternion(random: &mut Random) -> [f32x8; 4] {
+    let q = [(); 4].map(|_| random.vector(1.0));
+    let length = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+    q.map(|x| x / length)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn quat_rotate() {
+    let mut random = Random(Xorshift::new());
+    for _ in 0..20 {
+        let q = unit_quaternion(&mut random);
+        let v = [(); 3].map(|_| random.vector(4.0));
+
+        // q * v * q^-1, where the inverse of a unit quaternion is its conjugate
+        let zero = f32x8::splat(0.0);
+        let conjugate = [-q[0], -q[1], -q[2], q[3]];
+        let [x, y, z, _] = geom::quat_mul(geom::quat_mul(q, [v[0], v[1], v[2], zero]), conjugate);
+        assert_close(geom::quat_rotate(q, v), [x, y, z]);
+
+        // Rotating by a product rotates by its factors in turn
+        let r = unit_quaternion(&mut random);
+        assert_close(
+            geom::quat_rotate(geom::quat_mul(q, r), v),
+            geom::quat_rotate(q, geom::quat_rotate(r, v)),
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mat4() {
+    let mut random = Random(Xorshift::new());
+    for _ in 0..20 {
+        let a = [(); 4].map(|_| [(); 4].map(|_| random.vector(2.0)));
+        let b = [(); 4].map(|_| [(); 4].map(|_| random.vector(2.0)));
+        let v = [(); 4].map(|_| random.vector(2.0));
+
+        let transformed = geom::mat4_transform(a, v);
+        for lane in 0..8 {
+            for row in 0..4 {
+                let expected: f32 = (0..4).map(|j| a[row][j][lane] * v[j][lane]).sum();
+                assert!((transformed[row][lane] - expected).abs() < 1e-4);
+            }
+        }
+
+        // Transforming by a product transforms by its factors in turn
+        assert_close(
+            geom::mat4_transform(geom::mat4_mul(a, b), v),
+            geom::mat4_transform(a, geom::mat4_transform(b, v)),
+        );
+    }
+}
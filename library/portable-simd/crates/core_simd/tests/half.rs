@@ -0,0 +1,129 @@
+#![feature(portable_simd)]
+use core_simd::{f32x4, half, u16x4, u32x4, SimdFloat};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Values exercising each range of the 16-bit formats, exactly representable in `f64` sums.
+fn values() -> f32x4 {
+    f32x4::from_array([
+        1.0 + 3.0 * 2f32.powi(-13),
+        -1234.567,
+        // An f16 subnormal
+        5.25 * 2f32.powi(-20),
+        -7e-3,
+    ])
+}
+
+/// Checks that rounding with every possible random value averages to the original value.
+fn assert_unbiased(values: f32x4, random_bits: u32, round: impl Fn(f32x4, u32x4) -> f32x4) {
+    let mut sum = [0f64; 4];
+    for random in 0..1 << random_bits {
+        let rounded = round(values, u32x4::splat(random));
+        for (sum, rounded) in sum.iter_mut().zip(rounded.to_array()) {
+            *sum += rounded as f64;
+        }
+    }
+    for (sum, value) in sum.iter().zip(values.to_array()) {
+        assert_eq!(sum / (1 << random_bits) as f64, value as f64);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bf16_unbiased() {
+    assert_unbiased(values(), 16, |x, random| {
+        half::from_bf16(half::to_bf16_stochastic(x, random))
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn f16_unbiased() {
+    assert_unbiased(values(), 13, |x, random| {
+        half::from_f16(half::to_f16_stochastic(x, random))
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn exact_values() {
+    // Values representable in both formats are unaffected by the random bits
+    let exact = f32x4::from_array([0.0, -0.0, 1.5, -2f32.powi(-14)]);
+    for random in [0, 0x1fff, 0xffff, u32::MAX] {
+        let random = u32x4::splat(random);
+        assert_eq!(
+            half::from_f16(half::to_f16_stochastic(exact, random)).to_bits(),
+            exact.to_bits()
+        );
+        assert_eq!(
+            half::from_bf16(half::to_bf16_stochastic(exact, random)).to_bits(),
+            exact.to_bits()
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn special_values() {
+    let special = f32x4::from_array([f32::INFINITY, f32::NEG_INFINITY, f32::NAN, 1e10]);
+    let random = u32x4::splat(u32::MAX);
+    assert_eq!(
+        half::to_f16_stochastic(special, random),
+        u16x4::from_array([0x7c00, 0xfc00, 0x7e00, 0x7c00])
+    );
+    assert_eq!(
+        half::to_bf16_stochastic(special, random),
+        u16x4::from_array([0x7f80, 0xff80, 0x7fc0, 0x5016])
+    );
+
+    // The largest finite values round up to infinity
+    let max = f32x4::from_array([65504.0, 65504.0, f32::MAX, f32::MAX]);
+    let random = u32x4::from_array([0, u32::MAX, 0, u32::MAX]);
+    assert_eq!(
+        half::to_f16_stochastic(max, random),
+        u16x4::from_array([0x7bff, 0x7bff, 0x7c00, 0x7c00])
+    );
+    assert_eq!(
+        half::to_bf16_stochastic(max, random),
+        u16x4::from_array([0x477f, 0x4780, 0x7f7f, 0x7f80])
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn from_f16_all() {
+    for bits in 0..=u16::MAX {
+        let value = half::from_f16(u16x4::splat(bits))[0];
+        let exponent = (bits >> 10) & 0x1f;
+        let mantissa = (bits & 0x3ff) as f64;
+        let expected = match exponent {
+            0 => mantissa * 2f64.powi(-24),
+            0x1f if mantissa == 0.0 => f64::INFINITY,
+            0x1f => f64::NAN,
+            _ => (1.0 + mantissa / 1024.0) * 2f64.powi(exponent as i32 - 15),
+        };
+        let expected = if bits & 0x8000 != 0 {
+            -expected
+        } else {
+            expected
+        };
+        if expected.is_nan() {
+            assert!(value.is_nan());
+            // NaNs become quiet NaNs, keeping their sign
+            assert_eq!(
+                half::to_f16_stochastic(f32x4::splat(value), u32x4::splat(0))[0],
+                (bits & 0x8000) | 0x7e00
+            );
+        } else {
+            assert_eq!(value as f64, expected, "{bits:#x}");
+            assert_eq!(
+                half::to_f16_stochastic(f32x4::splat(value), u32x4::splat(0x1fff))[0],
+                bits
+            );
+        }
+    }
+}
@@ -0,0 +1,166 @@
+#![feature(portable_simd)]
+use core::hash::{BuildHasher, Hasher};
+use core_simd::hash::{self, BuildSimdHasher, SimdHasher, Xxh64};
+use std::collections::{HashMap, HashSet};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(n: usize) -> Vec<u8> {
+    Xorshift::new().take(n).map(|x| (x >> 56) as u8).collect()
+}
+
+fn hash(key: (u64, u64), bytes: &[u8]) -> u64 {
+    let mut hasher = SimdHasher::new_with_keys(key.0, key.1);
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn split_writes() {
+    let data = random(100);
+    for len in [0, 1, 31, 32, 33, 64, 100] {
+        let data = &data[..len];
+        let expected = hash((1, 2), data);
+        for split in 0..=len {
+            let mut hasher = SimdHasher::new_with_keys(1, 2);
+            hasher.write(&data[..split]);
+            hasher.write(&[]);
+            hasher.write(&data[split..]);
+            assert_eq!(hasher.finish(), expected);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn distinct() {
+    // Every prefix of random data, and runs of zeros of every length
+    let data = random(200);
+    let zeros = [0; 200];
+    let mut hashes = HashSet::new();
+    for len in 0..=200 {
+        assert!(hashes.insert(hash((1, 2), &data[..len])));
+        if len > 0 {
+            assert!(hashes.insert(hash((1, 2), &zeros[..len])));
+        }
+    }
+
+    // The same words in different lanes
+    let mut swapped = zeros;
+    swapped[..8].fill(1);
+    let mut block = zeros;
+    block[8..16].fill(1);
+    assert_ne!(hash((1, 2), &swapped[..32]), hash((1, 2), &block[..32]));
+
+    // Different keys
+    assert_ne!(hash((1, 2), &data), hash((2, 1), &data));
+    assert_ne!(hash((1, 2), &data), hash((1, 3), &data));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn avalanche() {
+    // Flipping any bit of the input flips about half the bits of the hash
+    let data = random(64);
+    let expected = hash((1, 2), &data);
+    let mut flipped = 0;
+    for bit in 0..data.len() * 8 {
+        let mut data = data.clone();
+        data[bit / 8] ^= 1 << (bit % 8);
+        flipped += (hash((1, 2), &data) ^ expected).count_ones();
+    }
+    let average = f64::from(flipped) / (data.len() * 8) as f64;
+    assert!((30.0..34.0).contains(&average), "{average}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn hash_map() {
+    let state = BuildSimdHasher::new(1, 2);
+    let mut hasher = state.build_hasher();
+    hasher.write(b"key");
+    assert_eq!(hasher.finish(), hash((1, 2), b"key"));
+
+    let mut map = HashMap::with_hasher(state);
+    for i in 0..1000 {
+        map.insert(format!("a longer key, number {i}"), i);
+    }
+    for i in 0..1000 {
+        assert_eq!(map[&format!("a longer key, number {i}")], i);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn xxh64() {
+    // From the reference implementation, at every tail length and with and without stripes
+    let lens = [
+        0, 1, 3, 4, 7, 8, 9, 15, 16, 31, 32, 33, 63, 64, 100, 255, 300,
+    ];
+    let expected = [
+        (
+            0,
+            [
+                0xef46db3751d8e999,
+                0x49d1c4f1c492ef50,
+                0xaf5606facb5515ba,
+                0xa8c1378444847db9,
+                0xcafeabade5555005,
+                0xa2e0306855ba5143,
+                0x8890c1a41a89cb14,
+                0x28e82622d3345b13,
+                0xa3fe4c1ab6fc5210,
+                0x84a4ae2d074a6d27,
+                0x22648b4ee1ee9581,
+                0xed1b730468a9173c,
+                0xb981afce3babc661,
+                0x722160ee99d7faf9,
+                0x29d9d7720f1cec7f,
+                0xdd4625ae29650931,
+                0x55181054ed0a3f59,
+            ],
+        ),
+        (
+            0x9e3779b97f4a7c15,
+            [
+                0xc4349fc93c010000,
+                0x8e87d3ad17c42d5e,
+                0xe3143abf581136ab,
+                0x01e75c8d06c17bf5,
+                0xf01b14c0d15cfa3c,
+                0xf994b460310f967e,
+                0x569791fa4dd05d70,
+                0xf4eae967065c6ec8,
+                0x28359dad2f2bfa5f,
+                0x87cb7b175e3304dd,
+                0x4d5d9f4090e1779b,
+                0xe31a01f4a7e1ae7e,
+                0x1e4d5a4452a2fe3a,
+                0xe47a1e951d955e1f,
+                0x7c726e24dadd24eb,
+                0xe1e8a51d64e44abd,
+                0x9ae648927264be24,
+            ],
+        ),
+    ];
+    let data = random(300);
+    for (seed, expected) in expected {
+        for (len, expected) in lens.into_iter().zip(expected) {
+            let data = &data[..len];
+            assert_eq!(hash::xxh64(data, seed), expected);
+            for split in [0, 1, len / 2, len.saturating_sub(1)] {
+                let split = split.min(len);
+                let mut xxh = Xxh64::new(seed);
+                xxh.update(&data[..split]);
+                xxh.update(&data[split..]);
+                assert_eq!(xxh.finish(), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+#![feature(portable_simd)]
+use core_simd::id;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(n: usize) -> Vec<u8> {
+    Xorshift::new().take(n).map(|x| (x >> 56) as u8).collect()
+}
+
+fn format_uuid(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn parse_hex() {
+    let bytes = random(100);
+    for len in 0..bytes.len() {
+        let bytes = &bytes[..len];
+        let lower: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+        let mut out = vec![0; len];
+        id::parse_hex(lower.as_bytes(), &mut out).unwrap();
+        assert_eq!(out, bytes);
+        id::parse_hex(lower.to_uppercase().as_bytes(), &mut out).unwrap();
+        assert_eq!(out, bytes);
+
+        // Lengths that don't match
+        if len > 0 {
+            assert!(id::parse_hex(&lower.as_bytes()[1..], &mut out).is_err());
+        }
+        let mut longer = vec![0; len + 1];
+        assert!(id::parse_hex(lower.as_bytes(), &mut longer).is_err());
+
+        // Every character that isn't a hexadecimal digit, at some position
+        if len > 0 {
+            let position = (len * 7) % (len * 2);
+            for c in 0..=u8::MAX {
+                let mut text = lower.clone().into_bytes();
+                text[position] = c;
+                assert_eq!(
+                    id::parse_hex(&text, &mut out).is_ok(),
+                    c.is_ascii_hexdigit(),
+                    "{c}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn parse_uuid() {
+    let bytes = random(16 * 50);
+    for bytes in bytes.chunks_exact(16) {
+        let text = format_uuid(bytes);
+        assert_eq!(id::parse_uuid(text.as_bytes()).unwrap(), bytes);
+        assert_eq!(
+            id::parse_uuid(text.to_uppercase().as_bytes()).unwrap(),
+            bytes
+        );
+    }
+
+    let text = format_uuid(&bytes[..16]).into_bytes();
+    for position in 0..text.len() {
+        for c in [b'-', b'g', b'G', b'/', b':', b'@', b'`', 0, 0xff] {
+            if text[position] == c {
+                continue;
+            }
+            let mut text = text.clone();
+            text[position] = c;
+            assert!(id::parse_uuid(&text).is_err(), "{c} at {position}");
+        }
+    }
+    for len in 0..40 {
+        if len != text.len() {
+            let mut text = text.clone();
+            text.resize(len, b'0');
+            assert!(id::parse_uuid(&text).is_err());
+        }
+    }
+    // Without dashes
+    let simple: Vec<u8> = text.iter().copied().filter(|c| *c != b'-').collect();
+    assert!(id::parse_uuid(&simple).is_err());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn parse_uuid_batch() {
+    let bytes = random(16 * 10);
+    let uuids: Vec<String> = bytes.chunks_exact(16).map(format_uuid).collect();
+    let mut out = [[0; 16]; 10];
+    for count in 0..=uuids.len() {
+        let text = uuids[..count].join(",");
+        assert_eq!(
+            id::parse_uuid_batch(text.as_bytes(), b',', &mut out),
+            Ok(count)
+        );
+        for (out, bytes) in out[..count].iter().zip(bytes.chunks_exact(16)) {
+            assert_eq!(out, bytes);
+        }
+    }
+
+    let text = uuids[..3].join(",");
+    assert!(id::parse_uuid_batch(text.as_bytes(), b';', &mut out).is_err());
+    assert!(id::parse_uuid_batch(format!("{text},").as_bytes(), b',', &mut out).is_err());
+    assert!(id::parse_uuid_batch(uuids[..3].join(",,").as_bytes(), b',', &mut out).is_err());
+}
+
+#[test]
+#[should_panic]
+fn parse_uuid_batch_too_many() {
+    let text = ["00000000-0000-0000-0000-000000000000"; 3].join(",");
+    let _ = id::parse_uuid_batch(text.as_bytes(), b',', &mut [[0; 16]; 2]);
+}
@@ -0,0 +1,89 @@
+#![feature(portable_simd)]
+use core_simd::{i32x8, usizex8};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(n: usize) -> Vec<i32> {
+    Xorshift::new()
+        .take(n)
+        .map(|x| (x >> 32) as i32 >> (x % 24))
+        .collect()
+}
+
+fn mirror(x: i64, len: i64) -> i64 {
+    let r = x.rem_euclid(len);
+    if x.div_euclid(len) % 2 == 0 {
+        r
+    } else {
+        len - 1 - r
+    }
+}
+
+const LENS: [i32; 8] = [1, 2, 3, 7, 8, 64, 100, i32::MAX];
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn i32_matches_scalar() {
+    for x in random(800).chunks_exact(8) {
+        let v = i32x8::from_slice(x);
+        for len in LENS {
+            let (clamp, wrap, mirror_) =
+                (v.clamp_index(len), v.wrap_index(len), v.mirror_index(len));
+            for i in 0..8 {
+                let (x, l) = (i64::from(x[i]), i64::from(len));
+                assert_eq!(i64::from(clamp[i]), x.clamp(0, l - 1), "{x} {len}");
+                assert_eq!(i64::from(wrap[i]), x.rem_euclid(l), "{x} {len}");
+                assert_eq!(i64::from(mirror_[i]), mirror(x, l), "{x} {len}");
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn usize_matches_scalar() {
+    for x in random(800).chunks_exact(8) {
+        let x: Vec<usize> = x.iter().map(|&x| x.unsigned_abs() as usize).collect();
+        let v = usizex8::from_slice(&x);
+        for len in LENS.map(|len| len as usize) {
+            let (clamp, wrap, mirror_) =
+                (v.clamp_index(len), v.wrap_index(len), v.mirror_index(len));
+            for i in 0..8 {
+                assert_eq!(clamp[i], x[i].min(len - 1), "{} {len}", x[i]);
+                assert_eq!(wrap[i], x[i] % len, "{} {len}", x[i]);
+                assert_eq!(
+                    mirror_[i] as i64,
+                    mirror(x[i] as i64, len as i64),
+                    "{} {len}",
+                    x[i]
+                );
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn negative_indices() {
+    let x = i32x8::from_array([-8, -7, -6, -5, -4, -3, -2, -1]);
+    assert_eq!(x.clamp_index(4).to_array(), [0; 8]);
+    assert_eq!(x.wrap_index(4).to_array(), [0, 1, 2, 3, 0, 1, 2, 3]);
+    assert_eq!(x.wrap_index(3).to_array(), [1, 2, 0, 1, 2, 0, 1, 2]);
+    assert_eq!(x.mirror_index(4).to_array(), [0, 1, 2, 3, 3, 2, 1, 0]);
+    assert_eq!(x.mirror_index(3).to_array(), [1, 0, 0, 1, 2, 2, 1, 0]);
+    assert_eq!(
+        i32x8::splat(i32::MIN).mirror_index(3),
+        i32x8::splat(mirror(i32::MIN.into(), 3) as i32)
+    );
+}
+
+#[test]
+#[should_panic]
+fn zero_len() {
+    let _ = usizex8::splat(1).wrap_index(0);
+}
@@ -0,0 +1,124 @@
+#![feature(portable_simd)]
+use core_simd::{f32x4, f64x2, interval::SimdInterval, SimdFloat, SimdPartialOrd};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(n: usize) -> Vec<f32> {
+    Xorshift::new()
+        .take(n)
+        .map(|x| {
+            // Values of very different magnitudes, so sums round
+            let mantissa = (x >> 40) as f32 / (1 << 24) as f32 - 0.5;
+            mantissa * 2f32.powi((x % 64) as i32 - 32)
+        })
+        .collect()
+}
+
+fn interval(a: f32, b: f32) -> SimdInterval<f32, 4> {
+    SimdInterval::new(f32x4::splat(a.min(b)), f32x4::splat(a.max(b)))
+}
+
+/// Sums and products of `f32`s are exact in `f64`, so check that the intervals contain the
+/// exact results of every combination of bounds.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn contains_exact() {
+    let values = random(400);
+    for v in values.chunks_exact(4) {
+        let x = interval(v[0], v[1]);
+        let y = interval(v[2], v[3]);
+        for a in [x.lo()[0], x.hi()[0]] {
+            for b in [y.lo()[0], y.hi()[0]] {
+                let (a64, b64) = (f64::from(a), f64::from(b));
+                for (result, exact) in [(x + y, a64 + b64), (x - y, a64 - b64), (x * y, a64 * b64)]
+                {
+                    assert!(f64::from(result.lo()[0]) <= exact, "{result:?} {exact}");
+                    assert!(f64::from(result.hi()[0]) >= exact, "{result:?} {exact}");
+                }
+            }
+        }
+        for result in [x.min(y), x.max(y)] {
+            assert!(result.lo().simd_le(result.hi()).all());
+        }
+        assert_eq!(x.min(y).lo()[0], x.lo()[0].min(y.lo()[0]));
+        assert_eq!(x.max(y).hi()[0], x.hi()[0].max(y.hi()[0]));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rounded_outward() {
+    // Exact results are widened by one unit in the last place
+    let one = SimdInterval::point(f64x2::splat(1.0));
+    let two = one + one;
+    assert_eq!(two.lo(), f64x2::splat(2.0).next_down());
+    assert_eq!(two.hi(), f64x2::splat(2.0).next_up());
+
+    let x = SimdInterval::new(
+        f64x2::from_array([-1.0, 2.0]),
+        f64x2::from_array([3.0, 4.0]),
+    );
+    let y = SimdInterval::new(
+        f64x2::from_array([-2.0, -3.0]),
+        f64x2::from_array([5.0, -1.0]),
+    );
+    let product = x * y;
+    assert_eq!(product.lo(), f64x2::from_array([-6.0, -12.0]).next_down());
+    assert_eq!(product.hi(), f64x2::from_array([15.0, -2.0]).next_up());
+    assert_eq!((-x).lo().to_array(), [-3.0, -4.0], "negation is exact");
+    assert!(x.contains(f64x2::from_array([3.0, 2.0])).all());
+    assert!(!x.contains(f64x2::from_array([3.5, 1.0])).any());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn infinite_bounds() {
+    let x = SimdInterval::new(
+        f32x4::from_array([0.0, 0.0, -1.0, f32::NEG_INFINITY]),
+        f32x4::from_array([1.0, 0.0, f32::INFINITY, f32::INFINITY]),
+    );
+    let y = SimdInterval::new(
+        f32x4::from_array([1.0, f32::NEG_INFINITY, 2.0, 1.0]),
+        f32x4::from_array([f32::INFINITY, f32::INFINITY, 3.0, 1.0]),
+    );
+    // Zero times infinity is zero
+    let product = x * y;
+    assert_eq!(
+        product.lo().to_array(),
+        [
+            -f32::from_bits(1),
+            -f32::from_bits(1),
+            f32x4::splat(-3.0).next_down()[0],
+            f32::NEG_INFINITY
+        ]
+    );
+    assert_eq!(
+        product.hi().to_array(),
+        [
+            f32::INFINITY,
+            f32::from_bits(1),
+            f32::INFINITY,
+            f32::INFINITY
+        ]
+    );
+    let sum = x + y;
+    assert_eq!(sum.hi().to_array(), [f32::INFINITY; 4]);
+    assert_eq!(sum.lo()[3], f32::NEG_INFINITY);
+}
+
+#[test]
+#[should_panic]
+fn new_reversed() {
+    let _ = SimdInterval::new(f32x4::splat(1.0), f32x4::from_array([1.0, 2.0, 0.5, 3.0]));
+}
+
+#[test]
+#[should_panic]
+fn point_nan() {
+    let _ = SimdInterval::point(f64x2::from_array([0.0, f64::NAN]));
+}
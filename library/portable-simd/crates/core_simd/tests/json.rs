@@ -0,0 +1,104 @@
+#![feature(portable_simd)]
+use core_simd::json::{Block, Scanner};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Classifies a document one byte at a time.
+fn scan_scalar(document: &[u8]) -> Vec<Block> {
+    let mut blocks = vec![Block::default(); document.len() / 64];
+    let mut in_string = false;
+    let mut next_is_escaped = false;
+    for (i, byte) in document.iter().copied().enumerate() {
+        let block = &mut blocks[i / 64];
+        let bit = 1 << (i % 64);
+
+        let escaped = next_is_escaped;
+        next_is_escaped = !escaped && byte == b'\\';
+        if escaped {
+            block.escaped |= bit;
+        }
+
+        if byte == b'"' && !escaped {
+            block.quote |= bit;
+            in_string = !in_string;
+            if in_string {
+                block.in_string |= bit;
+            }
+        } else if in_string {
+            block.in_string |= bit;
+        } else if b"{}[]:,".contains(&byte) {
+            block.structural |= bit;
+        } else if b" \t\n\r".contains(&byte) {
+            block.whitespace |= bit;
+        }
+    }
+    blocks
+}
+
+fn scan(document: &[u8]) -> (Vec<Block>, bool) {
+    let mut scanner = Scanner::new();
+    let blocks = document
+        .chunks_exact(64)
+        .map(|block| scanner.scan(block.try_into().unwrap()))
+        .collect();
+    (blocks, scanner.in_string())
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn document() {
+    let mut document = br#"{"name": "a \"quoted\" {string}", "path": "C:\\dir\\", "list": [1, 2, {"x": null}],
+        "long": "this string is long enough to span more than one block of input, \\\" to be sure"}"#
+        .to_vec();
+    document.resize(256, b' ');
+    let (blocks, in_string) = scan(&document);
+    assert_eq!(blocks, scan_scalar(&document));
+    assert!(!in_string);
+
+    let structural: Vec<u8> = document
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| blocks[i / 64].structural & (1 << (i % 64)) != 0)
+        .map(|(_, byte)| *byte)
+        .collect();
+    assert_eq!(structural, b"{:,:,:[,,{:}],:}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn backslash_runs() {
+    // Runs of backslashes of every length, ending at every position around a block boundary
+    for run in 0..70 {
+        for start in 0..70 {
+            let mut document = vec![b' '; 192];
+            document[start] = b'"';
+            for byte in &mut document[start + 1..][..run] {
+                *byte = b'\\';
+            }
+            document[start + 1 + run] = b'"';
+            let (blocks, in_string) = scan(&document);
+            assert_eq!(blocks, scan_scalar(&document), "run {run} at {start}");
+            assert_eq!(in_string, run % 2 == 1, "run {run} at {start}");
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn random() {
+    const ALPHABET: &[u8] = b"\"\\\"\\{}[]:, \t\n\rax";
+    let mut random = Xorshift::new();
+    for _ in 0..1000 {
+        let document: Vec<u8> = (&mut random)
+            .take(256)
+            .map(|x| ALPHABET[(x % ALPHABET.len() as u64) as usize])
+            .collect();
+        let (blocks, _) = scan(&document);
+        assert_eq!(blocks, scan_scalar(&document));
+    }
+}
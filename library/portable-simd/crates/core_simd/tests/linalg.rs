@@ -0,0 +1,104 @@
+#![feature(portable_simd)]
+use core_simd::linalg;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn values(len: usize) -> Vec<i32> {
+    Xorshift::new()
+        .take(len)
+        .map(|x| {
+            // Small integers, so every sum of floats is exact
+            (x >> 32) as i32 % 9
+        })
+        .collect()
+}
+
+fn scalar_gemv(matrix: &[i32], x: &[i32], rows: usize) -> Vec<i32> {
+    (0..rows)
+        .map(|r| {
+            let row = &matrix[r * x.len()..(r + 1) * x.len()];
+            row.iter().zip(x).map(|(a, b)| a * b).sum()
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gemv() {
+    let all = values(40 * 70 + 70);
+    for rows in [0, 1, 3, 4, 5, 9, 40] {
+        for cols in [0, 1, 15, 16, 17, 33, 70] {
+            let (matrix, x) = (&all[..rows * cols], &all[rows * cols..rows * cols + cols]);
+            let expected = scalar_gemv(matrix, x, rows);
+
+            let mut y = vec![1; rows];
+            linalg::gemv(matrix, x, &mut y);
+            assert_eq!(y, expected);
+
+            let to_f32 = |v: &[i32]| v.iter().map(|x| *x as f32).collect::<Vec<_>>();
+            let mut y = vec![1.0; rows];
+            linalg::gemv(&to_f32(matrix), &to_f32(x), &mut y);
+            assert_eq!(y, to_f32(&expected));
+
+            let to_f64 = |v: &[i32]| v.iter().map(|x| *x as f64).collect::<Vec<_>>();
+            let mut y = vec![1.0; rows];
+            linalg::gemv(&to_f64(matrix), &to_f64(x), &mut y);
+            assert_eq!(y, to_f64(&expected));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn gemv_wrong_size() {
+    linalg::gemv(&[1.0f32; 11], &[1.0; 3], &mut [0.0; 4]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gemm_micro() {
+    use core_simd::Simd;
+
+    fn test<const MR: usize, const NR: usize>(k: usize)
+    where
+        core_simd::LaneCount<NR>: core_simd::SupportedLaneCount,
+    {
+        let all = values(MR * k + NR * k);
+        let (a, b) = all.split_at(MR * k);
+
+        let mut tile = [Simd::<i32, NR>::splat(1); MR];
+        linalg::gemm_micro::<i32, MR, NR>(a, b, &mut tile);
+        for i in 0..MR {
+            for j in 0..NR {
+                let dot: i32 = (0..k).map(|p| a[p * MR + i] * b[p * NR + j]).sum();
+                assert_eq!(tile[i][j], dot + 1);
+            }
+        }
+
+        let to_f64 = |v: &[i32]| v.iter().map(|x| *x as f64).collect::<Vec<_>>();
+        let mut float_tile = [Simd::<f64, NR>::splat(1.0); MR];
+        linalg::gemm_micro::<f64, MR, NR>(&to_f64(a), &to_f64(b), &mut float_tile);
+        for (float_row, row) in float_tile.iter().zip(tile) {
+            assert_eq!(*float_row, row.cast());
+        }
+    }
+
+    for k in [0, 1, 2, 7, 64] {
+        test::<1, 1>(k);
+        test::<4, 8>(k);
+        test::<6, 16>(k);
+        test::<3, 4>(k);
+    }
+}
+
+#[test]
+#[should_panic]
+fn gemm_micro_wrong_size() {
+    let mut tile = [core_simd::f32x4::splat(0.0); 2];
+    linalg::gemm_micro::<f32, 2, 4>(&[1.0; 6], &[1.0; 8], &mut tile);
+}
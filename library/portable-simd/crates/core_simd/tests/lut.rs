@@ -0,0 +1,154 @@
+#![feature(portable_simd)]
+use core_simd::{
+    lut::{self, SimdLut, Table},
+    Simd,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn check<T, const SIZE: usize, const LANES: usize>(table: [T; SIZE])
+where
+    T: core_simd::SimdElement + Default + PartialEq + core::fmt::Debug,
+    core_simd::LaneCount<LANES>: core_simd::SupportedLaneCount,
+{
+    let lut = SimdLut::new(table);
+    for start in 0..SIZE + 2 {
+        let idxs: [usize; LANES] = core::array::from_fn(|i| (start + i * 3) % (SIZE + 3));
+        let expected = idxs.map(|i| table.get(i).copied().unwrap_or_default());
+        assert_eq!(lut.lookup(Simd::from_array(idxs)).to_array(), expected);
+    }
+    let far = Simd::from_array([usize::MAX; LANES]);
+    assert_eq!(lut.lookup(far).to_array(), [T::default(); LANES]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bytes() {
+    let table: [u8; 16] = core::array::from_fn(|i| (i * 17 + 3) as u8);
+    check::<_, 16, 1>(table);
+    check::<_, 16, 16>(table);
+    check::<_, 16, 64>(table);
+    check::<_, 5, 8>([-1i8, 2, -3, 4, -5]);
+    check::<_, 0, 4>([0u8; 0]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn large() {
+    let table: [u8; 40] = core::array::from_fn(|i| (i * 5) as u8);
+    check::<_, 40, 16>(table);
+    let table: [f32; 10] = core::array::from_fn(|i| i as f32 * 0.5);
+    check::<_, 10, 8>(table);
+    check::<_, 3, 4>([1u64, u64::MAX, 7]);
+}
+
+struct Primes;
+
+impl Table for Primes {
+    type Element = u8;
+    const TABLE: &'static [u8] = &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+}
+
+struct Signed;
+
+impl Table for Signed {
+    type Element = i8;
+    const TABLE: &'static [i8] = &[-8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 6, 7];
+}
+
+struct Wide;
+
+impl Table for Wide {
+    type Element = u32;
+    const TABLE: &'static [u32] = &[1, 10, 100, 1000, 10000, 100000];
+}
+
+fn check_map_table<M: Table, const LANES: usize>()
+where
+    M::Element: PartialEq + core::fmt::Debug,
+    core_simd::LaneCount<LANES>: core_simd::SupportedLaneCount,
+{
+    for start in 0..=255 {
+        let idxs: [u8; LANES] = core::array::from_fn(|i| (start + i * 7) as u8);
+        let expected = idxs.map(|i| M::TABLE.get(i as usize).copied().unwrap_or_default());
+        assert_eq!(Simd::from_array(idxs).map_table::<M>().to_array(), expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn map_table() {
+    check_map_table::<Primes, 1>();
+    check_map_table::<Primes, 8>();
+    check_map_table::<Primes, 16>();
+    check_map_table::<Primes, 64>();
+    check_map_table::<Signed, 16>();
+    check_map_table::<Signed, 32>();
+    check_map_table::<Wide, 4>();
+    check_map_table::<Wide, 16>();
+}
+
+macro_rules! test_lookup {
+    { $($name:ident, $size:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            fn check<const LANES: usize>()
+            where
+                core_simd::LaneCount<LANES>: core_simd::SupportedLaneCount,
+            {
+                let table = Simd::<u8, $size>::from_array(core::array::from_fn(|i| (i * 37 + 1) as u8));
+                for start in 0..=255 {
+                    let idxs: [u8; LANES] = core::array::from_fn(|i| (start + i * 7) as u8);
+                    let expected = idxs.map(|i| table.as_array().get(i as usize).copied().unwrap_or(0));
+                    let looked_up = lut::$name(table, Simd::from_array(idxs));
+                    assert_eq!(looked_up.to_array(), expected);
+                }
+            }
+            check::<1>();
+            check::<4>();
+            check::<8>();
+            check::<16>();
+            check::<32>();
+            check::<64>();
+        }
+        )*
+    }
+}
+
+test_lookup! {
+    lookup16, 16;
+    lookup32, 32;
+    lookup64, 64;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn single_instruction() {
+    let x86 = cfg!(any(target_arch = "x86", target_arch = "x86_64"));
+    let ssse3 = x86 && cfg!(target_feature = "ssse3");
+    let avx2 = x86 && cfg!(target_feature = "avx2");
+    let neon = cfg!(all(
+        target_arch = "aarch64",
+        target_endian = "little",
+        target_feature = "neon"
+    ));
+    let wasm = cfg!(all(target_arch = "wasm32", target_feature = "simd128"));
+    assert_eq!(lut::is_single_instruction(16, 16), ssse3 || neon || wasm);
+    assert_eq!(lut::is_single_instruction(16, 32), avx2);
+    assert_eq!(lut::is_single_instruction(32, 16), neon);
+    assert_eq!(lut::is_single_instruction(64, 8), neon);
+    // Looked up with a shuffle of each 16 bytes of the table, rather than a single one
+    if ssse3 || wasm {
+        assert!(!lut::is_single_instruction(32, 16));
+        assert!(!lut::is_single_instruction(64, 16));
+    }
+    assert!(!lut::is_single_instruction(16, 4));
+    assert!(!lut::is_single_instruction(20, 16));
+    assert!(!lut::is_single_instruction(128, 64));
+}
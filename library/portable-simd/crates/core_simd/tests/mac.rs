@@ -0,0 +1,191 @@
+#![feature(portable_simd)]
+use core_simd::mac::{GHash, Poly1305};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(n: usize) -> Vec<u8> {
+    Xorshift::new().take(n).map(|x| (x >> 56) as u8).collect()
+}
+
+/// Numbers below `2^192`, as little-endian 64-bit limbs.
+type U192 = [u64; 3];
+
+const P: U192 = [0xffff_ffff_ffff_fffb, 0xffff_ffff_ffff_ffff, 3];
+
+fn add(a: U192, b: U192) -> U192 {
+    let mut sum = [0; 3];
+    let mut carry = 0;
+    for i in 0..3 {
+        let limb = u128::from(a[i]) + u128::from(b[i]) + carry;
+        sum[i] = limb as u64;
+        carry = limb >> 64;
+    }
+    sum
+}
+
+fn reduce(mut a: U192) -> U192 {
+    while a.iter().rev().cmp(P.iter().rev()).is_ge() {
+        // Subtract `p` by adding its two's complement
+        a = add(a, add(P.map(|limb| !limb), [1, 0, 0]));
+    }
+    a
+}
+
+/// Poly1305, with the multiplication done a bit at a time.
+fn poly1305(key: &[u8; 32], message: &[u8]) -> [u8; 16] {
+    let r =
+        u128::from_le_bytes(key[..16].try_into().unwrap()) & 0x0ffffffc_0ffffffc_0ffffffc_0fffffff;
+    let s = u128::from_le_bytes(key[16..].try_into().unwrap());
+    let mut h = [0; 3];
+    for block in message.chunks(16) {
+        let mut padded = [0; 17];
+        padded[..block.len()].copy_from_slice(block);
+        padded[block.len()] = 1;
+        let m = u128::from_le_bytes(padded[..16].try_into().unwrap());
+        let m = [m as u64, (m >> 64) as u64, u64::from(padded[16])];
+        let a = reduce(add(h, m));
+        h = [0; 3];
+        for bit in (0..128).rev() {
+            h = reduce(add(h, h));
+            if r >> bit & 1 == 1 {
+                h = reduce(add(h, a));
+            }
+        }
+    }
+    let h = u128::from(h[0]) | u128::from(h[1]) << 64;
+    h.wrapping_add(s).to_le_bytes()
+}
+
+/// GHASH, with the multiplication done a bit at a time.
+fn ghash(h: &[u8; 16], message: &[u8]) -> [u8; 16] {
+    let h = u128::from_be_bytes(*h);
+    let mut y = 0;
+    for block in message.chunks(16) {
+        let mut padded = [0; 16];
+        padded[..block.len()].copy_from_slice(block);
+        let x = y ^ u128::from_be_bytes(padded);
+        let mut v = h;
+        y = 0;
+        for i in 0..128 {
+            if x >> (127 - i) & 1 == 1 {
+                y ^= v;
+            }
+            v = (v >> 1) ^ if v & 1 == 1 { 0xe1 << 120 } else { 0 };
+        }
+    }
+    y.to_be_bytes()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn poly1305_rfc8439() {
+    // Test vectors 1, 4, 5 and 6 of RFC 8439, section A.3
+    let mut key = [0; 32];
+    let mut poly = Poly1305::new(&key);
+    poly.update(&[0; 64]);
+    assert_eq!(poly.finish(), [0; 16]);
+
+    key[..16].copy_from_slice(&[
+        0x1c, 0x92, 0x40, 0xa5, 0xeb, 0x55, 0xd3, 0x8a, 0xf3, 0x33, 0x88, 0x86, 0x04, 0xf6, 0xb5,
+        0xf0,
+    ]);
+    key[16..].copy_from_slice(&[
+        0x47, 0x39, 0x17, 0xc1, 0x40, 0x2b, 0x80, 0x09, 0x9d, 0xca, 0x5c, 0xbc, 0x20, 0x70, 0x75,
+        0xc0,
+    ]);
+    let message = b"'Twas brillig, and the slithy toves\nDid gyre and gimble in the wabe:\n\
+                    All mimsy were the borogoves,\nAnd the mome raths outgrabe.";
+    let mut poly = Poly1305::new(&key);
+    poly.update(message);
+    assert_eq!(
+        poly.finish(),
+        [
+            0x45, 0x41, 0x66, 0x9a, 0x7e, 0xaa, 0xee, 0x61, 0xe7, 0x08, 0xdc, 0x7c, 0xbc, 0xc5,
+            0xeb, 0x62
+        ]
+    );
+
+    // h reaches p
+    let mut key = [0; 32];
+    key[0] = 2;
+    let mut poly = Poly1305::new(&key);
+    poly.update(&[0xff; 16]);
+    assert_eq!(
+        poly.finish(),
+        [3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+
+    // h + s overflows 128 bits
+    let mut key = [0; 32];
+    key[0] = 2;
+    key[16..].fill(0xff);
+    let mut poly = Poly1305::new(&key);
+    poly.update(&[2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        poly.finish(),
+        [3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn poly1305_random() {
+    let data = random(32 * 20 + 200);
+    let (keys, message) = data.split_at(32 * 20);
+    for (len, key) in keys.chunks_exact(32).enumerate() {
+        let key = key.try_into().unwrap();
+        let message = &message[..len * 9 + len % 3];
+        let expected = poly1305(key, message);
+
+        let mut poly = Poly1305::new(key);
+        poly.update(message);
+        assert_eq!(poly.finish(), expected);
+
+        // Split at every length
+        for split in 0..message.len() {
+            let mut poly = Poly1305::new(key);
+            poly.update(&message[..split]);
+            poly.update(&[]);
+            poly.update(&message[split..]);
+            assert_eq!(poly.finish(), expected);
+        }
+    }
+
+    // The largest key and message
+    let key = [0xff; 32];
+    let message = [0xff; 100];
+    let mut poly = Poly1305::new(&key);
+    poly.update(&message);
+    assert_eq!(poly.finish(), poly1305(&key, &message));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn ghash_random() {
+    let data = random(16 * 20 + 200);
+    let (keys, message) = data.split_at(16 * 20);
+    for (len, h) in keys.chunks_exact(16).enumerate() {
+        let h = h.try_into().unwrap();
+        let message = &message[..len * 10];
+        let mut ghash_simd = GHash::new(h);
+        ghash_simd.update(message);
+        assert_eq!(ghash_simd.finish(), ghash(h, message));
+    }
+
+    // Partial blocks are padded with zeros
+    let h = [0xff; 16];
+    let mut ghash_simd = GHash::new(&h);
+    ghash_simd.update(&message[..20]);
+    ghash_simd.update(&message[20..45]);
+    let mut padded = [0; 48];
+    padded[..20].copy_from_slice(&message[..20]);
+    padded[32..].copy_from_slice(&message[20..36]);
+    let mut expected = padded.to_vec();
+    expected.extend_from_slice(&message[36..45]);
+    assert_eq!(ghash_simd.finish(), ghash(&h, &expected));
+}
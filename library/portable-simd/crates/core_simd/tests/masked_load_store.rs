@@ -0,0 +1,107 @@
+#![feature(portable_simd)]
+use core_simd::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Loads and stores every length of slice up to past a whole vector, including empty slices,
+/// with random lanes disabled, and compares them with doing the same one lane at a time.
+fn check<T, const LANES: usize>(value: impl Fn(u64) -> T)
+where
+    T: SimdElement + Default + PartialEq + core::fmt::Debug,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut rng = Xorshift::new();
+    let or = Simd::from_array(core::array::from_fn(|i| value(1000 + i as u64)));
+    let values = Simd::from_array(core::array::from_fn(|i| value(2000 + i as u64)));
+    for len in 0..LANES + 3 {
+        let slice: Vec<T> = (0..len).map(|i| value(i as u64 + 1)).collect();
+        let bits = rng.next_u64();
+        let enable = Mask::from_array(core::array::from_fn(|i| bits >> (i % 64) & 1 != 0));
+
+        let loaded = Simd::load_select(&slice, enable, or);
+        let loaded_or = Simd::load_or(&slice, or);
+        let loaded_default = Simd::<T, LANES>::load_or_default(&slice);
+        for lane in 0..LANES {
+            let in_bounds = slice.get(lane).copied();
+            let expected = match in_bounds {
+                Some(x) if enable.test(lane) => x,
+                _ => or[lane],
+            };
+            assert_eq!(loaded[lane], expected, "len {len}, enable {bits:#x}");
+            assert_eq!(loaded_or[lane], in_bounds.unwrap_or(or[lane]), "len {len}");
+            assert_eq!(
+                loaded_default[lane],
+                in_bounds.unwrap_or_default(),
+                "len {len}"
+            );
+        }
+
+        // Store into the front of a longer buffer, to catch writes past the end of the slice
+        let mut buffer = slice.clone();
+        buffer.extend((0..LANES).map(|i| value(3000 + i as u64)));
+        let mut expected = buffer.clone();
+        values.store_select(&mut buffer[..len], enable);
+        for lane in 0..LANES.min(len) {
+            if enable.test(lane) {
+                expected[lane] = values[lane];
+            }
+        }
+        assert_eq!(buffer, expected, "len {len}, enable {bits:#x}");
+
+        // The unchecked variants agree when the lanes past the end are disabled
+        let enable = enable & Mask::from_array(core::array::from_fn(|i| i < len));
+        // Safety: lanes past the end of the slice are disabled
+        let loaded_unchecked = unsafe { Simd::load_select_unchecked(&slice, enable, or) };
+        assert_eq!(loaded_unchecked, loaded);
+        let mut stored = slice.clone();
+        // Safety: lanes past the end of the slice are disabled
+        unsafe { values.store_select_unchecked(&mut stored, enable) };
+        assert_eq!(stored, expected[..len]);
+    }
+}
+
+macro_rules! test_masked_load_store {
+    { $($name:ident: $ty:ty, $value:expr;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            check::<$ty, 1>($value);
+            check::<$ty, 2>($value);
+            check::<$ty, 4>($value);
+            check::<$ty, 8>($value);
+            check::<$ty, 16>($value);
+            check::<$ty, 64>($value);
+        }
+        )*
+    }
+}
+
+test_masked_load_store! {
+    masked_u8: u8, |x| x as u8;
+    masked_i16: i16, |x| x as i16;
+    masked_i32: i32, |x| x as i32;
+    masked_f32: f32, |x| x as f32;
+    masked_u64: u64, |x| x.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    masked_f64: f64, |x| x as f64 * -0.5;
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn disabled_lanes() {
+    let slice = [1, 2, 3, 4];
+    let or = Simd::from_array([-1, -2, -3, -4]);
+    let none = Mask::splat(false);
+    assert_eq!(Simd::load_select(&slice, none, or), or);
+    assert_eq!(Simd::load_select(&[], Mask::splat(true), or), or);
+
+    let mut stored = slice;
+    Simd::splat(9).store_select(&mut stored, none);
+    assert_eq!(stored, slice);
+    Simd::<i32, 4>::splat(9).store_select(&mut [], Mask::splat(true));
+}
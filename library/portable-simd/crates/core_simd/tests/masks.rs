@@ -59,6 +59,27 @@ fn all() {
                 assert!(!v.all());
             }
 
+            #[test]
+            fn splat_first_n() {
+                for n in 0..=10 {
+                    let mask = core_simd::Mask::<$type, 8>::splat_first_n(n);
+                    for lane in 0..8 {
+                        assert_eq!(mask.test(lane), lane < n);
+                    }
+                }
+                assert!(core_simd::Mask::<$type, 64>::splat_first_n(usize::MAX).all());
+            }
+
+            #[test]
+            fn set_algebra() {
+                let a = core_simd::Mask::<$type, 4>::from_array([false, false, true, true]);
+                let b = core_simd::Mask::<$type, 4>::from_array([false, true, false, true]);
+                assert_eq!(a.symmetric_difference(b).to_array(), [false, true, true, false]);
+                assert_eq!(a.implies(b).to_array(), [true, true, false, true]);
+                assert_eq!(b.implies(a).to_array(), [true, false, true, true]);
+                assert_eq!(a.select_mask(b, !b).to_array(), [true, false, false, true]);
+            }
+
             #[test]
             fn roundtrip_int_conversion() {
                 let values = [true, false, false, true, false, false, true, false];
@@ -100,6 +121,17 @@ fn roundtrip_bitmask_conversion_short() {
                 assert_eq!(core_simd::Mask::<$type, 2>::from_bitmask(bitmask), mask);
             }
 
+            #[test]
+            fn roundtrip_bitmask_conversion_long() {
+                use core_simd::ToBitMask;
+
+                let bitmask = 0x8000_0001_dead_beefu64;
+                let values = core::array::from_fn(|i| bitmask & (1 << i) != 0);
+                let mask = core_simd::Mask::<$type, 64>::from_array(values);
+                assert_eq!(mask.to_bitmask(), bitmask);
+                assert_eq!(core_simd::Mask::<$type, 64>::from_bitmask(bitmask), mask);
+            }
+
             #[test]
             fn cast() {
                 fn cast_impl<T: core_simd::MaskElement>()
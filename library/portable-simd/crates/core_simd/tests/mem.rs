@@ -0,0 +1,212 @@
+#![feature(portable_simd)]
+use core_simd::{
+    mem,
+    mem::{AlignedSlice, PipelinedGather},
+    u32x8, usizex4,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fill() {
+    let mut buffer = vec![0u8; 400];
+    // Every length up to two vectors, at every alignment
+    for start in 0..64 {
+        for len in (0..=130).chain([200, 330]) {
+            buffer.fill(0);
+            mem::fill(&mut buffer[start..start + len], 0x5a);
+            for (i, x) in buffer.iter().enumerate() {
+                let expected = if (start..start + len).contains(&i) {
+                    0x5a
+                } else {
+                    0
+                };
+                assert_eq!(*x, expected, "start {start}, len {len}, index {i}");
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn copy() {
+    let src: Vec<u8> = (0..400).map(|x| (x * 7) as u8).collect();
+    let mut buffer = vec![0u8; 400];
+    // Every length up to two vectors, at every alignment of the destination and a different one
+    // of the source
+    for dst_start in 0..64 {
+        let src_start = (dst_start * 37 + 5) % 64;
+        for len in (0..=130).chain([200, 330]) {
+            buffer.fill(0);
+            let src = &src[src_start..src_start + len];
+            mem::copy(&mut buffer[dst_start..dst_start + len], src);
+            assert!(buffer[..dst_start].iter().all(|x| *x == 0));
+            assert_eq!(&buffer[dst_start..dst_start + len], src);
+            assert!(buffer[dst_start + len..].iter().all(|x| *x == 0));
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn large() {
+    // Large enough to use non-temporal stores
+    let len = (1 << 21) + 3;
+    let mut buffer = vec![0u8; len + 1];
+    mem::fill(&mut buffer[1..], 0xc3);
+    assert_eq!(buffer[0], 0);
+    assert!(buffer[1..].iter().all(|x| *x == 0xc3));
+
+    let src: Vec<u8> = (0..len).map(|x| (x % 251) as u8).collect();
+    mem::copy(&mut buffer[1..], &src);
+    assert_eq!(buffer[1..], src);
+}
+
+#[test]
+#[should_panic]
+fn copy_different_lengths() {
+    mem::copy(&mut [0; 10], &[0; 9]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn segments() {
+    use mem::Segment;
+
+    fn check<const N: usize, const ALIGN: usize>(bytes: &[u8])
+    where
+        core_simd::LaneCount<N>: core_simd::SupportedLaneCount,
+    {
+        let segments: Vec<Segment<'_, N>> = mem::segments::<N, ALIGN>(bytes).collect();
+        let mut joined = Vec::new();
+        let mut vectors = None;
+        for (i, segment) in segments.iter().enumerate() {
+            match segment {
+                Segment::Bytes(b) => {
+                    assert!(!b.is_empty());
+                    joined.extend_from_slice(b);
+                }
+                Segment::Vectors(v) => {
+                    assert!(!v.is_empty());
+                    assert_eq!(v.as_ptr() as usize % ALIGN.max(N), 0);
+                    joined.extend(v.iter().flat_map(|v| v.to_array()));
+                    vectors = Some(i);
+                }
+            }
+        }
+        assert_eq!(joined, bytes);
+
+        // Vectors are preceded by fewer than `ALIGN` bytes and followed by fewer than `N`, or
+        // there is no room for any
+        let shorter =
+            |len| move |s: &Segment<'_, N>| matches!(s, Segment::Bytes(b) if b.len() < len);
+        match vectors {
+            Some(i) => {
+                assert!(segments[..i].iter().all(shorter(ALIGN.max(N))));
+                assert!(segments[i + 1..].iter().all(shorter(N)));
+            }
+            None => assert!(bytes.len() < ALIGN.max(N) + N),
+        }
+    }
+
+    let buffer: Vec<u8> = (0..400).map(|x| x as u8).collect();
+    for start in 0..70 {
+        for len in [0, 1, 15, 16, 17, 63, 64, 65, 200, 330] {
+            let bytes = &buffer[start..start + len];
+            check::<16, 1>(bytes);
+            check::<16, 64>(bytes);
+            check::<4, 8>(bytes);
+            check::<64, 64>(bytes);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn segments_unaligned() {
+    let _ = mem::segments::<16, 3>(&[0; 10]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pipelined_gather() {
+    let table: Vec<u64> = (0..500).map(|x| x * 3 + 1).collect();
+    for count in [0, 1, 2, 3, 7, 50] {
+        // Some indices are out of bounds
+        let idxs: Vec<usizex4> = (0..count)
+            .map(|i| usizex4::from_array([i, i * 13 % 512, 511 - i % 20, i * 101 % 600]))
+            .collect();
+        let expected: Vec<[u64; 4]> = idxs
+            .iter()
+            .map(|idxs| idxs.to_array().map(|i| table.get(i).copied().unwrap_or(0)))
+            .collect();
+
+        let gathered: Vec<[u64; 4]> = PipelinedGather::<_, _, 4, 1>::new(&table, idxs.clone())
+            .map(|v| v.to_array())
+            .collect();
+        assert_eq!(gathered, expected);
+
+        let mut pipeline = PipelinedGather::<_, _, 4, 4>::new(&table, idxs.iter().copied());
+        assert_eq!(pipeline.size_hint(), (count, Some(count)));
+        let gathered: Vec<[u64; 4]> = pipeline.by_ref().map(|v| v.to_array()).collect();
+        assert_eq!(gathered, expected);
+        assert_eq!(pipeline.next(), None);
+    }
+}
+
+#[test]
+#[should_panic]
+fn pipelined_gather_zero_depth() {
+    let _ = PipelinedGather::<_, _, 4, 0>::new(&[0u8; 4], [usizex4::splat(0)]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn aligned_slice() {
+    let vectors: Vec<u32x8> = (0..5)
+        .map(|i| u32x8::from_array(core::array::from_fn(|j| i * 8 + j as u32)))
+        .collect();
+    let elements: Vec<u32> = (0..40).collect();
+    let slice = AlignedSlice::from_vectors(&vectors);
+    assert_eq!(slice.as_slice(), elements);
+
+    // Only offsets that are a multiple of the vector's alignment are accepted
+    for start in 0..16 {
+        let aligned = AlignedSlice::<u32, 8>::new(&slice[start..]);
+        assert_eq!(aligned.is_some(), start % 8 == 0);
+        if let Some(aligned) = aligned {
+            if aligned.len() >= 8 {
+                assert_eq!(
+                    u32x8::from_slice_aligned(aligned),
+                    u32x8::from_slice(&elements[start..])
+                );
+            }
+        }
+    }
+
+    let (head, tail) = slice.split_at(2);
+    assert_eq!(head.as_vectors(), (&vectors[..2], &[][..]));
+    assert_eq!(tail.as_vectors(), (&vectors[2..], &[][..]));
+    let partial = AlignedSlice::<u32, 8>::assert_aligned(&slice[..20]);
+    assert_eq!(partial.as_vectors(), (&vectors[..2], &elements[16..20]));
+}
+
+#[test]
+#[should_panic]
+fn aligned_slice_unaligned() {
+    let vectors = [u32x8::splat(0); 2];
+    let _ = AlignedSlice::<u32, 8>::assert_aligned(&AlignedSlice::from_vectors(&vectors)[1..]);
+}
+
+#[test]
+#[should_panic]
+fn from_slice_aligned_too_short() {
+    let vectors = [u32x8::splat(0); 2];
+    let slice = AlignedSlice::<u32, 8>::assert_aligned(&AlignedSlice::from_vectors(&vectors)[..4]);
+    let _ = u32x8::from_slice_aligned(slice);
+}
@@ -0,0 +1,105 @@
+#![feature(portable_simd)]
+use core_simd::net;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(len: usize) -> Vec<u64> {
+    Xorshift::new().take(len).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn parse_ipv4() {
+    let mut texts: Vec<String> = random(1000)
+        .into_iter()
+        .map(|x| Ipv4Addr::from((x as u32) >> ((x >> 32) % 32)).to_string())
+        .collect();
+    texts.extend(
+        [
+            "",
+            "1.2.3",
+            "1.2.3.4.",
+            ".1.2.3.4",
+            "1..2.3",
+            "1.2.3.4.5",
+            "256.0.0.0",
+            "0.0.0.0",
+            "255.255.255.255",
+            "1.2.3.1000",
+            "01.2.3.4",
+            "0.0.0.00",
+            "1.2.3.-4",
+            "1.2.3.4 ",
+            "1,2.3.4",
+            "1.2.3.a",
+            "999.999.999.999",
+            "1234.1.1.1",
+            "1.2.3.4\0",
+        ]
+        .map(String::from),
+    );
+    for text in texts {
+        let expected = text.parse::<Ipv4Addr>().ok().map(|addr| addr.octets());
+        assert_eq!(net::parse_ipv4(text.as_bytes()), expected, "{text:?}");
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn format_ipv4() {
+    let addrs: Vec<[u8; 4]> = random(1000)
+        .into_iter()
+        .map(|x| ((x as u32) >> ((x >> 32) % 32)).to_be_bytes())
+        .collect();
+    let mut buf = vec![0; addrs.len() * 16];
+    let len = net::format_ipv4(&mut buf, &addrs, b'\n');
+    let expected: Vec<String> = addrs
+        .iter()
+        .map(|a| Ipv4Addr::from(*a).to_string())
+        .collect();
+    assert_eq!(
+        std::str::from_utf8(&buf[..len]).unwrap(),
+        expected.join("\n")
+    );
+    assert_eq!(net::format_ipv4(&mut [], &[], b'\n'), 0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn format_ipv6() {
+    // Sparse groups, to cover runs of zeros
+    let mut addrs: Vec<[u16; 8]> = random(2000)
+        .chunks_exact(2)
+        .map(|x| {
+            let mut addr = [0; 8];
+            for (i, group) in addr.iter_mut().enumerate() {
+                if x[0] >> i & 1 != 0 {
+                    *group = (x[1] >> (i * 8)) as u16 >> (x[1] >> 60);
+                }
+            }
+            addr
+        })
+        .collect();
+    addrs.extend([[0; 8], [0xffff; 8], [1, 0, 1, 0, 1, 0, 1, 0]]);
+    // IPv4-compatible and IPv4-mapped addresses are formatted differently by the standard library
+    addrs.retain(|a| {
+        a[..5] != [0; 5] || (a[5] != 0 && a[5] != 0xffff) || a[6..] == [0, 1] || a[6..] == [0, 0]
+    });
+
+    let mut buf = vec![0; addrs.len() * 40];
+    let len = net::format_ipv6(&mut buf, &addrs, b'\n');
+    let expected: Vec<String> = addrs
+        .iter()
+        .map(|a| Ipv6Addr::from(*a).to_string())
+        .collect();
+    assert_eq!(
+        std::str::from_utf8(&buf[..len]).unwrap(),
+        expected.join("\n")
+    );
+}
@@ -0,0 +1,104 @@
+#![feature(portable_simd)]
+use core_simd::nn::{self, ExpSum};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn logits(len: usize, range: f32) -> Vec<f32> {
+    let mut rng = Xorshift::new();
+    (0..len).map(|_| (rng.next_f32() - 0.5) * range).collect()
+}
+
+fn softmax_scalar(input: &[f32]) -> Vec<f64> {
+    let max = input.iter().copied().fold(f32::NEG_INFINITY, f32::max) as f64;
+    let sum: f64 = input.iter().map(|x| (*x as f64 - max).exp()).sum();
+    input
+        .iter()
+        .map(|x| (*x as f64 - max).exp() / sum)
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn softmax() {
+    for range in [1.0, 20.0, 200.0, 1e6] {
+        for len in (1..40).chain([100, 1000]) {
+            let input = logits(len, range);
+            let mut output = vec![0.0; len];
+            nn::softmax(&input, &mut output);
+            for (output, expected) in output.iter().zip(softmax_scalar(&input)) {
+                let error = (*output as f64 - expected).abs();
+                assert!(
+                    error <= expected * 1e-5 + 1e-30,
+                    "{output} != {expected} for length {len} and range {range}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn exp_sum_streaming() {
+    let input = logits(1000, 50.0);
+    let mut whole = ExpSum::new();
+    whole.update(&input);
+    for split in [1, 15, 16, 17, 333] {
+        let mut streamed = ExpSum::default();
+        for chunk in input.chunks(split) {
+            streamed.update(chunk);
+        }
+        streamed.update(&[]);
+        assert_eq!(streamed.max(), whole.max());
+        assert!((streamed.sum() - whole.sum()).abs() <= whole.sum() * 1e-5);
+    }
+
+    // Increasing input rescales the earlier sums
+    let mut increasing = ExpSum::new();
+    for x in 0..100 {
+        increasing.update(&[x as f32]);
+    }
+    assert_eq!(increasing.max(), 99.0);
+    let expected = 1.0 / (1.0 - (-1f64).exp());
+    assert!((increasing.sum() as f64 - expected).abs() < 1e-5);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn softmax_extremes() {
+    let input = [-1e30, f32::NEG_INFINITY, 1e30, 1e30, 88.0, -88.0];
+    let mut output = [1.0; 6];
+    nn::softmax(&input, &mut output);
+    assert_eq!(output, [0.0, 0.0, 0.5, 0.5, 0.0, 0.0]);
+
+    let mut empty = [];
+    nn::softmax(&[], &mut empty);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn softmax_nan() {
+    for len in [1, 5, 16, 17, 40] {
+        for i in [0, len / 2, len - 1] {
+            let mut input = logits(len, 20.0);
+            input[i] = f32::NAN;
+            let mut exp_sum = ExpSum::new();
+            exp_sum.update(&input);
+            assert!(exp_sum.sum().is_nan());
+
+            let mut output = vec![0.0; len];
+            nn::softmax(&input, &mut output);
+            assert!(output.iter().all(|x| x.is_nan()), "{output:?}");
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn softmax_length_mismatch() {
+    nn::softmax(&[1.0, 2.0], &mut [0.0; 3]);
+}
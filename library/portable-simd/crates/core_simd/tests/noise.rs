@@ -0,0 +1,66 @@
+#![feature(portable_simd)]
+use core_simd::{f32x8, noise};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Evaluates `f` on a grid of points around `origin`, returning the noise in row-major order.
+fn grid(f: fn(f32x8, f32x8) -> f32x8, origin: f32, step: f32) -> Vec<f32> {
+    let mut noise = Vec::new();
+    for row in 0..256 {
+        for column in (0..256).step_by(8) {
+            let x = f32x8::from_array([0, 1, 2, 3, 4, 5, 6, 7].map(|i| (column + i) as f32));
+            let x = f32x8::splat(origin) + x * f32x8::splat(step);
+            let y = f32x8::splat(origin + row as f32 * step);
+            noise.extend_from_slice(&f(x, y).to_array());
+        }
+    }
+    noise
+}
+
+fn check(f: fn(f32x8, f32x8) -> f32x8) {
+    for origin in [0.0, -37.3, 1000.5] {
+        let noise = grid(f, origin, 0.01);
+        // Bounded, but not flat
+        assert!(noise.iter().all(|n| (-1.0..=1.0).contains(n)));
+        let (min, max) = noise.iter().fold((1.0f32, -1.0f32), |(min, max), n| {
+            (min.min(*n), max.max(*n))
+        });
+        assert!(min < -0.3 && max > 0.3, "range {min}..{max}");
+
+        // Continuous, with a slope of less than 10
+        for row in noise.chunks(256) {
+            for pair in row.windows(2) {
+                assert!((pair[1] - pair[0]).abs() < 0.1, "{pair:?}");
+            }
+        }
+        for rows in noise.chunks(256).collect::<Vec<_>>().windows(2) {
+            for (a, b) in rows[0].iter().zip(rows[1]) {
+                assert!((a - b).abs() < 0.1, "{a} {b}");
+            }
+        }
+
+        // Deterministic
+        assert_eq!(noise, grid(f, origin, 0.01));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn perlin2d() {
+    check(noise::perlin2d);
+
+    // Zero at lattice points, including negative ones
+    let x = f32x8::from_array([0.0, 1.0, -1.0, 5.0, -17.0, 3.0, 0.0, -2.0]);
+    let y = f32x8::from_array([0.0, 0.0, 3.0, -4.0, 8.0, -9.0, -1.0, 2.0]);
+    assert_eq!(noise::perlin2d(x, y), f32x8::splat(0.0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn simplex2d() {
+    check(noise::simplex2d);
+}
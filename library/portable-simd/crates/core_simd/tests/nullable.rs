@@ -0,0 +1,84 @@
+#![feature(portable_simd)]
+use core_simd::{nullable, Simd};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(len: usize, seed: u64) -> Vec<u8> {
+    Xorshift::with_seed(seed)
+        .take(len)
+        .map(|x| x as u8)
+        .collect()
+}
+
+fn is_valid(validity: &[u8], i: usize) -> bool {
+    validity[i / 8] >> (i % 8) & 1 != 0
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn binary() {
+    for len in [0, 1, 7, 8, 9, 100] {
+        let a: Vec<i32> = random(len, 1).into_iter().map(i32::from).collect();
+        let b: Vec<i32> = random(len, 2)
+            .into_iter()
+            .map(|x| i32::from(x % 4) + 1)
+            .collect();
+        // Bitmaps may be longer than needed
+        let bytes = len / 8 + 1;
+        let (a_validity, b_validity) = (random(bytes, 3), random(bytes, 4));
+
+        let mut out = vec![-1; len];
+        let mut out_validity = vec![0xff; bytes];
+        nullable::binary(
+            &a,
+            &a_validity,
+            &b,
+            &b_validity,
+            &mut out,
+            &mut out_validity,
+            |a, b, valid| a / valid.select(b, Simd::splat(1)),
+        );
+
+        for i in 0..len {
+            let valid = is_valid(&a_validity, i) && is_valid(&b_validity, i);
+            assert_eq!(is_valid(&out_validity, i), valid);
+            assert_eq!(out[i], if valid { a[i] / b[i] } else { 0 });
+        }
+        // Bits past the end are cleared
+        if len % 8 != 0 {
+            assert_eq!(out_validity[len / 8] >> (len % 8), 0);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unary() {
+    let values: Vec<f32> = random(20, 1).into_iter().map(f32::from).collect();
+    let validity = [0xff, 0x0f, 0xf0];
+    let mut out = vec![1.0; 20];
+    let mut out_validity = [0; 3];
+    nullable::unary(&values, &validity, &mut out, &mut out_validity, |x, _| {
+        x * Simd::splat(0.5)
+    });
+    assert_eq!(out_validity, [0xff, 0x0f, 0x00]);
+    for i in 0..20 {
+        let expected = if is_valid(&validity, i) {
+            values[i] * 0.5
+        } else {
+            0.0
+        };
+        assert_eq!(out[i], expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn short_validity() {
+    nullable::unary(&[1; 9], &[0xff], &mut [0; 9], &mut [0; 2], |x, _| x);
+}
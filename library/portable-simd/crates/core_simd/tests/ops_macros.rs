@@ -94,6 +94,15 @@ fn assign<const LANES: usize>() {
 macro_rules! impl_common_integer_tests {
     { $vector:ident, $scalar:ident } => {
         test_helpers::test_lanes! {
+            fn splat<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    test_helpers::prop_assert_biteq!($vector::<LANES>::splat(x[0]).to_array(), [x[0]; LANES]);
+                    test_helpers::prop_assert_biteq!($vector::<LANES>::splat_ref(&x[0]).to_array(), [x[0]; LANES]);
+                    test_helpers::prop_assert_biteq!($vector::<LANES>::default().to_array(), [0; LANES]);
+                    Ok(())
+                });
+            }
+
             fn reduce_sum<const LANES: usize>() {
                 test_helpers::test_1(&|x| {
                     test_helpers::prop_assert_biteq! (
@@ -144,6 +153,31 @@ fn reduce_xor<const LANES: usize>() {
                 });
             }
 
+            fn all_zeros<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    let v = $vector::<LANES>::from_array(x);
+                    test_helpers::prop_assert_biteq!(v.all_zeros(), x.iter().all(|x| *x == 0));
+                    test_helpers::prop_assert_biteq!(v.any_nonzero(), x.iter().any(|x| *x != 0));
+                    test_helpers::prop_assert_biteq!(v.all_bits_set(), x.iter().all(|x| *x == !0));
+                    Ok(())
+                });
+            }
+
+            fn all_zeros_one_lane<const LANES: usize>() {
+                let zeros = $vector::<LANES>::splat(0);
+                let ones = $vector::<LANES>::splat(!0);
+                assert!(zeros.all_zeros() && !zeros.any_nonzero() && !zeros.all_bits_set());
+                assert!(!ones.all_zeros() && ones.any_nonzero() && ones.all_bits_set());
+                for i in 0..LANES {
+                    let mut x = zeros;
+                    x[i] = 1 << (i % <$scalar>::BITS as usize);
+                    assert!(!x.all_zeros() && x.any_nonzero());
+                    let mut x = ones;
+                    x[i] ^= 1 << (i % <$scalar>::BITS as usize);
+                    assert!(!x.all_bits_set() && x.any_nonzero());
+                }
+            }
+
             fn reduce_max<const LANES: usize>() {
                 test_helpers::test_1(&|x| {
                     test_helpers::prop_assert_biteq! (
@@ -163,6 +197,158 @@ fn reduce_min<const LANES: usize>() {
                     Ok(())
                 });
             }
+
+            fn leading_zeros<const LANES: usize>() {
+                test_helpers::test_unary_elementwise(
+                    &$vector::<LANES>::leading_zeros,
+                    &|x| x.leading_zeros() as $scalar,
+                    &|_| true,
+                );
+            }
+
+            fn ilog2<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    let x = x.map(|x| x.max(1));
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).ilog2().to_array(),
+                        x.map(|x| x.ilog2() as $scalar),
+                    );
+                    Ok(())
+                });
+            }
+
+            fn ilog10<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    let x = x.map(|x| x.max(1));
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).ilog10().to_array(),
+                        x.map(|x| x.ilog10() as $scalar),
+                    );
+                    Ok(())
+                });
+            }
+
+            fn ilog10_powers_of_ten<const LANES: usize>() {
+                let mut power: $scalar = 10;
+                let mut log: $scalar = 1;
+                loop {
+                    let v = $vector::<LANES>::splat(power);
+                    assert_eq!(v.ilog10(), $vector::<LANES>::splat(log));
+                    assert_eq!((v - $vector::<LANES>::splat(1)).ilog10(), $vector::<LANES>::splat(log - 1));
+                    match power.checked_mul(10) {
+                        Some(next) => power = next,
+                        None => break,
+                    }
+                    log += 1;
+                }
+            }
+
+            fn reverse_bytes_in_lanes<const LANES: usize>() {
+                test_helpers::test_unary_elementwise(
+                    &$vector::<LANES>::reverse_bytes_in_lanes,
+                    &<$scalar>::swap_bytes,
+                    &|_| true,
+                );
+            }
+
+            fn reverse_bits_in_bytes<const LANES: usize>() {
+                test_helpers::test_unary_elementwise(
+                    &$vector::<LANES>::reverse_bits_in_bytes,
+                    &|x: $scalar| x.reverse_bits().swap_bytes(),
+                    &|_| true,
+                );
+            }
+
+            fn reverse_bytes_whole<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    let mut expected = x.map(<$scalar>::swap_bytes);
+                    expected.reverse();
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).reverse_bytes_whole().to_array(),
+                        expected,
+                    );
+                    Ok(())
+                });
+            }
+
+            fn wrapping_shl<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::wrapping_shl,
+                    &|x: $scalar, n: $scalar| x.wrapping_shl(n as u32),
+                    &|_, _| true,
+                );
+            }
+
+            fn wrapping_shr<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::wrapping_shr,
+                    &|x: $scalar, n: $scalar| x.wrapping_shr(n as u32),
+                    &|_, _| true,
+                );
+            }
+
+            fn saturating_shl<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], n: [$scalar; LANES]| {
+                    // Mostly in range, and some amounts just past it
+                    let n = n.map(|n| n.rem_euclid(<$scalar>::BITS as $scalar + 2));
+                    let expected = core::array::from_fn::<$scalar, LANES, _>(|i| {
+                        match x[i].checked_shl(n[i] as u32) {
+                            Some(shifted) if shifted >> n[i] == x[i] => shifted,
+                            _ if x[i] == 0 => 0,
+                            _ if x[i] < 0 as $scalar => <$scalar>::MIN,
+                            _ => <$scalar>::MAX,
+                        }
+                    });
+                    let shifted = $vector::<LANES>::from_array(x)
+                        .saturating_shl($vector::<LANES>::from_array(n));
+                    test_helpers::prop_assert_biteq!(shifted.to_array(), expected);
+                    Ok(())
+                });
+            }
+
+            fn cmp<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    // Also compare vectors that differ only in the last lane
+                    let mut z = x;
+                    z[LANES - 1] = y[LANES - 1];
+                    for (a, b) in [(x, y), (x, z), (x, x)] {
+                        let va = $vector::<LANES>::from_array(a);
+                        let vb = $vector::<LANES>::from_array(b);
+                        test_helpers::prop_assert_biteq!(va == vb, a == b);
+                        test_helpers::prop_assert_biteq!(va.cmp(&vb) as i8, a.cmp(&b) as i8);
+                    }
+                    Ok(())
+                });
+            }
+
+            fn lanes_cmp<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (vx, vy) = ($vector::<LANES>::from_array(x), $vector::<LANES>::from_array(y));
+                    for i in 0..LANES {
+                        test_helpers::prop_assert_biteq!(vx.lanes_eq(vy).test(i), x[i] == y[i]);
+                        test_helpers::prop_assert_biteq!(vx.lanes_ne(vy).test(i), x[i] != y[i]);
+                        test_helpers::prop_assert_biteq!(vx.lanes_lt(vy).test(i), x[i] < y[i]);
+                    }
+                    let first = (0..LANES).find(|&i| x[i] != y[i]);
+                    assert_eq!(vx.first_ne(vy), first);
+                    assert_eq!(vx.first_ne(vx), None);
+                    Ok(())
+                });
+            }
+        }
+
+        test_helpers::test_lanes_panic! {
+            fn ilog2_zero_panics<const LANES: usize>() {
+                let mut x = $vector::<LANES>::splat(1);
+                x[0] = 0;
+                let _ = x.ilog2();
+            }
+
+            fn ilog10_zero_panics<const LANES: usize>() {
+                let mut x = $vector::<LANES>::splat(1);
+                x[0] = 0;
+                let _ = x.ilog10();
+            }
         }
     }
 }
@@ -276,6 +462,18 @@ fn rem_zero_panic<const LANES: usize>() {
                     let b = Vector::<LANES>::splat(0);
                     let _ = a % b;
                 }
+
+                fn ilog2_negative_panics<const LANES: usize>() {
+                    let mut x = Vector::<LANES>::splat(1);
+                    x[0] = -1;
+                    let _ = x.ilog2();
+                }
+
+                fn ilog10_negative_panics<const LANES: usize>() {
+                    let mut x = Vector::<LANES>::splat(1);
+                    x[0] = -1;
+                    let _ = x.ilog10();
+                }
             }
 
             test_helpers::test_lanes! {
@@ -319,6 +517,108 @@ mod $scalar {
 
             impl_common_integer_tests! { Vector, Scalar }
 
+            test_helpers::test_lanes! {
+                fn count_ones<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::count_ones,
+                        &|x| x.count_ones() as Scalar,
+                        &|_| true,
+                    );
+                }
+
+                fn next_power_of_two<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::next_power_of_two,
+                        &|x| x.checked_next_power_of_two().unwrap_or(0),
+                        &|_| true,
+                    );
+                }
+
+                fn checked_next_power_of_two<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let (pow, overflow) = Vector::<LANES>::from_array(x).checked_next_power_of_two();
+                        test_helpers::prop_assert_biteq!(
+                            pow.to_array(),
+                            x.map(|x| x.checked_next_power_of_two().unwrap_or(0)),
+                        );
+                        proptest::prop_assert_eq!(
+                            overflow.to_array(),
+                            x.map(|x| x.checked_next_power_of_two().is_none()),
+                        );
+                        Ok(())
+                    });
+                }
+
+                fn is_power_of_two<const LANES: usize>() {
+                    test_helpers::test_unary_mask_elementwise(
+                        &Vector::<LANES>::is_power_of_two,
+                        &Scalar::is_power_of_two,
+                        &|_| true,
+                    );
+                }
+
+                fn bit_deposit<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::bit_deposit,
+                        &|x: Scalar, mask: Scalar| {
+                            let mut deposited = 0;
+                            let mut next = 0;
+                            for i in 0..Scalar::BITS {
+                                if mask & (1 << i) != 0 {
+                                    deposited |= ((x >> next) & 1) << i;
+                                    next += 1;
+                                }
+                            }
+                            deposited
+                        },
+                        &|_, _| true,
+                    );
+                }
+
+                fn bit_extract<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::bit_extract,
+                        &|x: Scalar, mask: Scalar| {
+                            let mut extracted = 0;
+                            let mut next = 0;
+                            for i in 0..Scalar::BITS {
+                                if mask & (1 << i) != 0 {
+                                    extracted |= ((x >> i) & 1) << next;
+                                    next += 1;
+                                }
+                            }
+                            extracted
+                        },
+                        &|_, _| true,
+                    );
+                }
+
+                fn bit_extract_inverts_deposit<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], mask: [Scalar; LANES]| {
+                        let x = Vector::<LANES>::from_array(x);
+                        let mask = Vector::<LANES>::from_array(mask);
+                        let deposited = x.bit_deposit(mask);
+                        test_helpers::prop_assert_biteq!((deposited & !mask).to_array(), [0; LANES]);
+                        test_helpers::prop_assert_biteq!(
+                            deposited.bit_extract(mask).bit_deposit(mask).to_array(),
+                            deposited.to_array(),
+                        );
+                        Ok(())
+                    });
+                }
+
+                fn is_power_of_two_all_powers<const LANES: usize>() {
+                    for shift in 0..Scalar::BITS {
+                        let x = Vector::<LANES>::splat(1 << shift);
+                        assert!(x.is_power_of_two().all());
+                        assert_eq!(
+                            (x + Vector::<LANES>::splat(1)).is_power_of_two().any(),
+                            shift == 0,
+                        );
+                    }
+                }
+            }
+
             test_helpers::test_lanes_panic! {
                 fn rem_zero_panic<const LANES: usize>() {
                     let a = Vector::<LANES>::splat(42);
@@ -359,7 +659,33 @@ mod $scalar {
             impl_binary_op_test!(Scalar, Div::div, DivAssign::div_assign);
             impl_binary_op_test!(Scalar, Rem::rem, RemAssign::rem_assign);
 
+            /// The scalar `next_up`, from the bits, as the method is unstable on some toolchains
+            /// and stable on others.
+            fn next_up(x: Scalar) -> Scalar {
+                if x.is_nan() || x == Scalar::INFINITY {
+                    x
+                } else if x == 0.0 {
+                    Scalar::from_bits(1)
+                } else if x > 0.0 {
+                    Scalar::from_bits(x.to_bits() + 1)
+                } else {
+                    Scalar::from_bits(x.to_bits() - 1)
+                }
+            }
+
+            fn next_down(x: Scalar) -> Scalar {
+                -next_up(-x)
+            }
+
             test_helpers::test_lanes! {
+                fn splat<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        test_helpers::prop_assert_biteq!(Vector::<LANES>::splat(x[0]).to_array(), [x[0]; LANES]);
+                        test_helpers::prop_assert_biteq!(Vector::<LANES>::splat_ref(&x[0]).to_array(), [x[0]; LANES]);
+                        Ok(())
+                    });
+                }
+
                 fn is_sign_positive<const LANES: usize>() {
                     test_helpers::test_unary_mask_elementwise(
                         &Vector::<LANES>::is_sign_positive,
@@ -392,6 +718,106 @@ fn is_infinite<const LANES: usize>() {
                     );
                 }
 
+                fn negate_where<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let mask = Vector::<LANES>::from_array(y).is_sign_negative();
+                        let negated = Vector::<LANES>::from_array(x).negate_where(mask);
+                        let expected = core::array::from_fn(|i| if y[i].is_sign_negative() { -x[i] } else { x[i] });
+                        test_helpers::prop_assert_biteq!(negated.to_array(), expected);
+                        Ok(())
+                    });
+                }
+
+                fn copysign_from_mask<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let mask = Vector::<LANES>::from_array(y).is_sign_negative();
+                        let signed = Vector::<LANES>::from_array(x).copysign_from_mask(mask);
+                        let expected = core::array::from_fn(|i| x[i].copysign(y[i]));
+                        test_helpers::prop_assert_biteq!(signed.to_array(), expected);
+                        Ok(())
+                    });
+                }
+
+                fn clamp_unit<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::clamp_unit,
+                        &|x| x.clamp(-1.0, 1.0),
+                        &|_| true,
+                    )
+                }
+
+                fn clamp_unit_nan_to_zero<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::clamp_unit_nan_to_zero,
+                        &|x| if x.is_nan() { 0.0 } else { x.clamp(-1.0, 1.0) },
+                        &|_| true,
+                    )
+                }
+
+                fn saturate<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::saturate,
+                        &|x| x.clamp(0.0, 1.0),
+                        &|_| true,
+                    )
+                }
+
+                fn saturate_nan_to_zero<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::saturate_nan_to_zero,
+                        &|x| if x > 0.0 { x.min(1.0) } else { 0.0 },
+                        &|_| true,
+                    )
+                }
+
+                fn next_up<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::next_up,
+                        &next_up,
+                        &|_| true,
+                    )
+                }
+
+                fn next_down<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::next_down,
+                        &next_down,
+                        &|_| true,
+                    )
+                }
+
+                fn partial_cmp<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        // Also compare vectors that differ only in the last lane
+                        let mut z = x;
+                        z[LANES - 1] = y[LANES - 1];
+                        for (a, b) in [(x, y), (x, z), (x, x)] {
+                            let va = Vector::<LANES>::from_array(a);
+                            let vb = Vector::<LANES>::from_array(b);
+                            test_helpers::prop_assert_biteq!(va == vb, a == b);
+                            test_helpers::prop_assert_biteq!(
+                                va.partial_cmp(&vb).map_or(2, |o| o as i8),
+                                a.partial_cmp(&b).map_or(2, |o| o as i8),
+                            );
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn lanes_cmp<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let (vx, vy) = (Vector::<LANES>::from_array(x), Vector::<LANES>::from_array(y));
+                        for i in 0..LANES {
+                            test_helpers::prop_assert_biteq!(vx.lanes_eq(vy).test(i), x[i] == y[i]);
+                            test_helpers::prop_assert_biteq!(vx.lanes_ne(vy).test(i), x[i] != y[i]);
+                            test_helpers::prop_assert_biteq!(vx.lanes_lt(vy).test(i), x[i] < y[i]);
+                        }
+                        let first = (0..LANES).find(|&i| x[i] != y[i]);
+                        assert_eq!(vx.first_ne(vy), first);
+                        Ok(())
+                    });
+                }
+
                 fn is_nan<const LANES: usize>() {
                     test_helpers::test_unary_mask_elementwise(
                         &Vector::<LANES>::is_nan,
@@ -554,6 +980,51 @@ fn reduce_product<const LANES: usize>() {
                     });
                 }
 
+                fn arith_fast<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        // Small integers, so every result is finite and exact, and powers of two
+                        // to divide by
+                        let x = x.map(|x| if x.is_finite() { (x % 1000.).trunc() } else { 1. });
+                        let y = y.map(|y| if y.is_finite() { (y % 1000.).trunc() } else { 1. });
+                        let d = y.map(|y| [0.5, -1., 2., 1.][(y.to_bits() % 4) as usize]);
+                        let (vx, vy, vd) = (Vector::from_array(x), Vector::from_array(y), Vector::from_array(d));
+                        // Safety: every lane and result is finite
+                        unsafe {
+                            test_helpers::prop_assert_biteq!(vx.add_fast(vy).to_array(), (vx + vy).to_array());
+                            test_helpers::prop_assert_biteq!(vx.sub_fast(vy).to_array(), (vx - vy).to_array());
+                            test_helpers::prop_assert_biteq!(vx.mul_fast(vy).to_array(), (vx * vy).to_array());
+                            test_helpers::prop_assert_biteq!(vx.div_fast(vd).to_array(), (vx / vd).to_array());
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn reduce_sum_fast<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        // Small integers, so every order of addition is exact
+                        let x = x.map(|x| if x.is_finite() { (x % 1000.).trunc() } else { x });
+                        let sum = Vector::<LANES>::from_array(x).reduce_sum_fast();
+                        if x.iter().any(|x| x.is_nan()) || x.contains(&Scalar::INFINITY) && x.contains(&Scalar::NEG_INFINITY) {
+                            test_helpers::prop_assert_biteq!(sum.is_nan(), true);
+                        } else {
+                            test_helpers::prop_assert_biteq!(sum, x.iter().fold(-0., |sum, x| sum + x));
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn reduce_product_fast<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        // Powers of two, so every order of multiplication is exact
+                        let x = x.map(|x| [0.5, -1., 2., 1.][(x.to_bits() % 4) as usize]);
+                        test_helpers::prop_assert_biteq!(
+                            Vector::<LANES>::from_array(x).reduce_product_fast(),
+                            x.iter().product::<Scalar>(),
+                        );
+                        Ok(())
+                    });
+                }
+
                 fn reduce_max<const LANES: usize>() {
                     test_helpers::test_1(&|x| {
                         let vmax = Vector::<LANES>::from_array(x).reduce_max();
@@ -0,0 +1,119 @@
+#![feature(portable_simd)]
+use core_simd::{Mask, Simd, SimdConstPtr, SimdMutPtr};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn const_ptr_arithmetic() {
+    let values = [0u64; 8];
+    let base = values.as_ptr();
+    let ptrs = Simd::<*const u64, 4>::splat(base).wrapping_add(Simd::from_array([0, 1, 5, 7]));
+    assert_eq!(
+        ptrs.addr() - Simd::splat(base as usize),
+        Simd::from_array([0, 8, 40, 56])
+    );
+    assert_eq!(
+        ptrs.wrapping_sub(Simd::splat(1))
+            .wrapping_add(Simd::splat(1)),
+        ptrs
+    );
+    assert_eq!(
+        ptrs.wrapping_offset(Simd::from_array([0, -1, -5, -7])),
+        Simd::splat(base)
+    );
+    assert_eq!(
+        ptrs.with_addr(Simd::splat(base as usize)),
+        Simd::splat(base)
+    );
+    assert_eq!(
+        <Simd<*const u64, 4>>::from_exposed_addr(ptrs.expose_addr()),
+        ptrs
+    );
+    assert_eq!(ptrs.cast_mut().cast_const(), ptrs);
+    assert_eq!(ptrs.is_null(), Mask::splat(false));
+    assert_ne!(ptrs, Simd::splat(base));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mut_ptr_arithmetic() {
+    let mut values = [0u8; 8];
+    let base = values.as_mut_ptr();
+    let ptrs = Simd::<*mut u8, 4>::splat(base).wrapping_add(Simd::from_array([0, 1, 5, 7]));
+    assert_eq!(
+        ptrs.addr() - Simd::splat(base as usize),
+        Simd::from_array([0, 1, 5, 7])
+    );
+    assert_eq!(
+        ptrs.wrapping_sub(Simd::splat(1))
+            .wrapping_add(Simd::splat(1)),
+        ptrs
+    );
+    assert_eq!(
+        ptrs.with_addr(Simd::splat(base as usize)),
+        Simd::splat(base)
+    );
+    assert_eq!(
+        <Simd<*mut u8, 4>>::from_exposed_addr(ptrs.expose_addr()),
+        ptrs
+    );
+    assert_eq!(ptrs.cast_const().cast_mut(), ptrs);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn is_null() {
+    let x = 1i32;
+    let ptrs = Simd::from_array([&x as *const i32, core::ptr::null(), &x, core::ptr::null()]);
+    assert_eq!(ptrs.is_null().to_array(), [false, true, false, true]);
+    assert_eq!(
+        ptrs.cast_mut().is_null().to_array(),
+        [false, true, false, true]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gather_scatter_ptr() {
+    // Follow a linked list stored in an array, a lane per list
+    let next = [3usize, 0, 1, 2, 5, 4, 7, 6];
+    let mut ptrs =
+        Simd::<*const usize, 4>::splat(next.as_ptr()).wrapping_add(Simd::from_array([0, 2, 4, 6]));
+    for _ in 0..2 {
+        // Safety: every index in `next` is in bounds
+        let indices = unsafe { Simd::gather_ptr(ptrs) };
+        ptrs = Simd::splat(next.as_ptr()).wrapping_add(indices);
+    }
+    let offsets = ptrs.addr() - Simd::splat(next.as_ptr() as usize);
+    assert_eq!(
+        offsets / Simd::splat(core::mem::size_of::<usize>()),
+        Simd::from_array([2, 0, 4, 6])
+    );
+
+    let mut values = [0i16; 4];
+    let dest = Simd::splat(values.as_mut_ptr()).wrapping_add(Simd::from_array([1, 2, 3, 0]));
+    let enable = Mask::from_array([true, false, true, true]);
+    // Safety: every pointer is within `values`
+    unsafe { Simd::from_array([1, 2, 3, 4]).scatter_select_ptr(dest, enable) };
+    assert_eq!(values, [4, 1, 0, 3]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn compare_addresses() {
+    let values = [0u32; 4];
+    let base = values.as_ptr();
+    let a = Simd::<*const u32, 4>::splat(base).wrapping_add(Simd::from_array([0, 1, 2, 3]));
+    let b = Simd::<*const u32, 4>::splat(base).wrapping_add(Simd::from_array([0, 2, 1, 3]));
+    assert_eq!(a.lanes_eq(b).to_array(), [true, false, false, true]);
+    assert_eq!(a.lanes_ne(b).to_array(), [false, true, true, false]);
+    assert_eq!(a.lanes_lt(b).to_array(), [false, true, false, false]);
+    assert_eq!(a.first_ne(b), Some(1));
+    assert!(a < b);
+    assert_eq!(a.cast_mut().cmp(&b.cast_mut()), core::cmp::Ordering::Less);
+}
@@ -0,0 +1,74 @@
+#![feature(portable_simd)]
+use core_simd::{f32x8, i8x8, quant};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn round_ties_even(x: f32) -> f32 {
+    let rounded = x.round();
+    if (x - x.trunc()).abs() == 0.5 {
+        2.0 * (x / 2.0).round()
+    } else {
+        rounded
+    }
+}
+
+fn quantize_scalar(value: f32, scale: f32, zero_point: i8) -> i8 {
+    if value.is_nan() {
+        return zero_point;
+    }
+    (round_ties_even(value / scale) + zero_point as f32).clamp(-128.0, 127.0) as i8
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn quantize() {
+    for (scale, zero_point) in [(1.0, 0), (0.5, 3), (0.0173, -128), (3.0, 127), (-0.25, 10)] {
+        let values: Vec<f32> = (-2000..2000).map(|i| i as f32 * 0.125).collect();
+        for chunk in values.chunks_exact(8) {
+            let quantized = quant::quantize(f32x8::from_slice(chunk), scale, zero_point);
+            for (value, quantized) in chunk.iter().zip(quantized.to_array()) {
+                assert_eq!(
+                    quantized,
+                    quantize_scalar(*value, scale, zero_point),
+                    "{value} with scale {scale} and zero point {zero_point}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn quantize_ties_and_specials() {
+    let values = f32x8::from_array([0.5, 1.5, 2.5, -0.5, -1.5, f32::NAN, f32::INFINITY, -1e30]);
+    assert_eq!(
+        quant::quantize(values, 1.0, 1).to_array(),
+        [1, 3, 3, 1, -1, 1, 127, -128]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn dequantize() {
+    let all: Vec<i8> = (i8::MIN..=i8::MAX).collect();
+    for (scale, zero_point) in [(1.0, 0), (0.5, 3), (0.0173, -128), (3.0, 127)] {
+        for chunk in all.chunks_exact(8) {
+            let dequantized = quant::dequantize(i8x8::from_slice(chunk), scale, zero_point);
+            for (quantized, dequantized) in chunk.iter().zip(dequantized.to_array()) {
+                assert_eq!(
+                    dequantized,
+                    (*quantized as i32 - zero_point as i32) as f32 * scale
+                );
+            }
+            // Dequantizing is the inverse of quantizing
+            assert_eq!(
+                quant::quantize(dequantized, scale, zero_point),
+                i8x8::from_slice(chunk)
+            );
+        }
+    }
+}
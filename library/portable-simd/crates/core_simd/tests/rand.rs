@@ -0,0 +1,190 @@
+#![feature(portable_simd)]
+use core_simd::{
+    f32x16, f32x4, f32x8,
+    rand::{pick_by_cumulative_weight, pick_by_threshold, SimdRng},
+    u32x8, u8x16, Simd,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn matches_scalar() {
+    let mut scalar = 0x2545_f491_4f6c_dd1d;
+    let mut rng = SimdRng::<4>::new(scalar);
+    for _ in 0..10 {
+        let expected: [u64; 4] = core::array::from_fn(|_| splitmix64(&mut scalar));
+        assert_eq!(rng.next_u64().to_array(), expected);
+    }
+    let mut narrow = SimdRng::<1>::new(3);
+    let expected: [u64; 8] = core::array::from_fn(|_| narrow.next_u64()[0]);
+    assert_eq!(SimdRng::<8>::new(3).next_u64().to_array(), expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn below() {
+    let mut rng = SimdRng::<8>::new(1);
+    let bound = u32x8::from_array([0, 1, 2, 3, 10, 1000, 1 << 31, u32::MAX]);
+    let mut counts = [0; 10];
+    for _ in 0..1000 {
+        let x = rng.below(bound);
+        for (x, bound) in x.to_array().into_iter().zip(bound.to_array()) {
+            assert!(x < bound.max(1), "{x} {bound}");
+        }
+        counts[x[4] as usize] += 1;
+    }
+    assert!(
+        counts.iter().all(|&count| (50..150).contains(&count)),
+        "{counts:?}"
+    );
+}
+
+/// Every permutation of each group should be about equally likely.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn permutation_uniform() {
+    let mut rng = SimdRng::<16>::new(2);
+    let mut counts = std::collections::HashMap::new();
+    for _ in 0..1000 {
+        let idxs = rng.permutation::<4>().to_array();
+        for (group, idxs) in idxs.chunks(4).enumerate() {
+            let mut sorted = idxs.to_vec();
+            sorted.sort();
+            assert_eq!(
+                sorted,
+                (group as u8 * 4..group as u8 * 4 + 4).collect::<Vec<_>>()
+            );
+            *counts
+                .entry(idxs.iter().map(|i| i % 4).collect::<Vec<_>>())
+                .or_insert(0) += 1;
+        }
+    }
+    // 4000 shuffles of 24 permutations
+    assert_eq!(counts.len(), 24);
+    assert!(
+        counts.values().all(|&count| (100..240).contains(&count)),
+        "{counts:?}"
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn shuffle() {
+    let x = u8x16::from_array(*b"abcdefghijklmnop");
+    let mut rng = SimdRng::<16>::new(3);
+    let idxs = rng.clone().permutation::<8>();
+    assert_eq!(rng.shuffle::<8>(x), x.swizzle_dyn(idxs));
+    assert_eq!(rng.shuffle::<1>(x), x);
+    let mut whole = rng.shuffle::<16>(x).to_array();
+    whole.sort();
+    assert_eq!(whole, x.to_array());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn next_f32() {
+    let mut rng = SimdRng::<8>::new(4);
+    let mut sum = 0.0;
+    for _ in 0..1000 {
+        let x = rng.next_f32();
+        assert!(x.to_array().iter().all(|x| (0.0..1.0).contains(x)), "{x:?}");
+        sum += x.to_array().iter().sum::<f32>();
+    }
+    assert!((sum / 8000.0 - 0.5).abs() < 0.02, "{sum}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn threshold() {
+    let mut rng = SimdRng::<4>::new(5);
+    let weights = f32x4::from_array([0.0, 0.25, 0.9, 1.0]);
+    let mut counts = [0; 4];
+    for _ in 0..1000 {
+        let picked = pick_by_threshold(weights, rng.next_f32()).to_array();
+        for (count, picked) in counts.iter_mut().zip(picked) {
+            *count += picked as u32;
+        }
+    }
+    assert_eq!(counts[0], 0);
+    assert!((200..300).contains(&counts[1]), "{counts:?}");
+    assert!((850..950).contains(&counts[2]), "{counts:?}");
+    assert_eq!(counts[3], 1000);
+}
+
+/// Integer weights sum exactly, so the pick can be checked against a scalar search.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cumulative_weight_matches_scalar() {
+    let mut rng = SimdRng::<16>::new(6);
+    for _ in 0..200 {
+        let weights = rng.below(Simd::splat(4)).cast::<f32>();
+        let total: f32 = weights.to_array().iter().sum();
+        for rand in rng
+            .next_f32()
+            .to_array()
+            .into_iter()
+            .chain([0.0, 1.0 - f32::EPSILON / 2.0])
+        {
+            let target = rand * total;
+            let mut cumulative = 0.0;
+            let expected = weights.to_array().iter().position(|&weight| {
+                cumulative += weight;
+                cumulative > target
+            });
+            let expected = if total > 0.0 {
+                // The largest random floats can round up to the total
+                Some(expected.unwrap_or_else(|| {
+                    weights
+                        .to_array()
+                        .iter()
+                        .rposition(|&weight| weight > 0.0)
+                        .unwrap()
+                }))
+            } else {
+                None
+            };
+            assert_eq!(
+                pick_by_cumulative_weight(weights, rand),
+                expected,
+                "{weights:?} {rand}"
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cumulative_weight_frequencies() {
+    let mut rng = SimdRng::<8>::new(7);
+    let weights = f32x8::from_array([0.5, 0.0, 0.25, 0.0, 0.0, 1.25, 0.0, 0.0]);
+    let mut counts = [0; 8];
+    for _ in 0..500 {
+        for rand in rng.next_f32().to_array() {
+            counts[pick_by_cumulative_weight(weights, rand).unwrap()] += 1;
+        }
+    }
+    assert_eq!(counts.iter().sum::<u32>(), 4000);
+    assert!((900..1100).contains(&counts[0]), "{counts:?}");
+    assert!((400..600).contains(&counts[2]), "{counts:?}");
+    assert!((2400..2600).contains(&counts[5]), "{counts:?}");
+    assert_eq!(pick_by_cumulative_weight(f32x16::splat(0.0), 0.5), None);
+}
+
+#[test]
+#[should_panic]
+fn group_not_dividing() {
+    let _ = SimdRng::<8>::new(0).permutation::<3>();
+}
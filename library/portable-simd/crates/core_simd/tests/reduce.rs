@@ -0,0 +1,92 @@
+#![feature(portable_simd)]
+use core_simd::{f32x4, f64x4, i8x16, reduce, reduce::RunningExtrema, u32x8};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn integers() {
+    let data: Vec<i8> = (0..16 * 37).map(|x| (x * 7919 % 251) as i8).collect();
+    let vectors: Vec<i8x16> = data.chunks_exact(16).map(i8x16::from_slice).collect();
+    for len in 0..vectors.len() {
+        let lanes = &data[..len * 16];
+        assert_eq!(
+            reduce::reduce_sum(&vectors[..len]),
+            lanes.iter().fold(0i8, |a, b| a.wrapping_add(*b))
+        );
+        assert_eq!(
+            reduce::reduce_min(&vectors[..len]),
+            lanes.iter().copied().min()
+        );
+        assert_eq!(
+            reduce::reduce_max(&vectors[..len]),
+            lanes.iter().copied().max()
+        );
+    }
+
+    let vectors = [u32x8::splat(u32::MAX); 3];
+    assert_eq!(reduce::reduce_sum(&vectors), u32::MAX.wrapping_mul(24));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn floats() {
+    let vectors: Vec<f64x4> = (0..25)
+        .map(|i| f64x4::from_array([i as f64, -(i as f64), 0.5, i as f64 * 0.25]))
+        .collect();
+    assert_eq!(reduce::reduce_sum(&vectors), 25.0 * 0.5 + 300.0 * 0.25);
+    assert_eq!(reduce::reduce_min(&vectors), Some(-24.0));
+    assert_eq!(reduce::reduce_max(&vectors), Some(24.0));
+    assert_eq!(reduce::reduce_sum::<f64, 4>(&[]), 0.0);
+
+    // NaNs are ignored by min and max, unless every lane is NaN
+    let nan = [
+        f64x4::splat(f64::NAN),
+        f64x4::from_array([f64::NAN, 1.0, f64::NAN, 2.0]),
+    ];
+    assert_eq!(reduce::reduce_min(&nan), Some(1.0));
+    assert_eq!(reduce::reduce_max(&nan), Some(2.0));
+    assert!(reduce::reduce_max(&nan[..1]).unwrap().is_nan());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn running_extrema() {
+    let data: Vec<i8> = (0..16 * 37).map(|x| (x * 7919 % 251) as i8).collect();
+    let mut extrema = RunningExtrema::new();
+    assert_eq!(extrema.minimum(), None);
+    for (i, chunk) in data.chunks_exact(16).enumerate() {
+        extrema.update(i8x16::from_slice(chunk));
+        let seen = &data[..(i + 1) * 16];
+        // The first of equal elements
+        let min = *seen.iter().min().unwrap();
+        let max = *seen.iter().max().unwrap();
+        let min_index = seen.iter().position(|x| *x == min).unwrap();
+        let max_index = seen.iter().position(|x| *x == max).unwrap();
+        assert_eq!(extrema.minimum(), Some((min, min_index)));
+        assert_eq!(extrema.maximum(), Some((max, max_index)));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn running_extrema_nan() {
+    let mut extrema = RunningExtrema::new();
+    extrema.update(f32x4::from_array([f32::NAN, f32::NAN, 2.0, f32::NAN]));
+    extrema.update(f32x4::from_array([f32::NAN, 1.0, f32::NAN, f32::NAN]));
+    extrema.update(f32x4::from_array([f32::NAN, 3.0, 2.0, f32::NAN]));
+    assert_eq!(extrema.minimum(), Some((1.0, 5)));
+    assert_eq!(extrema.maximum(), Some((3.0, 9)));
+
+    // Every element is NaN
+    let mut extrema = RunningExtrema::<f32, 4>::default();
+    extrema.update(f32x4::splat(f32::NAN));
+    extrema.update(f32x4::splat(f32::NAN));
+    let (min, index) = extrema.minimum().unwrap();
+    assert!(min.is_nan());
+    assert_eq!(index, 0);
+}
@@ -0,0 +1,99 @@
+#![feature(portable_simd)]
+use core_simd::Simd;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random<const N: usize>(rng: &mut Xorshift) -> [u8; N] {
+    core::array::from_fn(|_| rng.next_u64() as u8)
+}
+
+/// Reshaping to more, narrower lanes splits each lane into its bytes in memory order.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn more_lanes() {
+    let mut rng = Xorshift::new();
+    for _ in 0..100 {
+        let bytes = random::<16>(&mut rng);
+        let words = Simd::<u32, 4>::from_array(core::array::from_fn(|i| {
+            u32::from_ne_bytes(bytes[i * 4..][..4].try_into().unwrap())
+        }));
+        assert_eq!(words.try_reshape::<u8, 16>(), Simd::from_array(bytes));
+
+        let halves: Simd<u16, 8> = words.try_reshape();
+        for (i, half) in halves.to_array().iter().enumerate() {
+            assert_eq!(half.to_ne_bytes(), bytes[i * 2..][..2]);
+        }
+
+        let bytes = random::<64>(&mut rng);
+        let longs = Simd::<f64, 8>::from_array(core::array::from_fn(|i| {
+            f64::from_ne_bytes(bytes[i * 8..][..8].try_into().unwrap())
+        }));
+        assert_eq!(longs.try_reshape::<u8, 64>(), Simd::from_array(bytes));
+    }
+}
+
+/// Reshaping to fewer, wider lanes joins the bytes of consecutive lanes.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fewer_lanes() {
+    let mut rng = Xorshift::new();
+    for _ in 0..100 {
+        let bytes = random::<16>(&mut rng);
+        let longs: Simd<u64, 2> = Simd::<u8, 16>::from_array(bytes).try_reshape();
+        for (i, long) in longs.to_array().iter().enumerate() {
+            assert_eq!(long.to_ne_bytes(), bytes[i * 8..][..8]);
+        }
+
+        let bytes = random::<8>(&mut rng);
+        let single: Simd<i64, 1> = Simd::<i8, 8>::from_array(bytes.map(|x| x as i8)).try_reshape();
+        assert_eq!(single[0], i64::from_ne_bytes(bytes));
+    }
+}
+
+/// Reshaping to the same number of lanes reinterprets each lane.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn same_lanes() {
+    let floats = Simd::from_array([1.0f32, -0.0, f32::INFINITY, f32::MIN_POSITIVE]);
+    let bits: Simd<u32, 4> = floats.try_reshape();
+    assert_eq!(bits, Simd::from_array(floats.to_array().map(f32::to_bits)));
+    assert_eq!(
+        bits.try_reshape::<f32, 4>().to_array().map(f32::to_bits),
+        bits.to_array()
+    );
+
+    let signed = Simd::<i64, 2>::from_array([-1, i64::MIN]);
+    assert_eq!(
+        signed.try_reshape::<u64, 2>(),
+        Simd::from_array([u64::MAX, 1 << 63])
+    );
+
+    // Reshaping to the same shape changes nothing
+    assert_eq!(signed.try_reshape::<i64, 2>(), signed);
+}
+
+/// Every reshape of a vector round-trips back to it.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn round_trip() {
+    let mut rng = Xorshift::new();
+    let bytes = Simd::<u8, 32>::from_array(random(&mut rng));
+    assert_eq!(
+        bytes.try_reshape::<u16, 16>().try_reshape::<u8, 32>(),
+        bytes
+    );
+    assert_eq!(bytes.try_reshape::<i32, 8>().try_reshape::<u8, 32>(), bytes);
+    assert_eq!(bytes.try_reshape::<u64, 4>().try_reshape::<u8, 32>(), bytes);
+    assert_eq!(
+        bytes
+            .try_reshape::<u64, 4>()
+            .try_reshape::<u16, 16>()
+            .try_reshape::<u8, 32>(),
+        bytes
+    );
+}
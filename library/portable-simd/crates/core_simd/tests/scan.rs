@@ -0,0 +1,100 @@
+#![feature(portable_simd)]
+use core_simd::scan;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(rng: &mut Xorshift, len: usize) -> Vec<u8> {
+    let mut value = 0;
+    rng.take(len)
+        .map(|x| {
+            // Runs of random lengths
+            if x < u64::MAX / 3 {
+                value = x as u8 % 4;
+            }
+            value
+        })
+        .collect()
+}
+
+fn expected_starts<T: PartialEq>(slice: &[T]) -> Vec<usize> {
+    (0..slice.len())
+        .filter(|&i| i == 0 || slice[i] != slice[i - 1])
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn run_boundaries() {
+    let mut rng = Xorshift::new();
+    for len in (0..200).chain([1000, 1023]) {
+        let values = random(&mut rng, len);
+        let expected = expected_starts(&values);
+
+        // One word for every 64 elements, and one more
+        let words = len / 64 + (len % 64 != 0) as usize;
+        let mut boundaries = vec![!0; words + 1];
+        assert_eq!(
+            scan::run_boundaries(&values, &mut boundaries),
+            expected.len()
+        );
+        let starts: Vec<usize> = (0..len)
+            .filter(|&i| boundaries[i / 64] & (1 << (i % 64)) != 0)
+            .collect();
+        assert_eq!(starts, expected);
+        if len % 64 != 0 {
+            assert_eq!(boundaries[len / 64] >> (len % 64), 0);
+        }
+        // Words past the slice aren't written
+        assert_eq!(boundaries[boundaries.len() - 1], !0);
+
+        let wide: Vec<u64> = values.iter().map(|x| *x as u64).collect();
+        assert_eq!(scan::run_boundaries(&wide, &mut boundaries), expected.len());
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn run_starts() {
+    let mut rng = Xorshift::new();
+    for len in (0..200).chain([1000, 1023]) {
+        let values = random(&mut rng, len);
+        let expected = expected_starts(&values);
+
+        let mut starts = vec![0; len];
+        let runs = scan::run_starts(&values, &mut starts);
+        assert_eq!(starts[..runs], expected);
+
+        let floats: Vec<f32> = values.iter().map(|x| *x as f32).collect();
+        let runs = scan::run_starts(&floats, &mut starts);
+        assert_eq!(starts[..runs], expected);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn nan_starts_runs() {
+    let values = [f64::NAN, f64::NAN, 1.0, 1.0, f64::NAN];
+    let mut starts = [0; 5];
+    assert_eq!(scan::run_starts(&values, &mut starts), 4);
+    assert_eq!(starts[..4], [0, 1, 2, 4]);
+    let mut boundaries = [0];
+    assert_eq!(scan::run_boundaries(&values, &mut boundaries), 4);
+    assert_eq!(boundaries, [0b10111]);
+}
+
+#[test]
+#[should_panic]
+fn run_boundaries_too_few_words() {
+    let _ = scan::run_boundaries(&[0u32; 65], &mut [0; 1]);
+}
+
+#[test]
+#[should_panic]
+fn run_starts_too_short() {
+    let _ = scan::run_starts(&[0u32; 8], &mut [0; 7]);
+}
@@ -0,0 +1,46 @@
+#![feature(portable_simd)]
+use core_simd::search;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lower_bound() {
+    for len in (0..300).chain([1000, 4913, 4914, 100_000]) {
+        // Runs of equal elements, with gaps between them
+        let sorted: Vec<i64> = (0..len as i64).map(|x| x / 3 * 5).collect();
+        let max = sorted.last().copied().unwrap_or(0);
+        for key in (-2..max + 3).step_by(1 + len / 200) {
+            assert_eq!(
+                search::lower_bound(&sorted, key),
+                sorted.partition_point(|x| *x < key),
+                "key {key} in length {len}"
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lower_bound_floats() {
+    let sorted: Vec<f32> = (0..777).map(|x| x as f32 * 0.25 - 50.0).collect();
+    for key in [
+        -1000.0,
+        -50.0,
+        -49.9,
+        0.0,
+        0.1,
+        143.75,
+        144.0,
+        f32::INFINITY,
+    ] {
+        assert_eq!(
+            search::lower_bound(&sorted, key),
+            sorted.partition_point(|x| *x < key)
+        );
+    }
+}
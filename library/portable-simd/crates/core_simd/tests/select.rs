@@ -0,0 +1,137 @@
+#![feature(portable_simd)]
+use core_simd::{select, Mask, Simd, SimdFloat};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random(rng: &mut Xorshift, len: usize) -> impl Iterator<Item = u64> + '_ {
+    // Keep some duplicates
+    rng.take(len).map(|x| if x < u64::MAX / 5 { 0 } else { x })
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn top_k_integers() {
+    let mut rng = Xorshift::new();
+    for len in (0..40).chain([100, 1000]) {
+        let bits: Vec<u64> = random(&mut rng, len).collect();
+        for k in 0..=16 {
+            let n = k.min(len);
+
+            let values: Vec<u32> = bits.iter().map(|x| *x as u32).collect();
+            let mut expected = values.clone();
+            expected.sort_by(|a, b| b.cmp(a));
+            let mut top = vec![0; k];
+            assert_eq!(select::top_k(&values, &mut top), n);
+            assert_eq!(top[..n], expected[..n]);
+
+            let values: Vec<i64> = bits.iter().map(|x| *x as i64).collect();
+            let mut expected = values.clone();
+            expected.sort_by(|a, b| b.cmp(a));
+            let mut top = vec![0; k];
+            assert_eq!(select::top_k(&values, &mut top), n);
+            assert_eq!(top[..n], expected[..n]);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn top_k_floats() {
+    let mut rng = Xorshift::new();
+    for len in [0, 1, 5, 16, 17, 100, 1000] {
+        let bits: Vec<u64> = random(&mut rng, len).collect();
+        for k in [1, 4, 16] {
+            let n = k.min(len);
+
+            // Every bit pattern, including NaNs and infinities
+            let values: Vec<f32> = bits.iter().map(|x| f32::from_bits(*x as u32)).collect();
+            let mut expected = values.clone();
+            expected.sort_by(|a, b| b.total_cmp(a));
+            let mut top = vec![0.0; k];
+            assert_eq!(select::top_k(&values, &mut top), n);
+            let top: Vec<u32> = top[..n].iter().map(|x| x.to_bits()).collect();
+            let expected: Vec<u32> = expected[..n].iter().map(|x| x.to_bits()).collect();
+            assert_eq!(top, expected);
+
+            let values: Vec<f64> = bits.iter().map(|x| f64::from_bits(*x)).collect();
+            let mut expected = values.clone();
+            expected.sort_by(|a, b| b.total_cmp(a));
+            let mut top = vec![0.0; k];
+            assert_eq!(select::top_k(&values, &mut top), n);
+            let top: Vec<u64> = top[..n].iter().map(|x| x.to_bits()).collect();
+            let expected: Vec<u64> = expected[..n].iter().map(|x| x.to_bits()).collect();
+            assert_eq!(top, expected);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn top_k_ascending() {
+    // Every chunk has keys larger than the ones kept so far
+    let values: Vec<i32> = (-500..500).collect();
+    let mut top = [0; 5];
+    assert_eq!(select::top_k(&values, &mut top), 5);
+    assert_eq!(top, [499, 498, 497, 496, 495]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn select_many() {
+    let default = Simd::from_array([-1; 8]);
+    assert_eq!(Simd::select_many(&[], default), default);
+
+    // Every combination of three masks
+    let cases = [1, 2, 4].map(|bit| {
+        let mask = Mask::from_array(core::array::from_fn(|lane| lane & bit != 0));
+        (mask, Simd::splat(bit as i32))
+    });
+    let selected = Simd::select_many(&cases, default);
+    assert_eq!(selected.to_array(), [-1, 1, 2, 1, 4, 1, 2, 1]);
+
+    // Later cases only apply where earlier cases don't
+    let reversed = [cases[2], cases[1], cases[0]];
+    let selected = Simd::select_many(&reversed, default);
+    assert_eq!(selected.to_array(), [-1, 1, 2, 2, 4, 4, 4, 4]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn select3() {
+    // Every combination of two masks
+    let a_mask = Mask::from_array([false, true, false, true]);
+    let b_mask = Mask::from_array([false, false, true, true]);
+    let [a, b, c] = [1, 2, 3].map(Simd::<i64, 4>::splat);
+    let selected = Simd::select3(a_mask, b_mask, a, b, c);
+    assert_eq!(selected.to_array(), [3, 1, 2, 1]);
+    assert_eq!(selected, Simd::select_many(&[(a_mask, a), (b_mask, b)], c));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn select_branchless() {
+    let a = Simd::from_array([1.5f32, -0.0, f32::NAN, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let b = Simd::from_array([-1.0f32, 0.0, 3.0, f32::INFINITY, -5.0, -6.0, -7.0, -8.0]);
+    for bits in 0..=u8::MAX {
+        let mask = Mask::<i32, 8>::from_array(core::array::from_fn(|lane| bits & 1 << lane != 0));
+        let expected = mask.select(a, b);
+        let selected = mask.select_branchless(a, b);
+        assert_eq!(selected.to_bits(), expected.to_bits());
+    }
+    for secret in [false, true] {
+        let mask = Mask::<i8, 16>::splat(secret);
+        let (a, b) = (Simd::splat(1u8), Simd::splat(2u8));
+        assert_eq!(mask.select_branchless(a, b), mask.select(a, b));
+    }
+}
+
+#[test]
+#[should_panic]
+fn top_k_too_many() {
+    let _ = select::top_k(&[0u32; 32], &mut [0; 17]);
+}
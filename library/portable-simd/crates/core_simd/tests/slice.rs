@@ -0,0 +1,130 @@
+#![feature(portable_simd)]
+use core_simd::{slice, Simd, SimdPartialEq, SimdPartialOrd};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn values(len: usize) -> Vec<i32> {
+    Xorshift::new().take(len).map(|x| x as i32 >> 8).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn integers() {
+    let all = values(300);
+    for len in 0..all.len() {
+        // Every offset, to test unaligned slices
+        for start in 0..4.min(all.len() - len + 1) {
+            let values = &all[start..start + len];
+            assert_eq!(
+                slice::sum(values),
+                values.iter().fold(0i32, |a, b| a.wrapping_add(*b))
+            );
+            assert_eq!(slice::min(values), values.iter().copied().min());
+            assert_eq!(slice::max(values), values.iter().copied().max());
+            assert_eq!(
+                slice::minmax(values),
+                values
+                    .iter()
+                    .copied()
+                    .min()
+                    .zip(values.iter().copied().max())
+            );
+            let sum: i64 = values.iter().map(|x| *x as i64).sum();
+            let mean = slice::mean(values);
+            if len == 0 {
+                assert_eq!(mean, None);
+            } else {
+                assert_eq!(mean, Some(sum as f64 / len as f64));
+            }
+        }
+    }
+
+    let bytes = [200u8; 100];
+    assert_eq!(slice::sum(&bytes), 200u8.wrapping_mul(100));
+    assert_eq!(slice::mean(&bytes), Some(200.0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn floats() {
+    for len in 1..200 {
+        let values: Vec<f64> = (0..len).map(|x| (x as f64 - 50.0) * 0.5).collect();
+        assert_eq!(slice::sum(&values), values.iter().sum::<f64>());
+        assert_eq!(slice::min(&values), Some(-25.0));
+        assert_eq!(slice::max(&values), Some((len as f64 - 51.0) * 0.5));
+        assert_eq!(
+            slice::minmax(&values),
+            Some((-25.0, (len as f64 - 51.0) * 0.5))
+        );
+        assert_eq!(
+            slice::mean(&values),
+            Some(values.iter().sum::<f64>() / len as f64)
+        );
+    }
+
+    // NaNs are ignored by min and max, unless every element is NaN
+    let mut values = [f32::NAN; 40];
+    assert!(slice::min(&values).unwrap().is_nan());
+    values[17] = 3.0;
+    values[33] = -1.0;
+    assert_eq!(slice::minmax(&values), Some((-1.0, 3.0)));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn dot() {
+    // Small integers, so every sum is exact
+    let all: Vec<f32> = values(600).iter().map(|x| (x % 9) as f32).collect();
+    let (a, b) = all.split_at(300);
+    for len in 0..300 {
+        for start in 0..4.min(300 - len + 1) {
+            let (a, b) = (&a[start..start + len], &b[..len]);
+            let expected: f32 = a.iter().zip(b).map(|(a, b)| a * b).sum();
+            assert_eq!(slice::dot(a, b), expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn dot_different_lengths() {
+    let _ = slice::dot(&[1.0; 20], &[1.0; 19]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn filter_into() {
+    let all = values(300);
+    for len in 0..all.len() {
+        let values = &all[..len];
+        let mut filtered = vec![0; len];
+
+        let count = slice::filter_into(values, |x| x.simd_gt(Simd::splat(0)), &mut filtered);
+        let expected: Vec<i32> = values.iter().copied().filter(|x| *x > 0).collect();
+        assert_eq!(filtered[..count], expected);
+
+        let count = slice::filter_into(
+            values,
+            |x| (x & Simd::splat(3)).simd_eq(Simd::splat(0)),
+            &mut filtered,
+        );
+        let expected: Vec<i32> = values.iter().copied().filter(|x| x & 3 == 0).collect();
+        assert_eq!(filtered[..count], expected);
+    }
+
+    // The padding of the last vector is never kept
+    let mut filtered = [0.0; 5];
+    let count = slice::filter_into(&[1.0f32; 5], |x| x.simd_eq(x), &mut filtered);
+    assert_eq!(count, 5);
+}
+
+#[test]
+#[should_panic]
+fn filter_into_short_destination() {
+    let _ = slice::filter_into(&[1u8; 20], |x| x.simd_eq(Simd::splat(2)), &mut [0; 19]);
+}
@@ -0,0 +1,113 @@
+#![feature(portable_simd)]
+use core_simd::{sort, Simd};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn random<const N: usize>(rng: &mut Xorshift) -> [u64; N] {
+    core::array::from_fn(|_| {
+        let x = rng.next_u64();
+        // Keep some duplicates
+        if x < u64::MAX / 5 {
+            0
+        } else {
+            x
+        }
+    })
+}
+
+macro_rules! test_sort {
+    { $($name:ident: $sort:ident, $lanes:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            let mut rng = Xorshift::new();
+            for _ in 0..200 {
+                let bits = random::<$lanes>(&mut rng);
+
+                let mut keys = bits.map(|x| x as u32);
+                let mut expected = keys;
+                expected.sort();
+                sort::$sort(&mut keys);
+                assert_eq!(keys, expected);
+
+                let mut keys = bits.map(|x| x as i64);
+                let mut expected = keys;
+                expected.sort();
+                sort::$sort(&mut keys);
+                assert_eq!(keys, expected);
+
+                // Every bit pattern, including NaNs and infinities
+                let mut keys = bits.map(|x| f32::from_bits(x as u32));
+                let mut expected = keys;
+                expected.sort_by(f32::total_cmp);
+                sort::$sort(&mut keys);
+                assert_eq!(keys.map(f32::to_bits), expected.map(f32::to_bits));
+
+                let mut keys = bits.map(f64::from_bits);
+                let mut expected = keys;
+                expected.sort_by(f64::total_cmp);
+                sort::$sort(&mut keys);
+                assert_eq!(keys.map(f64::to_bits), expected.map(f64::to_bits));
+            }
+        }
+        )*
+    }
+}
+
+test_sort! {
+    sort8: sort8, 8;
+    sort16: sort16, 16;
+    sort32: sort32, 32;
+}
+
+macro_rules! test_merge_sorted {
+    { $($name:ident: $lanes:literal;)* } => {
+        $(
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            let mut rng = Xorshift::new();
+            for _ in 0..200 {
+                let mut a = random::<$lanes>(&mut rng).map(|x| x as i32);
+                let mut b = random::<$lanes>(&mut rng).map(|x| x as i32);
+                a.sort();
+                b.sort();
+                let mut expected = [a, b].concat();
+                expected.sort();
+                let (lo, hi) = sort::merge_sorted(Simd::from_array(a), Simd::from_array(b));
+                assert_eq!([lo.to_array(), hi.to_array()].concat(), expected);
+
+                // Every bit pattern, including NaNs and infinities
+                let mut a = random::<$lanes>(&mut rng).map(f64::from_bits);
+                let mut b = random::<$lanes>(&mut rng).map(f64::from_bits);
+                a.sort_by(f64::total_cmp);
+                b.sort_by(f64::total_cmp);
+                let mut expected = [a, b].concat();
+                expected.sort_by(f64::total_cmp);
+                let (lo, hi) = sort::merge_sorted(Simd::from_array(a), Simd::from_array(b));
+                let merged = [lo.to_array(), hi.to_array()].concat();
+                assert_eq!(
+                    merged.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+                    expected.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+                );
+            }
+        }
+        )*
+    }
+}
+
+test_merge_sorted! {
+    merge_sorted_1: 1;
+    merge_sorted_2: 2;
+    merge_sorted_4: 4;
+    merge_sorted_8: 8;
+    merge_sorted_16: 16;
+    merge_sorted_32: 32;
+    merge_sorted_64: 64;
+}
@@ -0,0 +1,78 @@
+#![feature(portable_simd)]
+use core_simd::{spatial, Simd};
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn values(len: usize) -> Vec<u64> {
+    Xorshift::new().take(len).collect()
+}
+
+/// Interleaves the low bits of `coordinates`, one bit of each at a time.
+fn interleave(coordinates: &[u64], bits: u32) -> u64 {
+    let mut code = 0;
+    for bit in 0..bits {
+        for (i, x) in coordinates.iter().enumerate() {
+            code |= (x >> bit & 1) << (bit as usize * coordinates.len() + i);
+        }
+    }
+    code
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn morton_2d() {
+    for chunk in values(256).chunks_exact(8) {
+        let x = Simd::<u64, 4>::from_slice(chunk);
+        let y = Simd::<u64, 4>::from_slice(&chunk[4..]);
+        let code = spatial::morton_encode_2d(x, y);
+        for i in 0..4 {
+            assert_eq!(code[i], interleave(&[x[i], y[i]], 32));
+        }
+        let mask = Simd::splat(u32::MAX as u64);
+        assert_eq!(spatial::morton_decode_2d(code), (x & mask, y & mask));
+
+        let (x, y) = (x.cast::<u32>(), y.cast::<u32>());
+        let code = spatial::morton_encode_2d(x, y);
+        for i in 0..4 {
+            assert_eq!(code[i] as u64, interleave(&[x[i] as u64, y[i] as u64], 16));
+        }
+        let mask = Simd::splat(u16::MAX as u32);
+        assert_eq!(spatial::morton_decode_2d(code), (x & mask, y & mask));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn morton_3d() {
+    for chunk in values(240).chunks_exact(12) {
+        let x = Simd::<u64, 4>::from_slice(chunk);
+        let y = Simd::<u64, 4>::from_slice(&chunk[4..]);
+        let z = Simd::<u64, 4>::from_slice(&chunk[8..]);
+        let code = spatial::morton_encode_3d(x, y, z);
+        for i in 0..4 {
+            assert_eq!(code[i], interleave(&[x[i], y[i], z[i]], 21));
+        }
+        let mask = Simd::splat((1 << 21) - 1);
+        assert_eq!(
+            spatial::morton_decode_3d(code),
+            (x & mask, y & mask, z & mask)
+        );
+
+        let (x, y, z) = (x.cast::<u32>(), y.cast::<u32>(), z.cast::<u32>());
+        let code = spatial::morton_encode_3d(x, y, z);
+        for i in 0..4 {
+            let coordinates = [x[i] as u64, y[i] as u64, z[i] as u64];
+            assert_eq!(code[i] as u64, interleave(&coordinates, 10));
+        }
+        let mask = Simd::splat((1 << 10) - 1);
+        assert_eq!(
+            spatial::morton_decode_3d(code),
+            (x & mask, y & mask, z & mask)
+        );
+    }
+}
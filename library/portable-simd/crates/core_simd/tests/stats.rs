@@ -0,0 +1,100 @@
+#![feature(portable_simd)]
+use core_simd::stats;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn scalar_histogram(bytes: &[u8]) -> [u32; 256] {
+    let mut histogram = [0; 256];
+    for &byte in bytes {
+        histogram[usize::from(byte)] += 1;
+    }
+    histogram
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn byte_histogram() {
+    let bytes: Vec<u8> = Xorshift::new()
+        .take(1000)
+        // Skewed towards small values, so some counts are large
+        .map(|x| (x >> 56) as u8 & (x >> 48) as u8)
+        .collect();
+    for len in [0, 1, 3, 15, 16, 17, 63, 64, 65, 1000] {
+        assert_eq!(
+            stats::byte_histogram(&bytes[..len]),
+            scalar_histogram(&bytes[..len])
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn byte_histogram_runs() {
+    let mut histogram = [0; 256];
+    histogram[7] = 1001;
+    assert_eq!(stats::byte_histogram(&[7; 1001]), histogram);
+
+    let all: Vec<u8> = (0..=255).cycle().take(256 * 3 + 5).collect();
+    assert_eq!(stats::byte_histogram(&all), scalar_histogram(&all));
+}
+
+fn random(n: usize) -> Vec<f32> {
+    let mut rng = Xorshift::new();
+    (0..n).map(|_| rng.next_f32() * 10.0 + 100.0).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mean_and_variance() {
+    let values = random(1000);
+    for len in [1, 2, 7, 8, 9, 15, 16, 17, 100, 1000] {
+        let values = &values[..len];
+        let n = len as f64;
+        let mean = values.iter().map(|&x| f64::from(x)).sum::<f64>() / n;
+        let variance = values
+            .iter()
+            .map(|&x| (f64::from(x) - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        let (simd_mean, simd_variance) = stats::mean_and_variance(values);
+        assert!(
+            (f64::from(simd_mean) - mean).abs() < 1e-4,
+            "{simd_mean} {mean}"
+        );
+        assert!(
+            (f64::from(simd_variance) - variance).abs() < 1e-3,
+            "{simd_variance} {variance}"
+        );
+    }
+    assert_eq!(stats::mean_and_variance(&[3.0; 11]), (3.0, 0.0));
+    let (mean, variance) = stats::mean_and_variance(&[]);
+    assert!(mean.is_nan() && variance.is_nan());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn standardize() {
+    let values = random(1000);
+    for len in [0, 2, 7, 8, 9, 1000] {
+        let mut standardized = values[..len].to_vec();
+        let (mean, variance) = stats::mean_and_variance(&standardized);
+        stats::standardize(&mut standardized, mean, variance.sqrt());
+        for (x, expected) in standardized.iter().zip(&values[..len]) {
+            let expected = (expected - mean) / variance.sqrt();
+            assert!(
+                (x - expected).abs() <= expected.abs() * 1e-6,
+                "{x} {expected}"
+            );
+        }
+        if len > 0 {
+            let (mean, variance) = stats::mean_and_variance(&standardized);
+            assert!(mean.abs() < 1e-5, "{mean}");
+            assert!((variance - 1.0).abs() < 1e-5, "{variance}");
+        }
+    }
+}
@@ -48,6 +48,63 @@ fn rotate() {
     assert_eq!(a.rotate_lanes_right::<5>().to_array(), [4, 1, 2, 3]);
 }
 
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rotate_dyn() {
+    fn check<T, const LANES: usize>(f: impl Fn(usize) -> T)
+    where
+        T: core_simd::SimdElement + PartialEq + core::fmt::Debug,
+        core_simd::LaneCount<LANES>: core_simd::SupportedLaneCount,
+    {
+        let array: [T; LANES] = core::array::from_fn(f);
+        let v = Simd::from_array(array);
+        for n in 0..2 * LANES {
+            let mut left = array;
+            left.rotate_left(n % LANES);
+            assert_eq!(v.rotate_lanes_left_dyn(n).to_array(), left, "{n}");
+            let mut right = array;
+            right.rotate_right(n % LANES);
+            assert_eq!(v.rotate_lanes_right_dyn(n).to_array(), right, "{n}");
+        }
+        assert_eq!(v.rotate_lanes_left_dyn(usize::MAX), {
+            let mut a = array;
+            a.rotate_left(usize::MAX % LANES);
+            Simd::from_array(a)
+        });
+    }
+    check::<u8, 1>(|i| i as u8);
+    check::<u8, 64>(|i| i as u8);
+    check::<u32, 4>(|i| i as u32);
+    check::<i16, 16>(|i| i as i16);
+    check::<f64, 8>(|i| i as f64);
+    check::<u32, 32>(|i| i as u32);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn repeat_each() {
+    let a = Simd::from_array([1, 2, 3, 4]);
+    assert_eq!(a.repeat_each::<1, 4>(), a);
+    assert_eq!(a.repeat_each::<2, 8>().to_array(), [1, 1, 2, 2, 3, 3, 4, 4]);
+    let b = a.repeat_each::<16, 64>().to_array();
+    assert!(b.iter().enumerate().all(|(i, x)| *x == i as i32 / 16 + 1));
+    assert_eq!(
+        Simd::from_array([5u8]).repeat_each::<8, 8>().to_array(),
+        [5; 8]
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn take_every() {
+    let a = Simd::from_array([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(a.take_every::<1, 8>(), a);
+    assert_eq!(a.take_every::<2, 4>().to_array(), [1, 3, 5, 7]);
+    assert_eq!(a.take_every::<4, 2>().to_array(), [1, 5]);
+    assert_eq!(a.take_every::<8, 1>().to_array(), [1]);
+    assert_eq!(a.repeat_each::<8, 64>().take_every::<8, 8>(), a);
+}
+
 #[test]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
 fn interleave() {
@@ -60,3 +117,29 @@ fn interleave() {
     assert_eq!(even, a);
     assert_eq!(odd, b);
 }
+
+test_helpers::test_lanes! {
+    fn swizzle_dyn<const LANES: usize>() {
+        test_helpers::test_2(&|bytes: [u8; LANES], idxs: [u8; LANES]| {
+            // About half of the indices are in bounds
+            let idxs = idxs.map(|k| k % (2 * LANES as u8));
+            let expected = idxs.map(|k| bytes.get(k as usize).copied().unwrap_or(0));
+            let swizzled = Simd::from_array(bytes).swizzle_dyn(Simd::from_array(idxs));
+            test_helpers::prop_assert_biteq!(swizzled.to_array(), expected);
+            Ok(())
+        });
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swizzle_dyn_out_of_bounds() {
+    let bytes = Simd::from_array([1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    let idxs = Simd::from_array([
+        0, 15, 16, 17, 31, 32, 127, 128, 129, 143, 255, 1, 2, 3, 4, 5,
+    ]);
+    assert_eq!(
+        bytes.swizzle_dyn(idxs).to_array(),
+        [1, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6]
+    );
+}
@@ -0,0 +1,80 @@
+#![feature(portable_simd)]
+use core_simd::unicode;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+const SAMPLES: &[&str] = &[
+    "",
+    "ascii only, but long enough to use more than one block of sixteen",
+    "é",
+    "mostly ascii with a single é in the middle of a long run of ascii text",
+    "Ελληνικά και русский и 中文 和 日本語 と 한국어",
+    "🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀",
+    "crab at the end of a block 🦀 and more ascii after it, plus \u{ffff}\u{10000}",
+];
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn utf8_to_utf16() {
+    for sample in SAMPLES {
+        for start in 0..sample.len() {
+            if let Some(text) = sample.get(start..) {
+                let mut utf16 = vec![0; text.len()];
+                let len = unicode::utf8_to_utf16(text.as_bytes(), &mut utf16).unwrap();
+                assert_eq!(utf16[..len], text.encode_utf16().collect::<Vec<_>>());
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn utf16_to_utf8() {
+    for sample in SAMPLES {
+        let utf16: Vec<u16> = sample.encode_utf16().collect();
+        for start in 0..utf16.len() {
+            let mut utf8 = vec![0; utf16.len() * 3];
+            let result = unicode::utf16_to_utf8(&utf16[start..], &mut utf8);
+            match String::from_utf16(&utf16[start..]) {
+                Ok(text) => assert_eq!(utf8[..result.unwrap()], *text.as_bytes()),
+                // Starting in the middle of a surrogate pair
+                Err(_) => assert_eq!(result.unwrap_err().valid_up_to(), 0),
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_utf8() {
+    let mut utf16 = [0; 64];
+    for (bytes, valid_up_to) in [
+        (&b"\x80"[..], 0),
+        (b"0123456789abcdef0123456789\xc3", 26),
+        (b"0123456789abcdef\xed\xa0\x80", 16),
+        (b"\xf0\x9f\xa6\x80\xf0\x9f\xa6", 4),
+    ] {
+        let error = unicode::utf8_to_utf16(bytes, &mut utf16).unwrap_err();
+        assert_eq!(error.valid_up_to(), valid_up_to);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn invalid_utf16() {
+    let mut utf8 = [0; 192];
+    let mut units = vec![b'a' as u16; 40];
+    for i in 0..units.len() {
+        for surrogate in [0xd800, 0xdbff, 0xdc00, 0xdfff] {
+            let original = units[i];
+            units[i] = surrogate;
+            let error = unicode::utf16_to_utf8(&units, &mut utf8).unwrap_err();
+            assert_eq!(error.valid_up_to(), i);
+            units[i] = original;
+        }
+    }
+}
@@ -0,0 +1,97 @@
+#![feature(portable_simd)]
+use core_simd::varint;
+use test_helpers::rng::Xorshift;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn encode(mut value: u64, output: &mut Vec<u8>) {
+    while value >= 0x80 {
+        output.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+/// Returns integers of every encoded length, biased towards short ones.
+fn values(len: usize) -> Vec<u64> {
+    Xorshift::new()
+        .take(len)
+        .map(|x| {
+            let bits = match x % 4 {
+                0 | 1 => 7,
+                2 => 14,
+                _ => (x >> 8) % 65,
+            };
+            x.rotate_right(16)
+                .checked_shr(64 - bits as u32)
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn decode_batch() {
+    for values in [values(1000), vec![5; 100], vec![u64::MAX; 50], vec![]] {
+        let mut input = Vec::new();
+        for value in &values {
+            encode(*value, &mut input);
+        }
+        let mut output = vec![0; values.len() + 3];
+        assert_eq!(
+            varint::decode_batch(&input, &mut output),
+            (values.len(), input.len())
+        );
+        assert_eq!(output[..values.len()], values);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn decode_batch_partial() {
+    let values = values(300);
+    let mut input = Vec::new();
+    let mut ends = vec![0];
+    for value in &values {
+        encode(*value, &mut input);
+        ends.push(input.len());
+    }
+
+    // Stops when the output is full
+    for len in [0, 1, 17, 100, 299] {
+        let mut output = vec![0; len];
+        assert_eq!(varint::decode_batch(&input, &mut output), (len, ends[len]));
+        assert_eq!(output, values[..len]);
+    }
+
+    // Stops at an incomplete integer, and can be resumed
+    for cut in [1, 7, 16, 100, input.len() - 1] {
+        let mut output = vec![0; values.len()];
+        let (count, read) = varint::decode_batch(&input[..cut], &mut output);
+        assert_eq!(read, ends[count]);
+        assert!(ends[count + 1] > cut);
+        let (rest, _) = varint::decode_batch(&input[read..], &mut output[count..]);
+        assert_eq!(count + rest, values.len());
+        assert_eq!(output, values);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn decode_batch_too_long() {
+    // Two integers, then one of eleven bytes
+    let mut input = vec![1, 2];
+    input.extend_from_slice(&[0x80; 10]);
+    input.extend_from_slice(&[0; 20]);
+    let mut output = [0; 32];
+    assert_eq!(varint::decode_batch(&input, &mut output), (2, 2));
+    assert_eq!(output[..2], [1, 2]);
+
+    // And longer than a block
+    input.splice(2..2, [0x80; 10]);
+    assert_eq!(varint::decode_batch(&input, &mut output), (2, 2));
+}
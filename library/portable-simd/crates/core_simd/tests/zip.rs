@@ -0,0 +1,61 @@
+#![feature(portable_simd)]
+use core_simd::{mem::AlignedSlice, zip_slices, Mask, Simd};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Every element is passed exactly once, in order, whatever the alignment and length of the
+/// slices.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn windows() {
+    let a: Vec<u32> = (1..=100).collect();
+    let b: Vec<f32> = (1..=100).map(|x| x as f32 * -0.5).collect();
+    for start in 0..8 {
+        for end in start..start + 70 {
+            let (a, b) = (&a[start..end], &b[start..end]);
+            let (mut seen_a, mut seen_b) = (Vec::new(), Vec::new());
+            let mut partial = 0;
+            zip_slices(
+                a,
+                b,
+                |va: Simd<u32, 8>, vb: Simd<f32, 8>, valid: Mask<i32, 8>| {
+                    let len = valid.to_array().iter().filter(|&&x| x).count();
+                    assert!(len > 0);
+                    assert_eq!(valid, Mask::from_array(core::array::from_fn(|i| i < len)));
+                    assert!(va[len..].iter().all(|&x| x == 0), "{va:?}");
+                    assert!(vb[len..].iter().all(|&x| x == 0.0), "{vb:?}");
+                    seen_a.extend_from_slice(&va[..len]);
+                    seen_b.extend_from_slice(&vb[..len]);
+                    partial += (len < 8) as usize;
+                },
+            );
+            assert_eq!(seen_a, a);
+            assert_eq!(seen_b, b);
+            assert!(partial <= 2, "{start} {end}");
+        }
+    }
+}
+
+/// Only the first and last windows are partial.
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn aligned_windows() {
+    let vectors = [Simd::<u16, 16>::splat(1); 4];
+    let a = &AlignedSlice::from_vectors(&vectors)[3..60];
+    let mut lens = Vec::new();
+    zip_slices(a, a, |va: Simd<u16, 16>, vb, valid: Mask<i16, 16>| {
+        assert_eq!(va, vb);
+        lens.push(valid.to_array().iter().filter(|&&x| x).count());
+    });
+    assert_eq!(lens, [13, 16, 16, 12]);
+}
+
+#[test]
+#[should_panic]
+fn different_lengths() {
+    zip_slices(&[1u8; 3], &[1u8; 4], |_: Simd<u8, 4>, _, _| {});
+}
@@ -0,0 +1,172 @@
+use crate::simd::{LaneCount, Simd, SupportedLaneCount};
+use crate::StdFloat;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A vector of double-double numbers, each the unevaluated sum of two [`f64`]s.
+///
+/// Each lane holds roughly 106 bits of precision, which is enough to make compensated
+/// summations, dot products and geometric predicates exact or nearly so, without leaving SIMD.
+/// The arithmetic is built on the error-free transformations [`StdFloat::two_sum`] and
+/// [`StdFloat::two_product`].
+///
+/// The exponent range is that of [`f64`], and the low part of a lane may lose precision once the
+/// high part is subnormal.
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use std::simd;
+/// # #[cfg(feature = "as_crate")] use std_float::DoubleDouble;
+/// # #[cfg(not(feature = "as_crate"))] use simd::DoubleDouble;
+/// # use simd::f64x2;
+/// let a = f64x2::from_array([1e16, 0.1]);
+/// let b = f64x2::from_array([1.0, 0.2]);
+/// // In plain `f64`, the small term is lost
+/// assert_eq!((a + b - a)[0], 0.0);
+///
+/// let a = DoubleDouble::from(a);
+/// let sum = a + DoubleDouble::from(b) - a;
+/// assert_eq!(sum.to_f64(), b);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DoubleDouble<const N: usize>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    hi: Simd<f64, N>,
+    lo: Simd<f64, N>,
+}
+
+impl<const N: usize> DoubleDouble<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Constructs a vector from high and low parts, normalizing them so that the high part is
+    /// the sum rounded to the nearest [`f64`].
+    #[inline]
+    #[must_use]
+    pub fn new(hi: Simd<f64, N>, lo: Simd<f64, N>) -> Self {
+        let (hi, lo) = hi.two_sum(lo);
+        Self { hi, lo }
+    }
+
+    /// Constructs a vector holding the exact sum of `a` and `b`.
+    #[inline]
+    #[must_use]
+    pub fn from_sum(a: Simd<f64, N>, b: Simd<f64, N>) -> Self {
+        let (hi, lo) = a.two_sum(b);
+        Self { hi, lo }
+    }
+
+    /// Constructs a vector holding the exact product of `a` and `b`.
+    #[inline]
+    #[must_use]
+    pub fn from_product(a: Simd<f64, N>, b: Simd<f64, N>) -> Self {
+        let (hi, lo) = a.two_product(b);
+        Self { hi, lo }
+    }
+
+    /// Returns the high part of each lane, which is the lane rounded to the nearest [`f64`].
+    #[inline]
+    #[must_use]
+    pub fn hi(self) -> Simd<f64, N> {
+        self.hi
+    }
+
+    /// Returns the low part of each lane, the error of rounding it to the high part.
+    #[inline]
+    #[must_use]
+    pub fn lo(self) -> Simd<f64, N> {
+        self.lo
+    }
+
+    /// Rounds each lane to the nearest [`f64`].
+    #[inline]
+    #[must_use]
+    pub fn to_f64(self) -> Simd<f64, N> {
+        self.hi
+    }
+}
+
+/// Normalizes a high and low part, given that the high part is at least as large in magnitude.
+#[inline]
+fn quick_two_sum<const N: usize>(hi: Simd<f64, N>, lo: Simd<f64, N>) -> DoubleDouble<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let sum = hi + lo;
+    DoubleDouble {
+        hi: sum,
+        lo: lo - (sum - hi),
+    }
+}
+
+impl<const N: usize> From<Simd<f64, N>> for DoubleDouble<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn from(value: Simd<f64, N>) -> Self {
+        Self {
+            hi: value,
+            lo: Simd::splat(0.0),
+        }
+    }
+}
+
+impl<const N: usize> Add for DoubleDouble<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let (hi, hi_error) = self.hi.two_sum(rhs.hi);
+        let (lo, lo_error) = self.lo.two_sum(rhs.lo);
+        let sum = quick_two_sum(hi, hi_error + lo);
+        quick_two_sum(sum.hi, sum.lo + lo_error)
+    }
+}
+
+impl<const N: usize> Sub for DoubleDouble<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<const N: usize> Mul for DoubleDouble<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let (hi, error) = self.hi.two_product(rhs.hi);
+        let cross = self.hi.mul_add(rhs.lo, self.lo * rhs.hi);
+        quick_two_sum(hi, error + cross)
+    }
+}
+
+impl<const N: usize> Neg for DoubleDouble<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
@@ -7,6 +7,9 @@
 
 use simd::{LaneCount, Simd, SupportedLaneCount};
 
+mod double_double;
+pub use double_double::DoubleDouble;
+
 #[cfg(feature = "as_crate")]
 mod experimental {
     pub trait Sealed {}
@@ -37,6 +40,18 @@ pub trait Sealed {}
 
     // fma
     fn simd_fma<T>(x: T, y: T, z: T) -> T;
+
+    // fsin
+    fn simd_fsin<T>(x: T) -> T;
+
+    // fcos
+    fn simd_fcos<T>(x: T) -> T;
+
+    // fexp
+    fn simd_fexp<T>(x: T) -> T;
+
+    // flog
+    fn simd_flog<T>(x: T) -> T;
 }
 
 /// This trait provides a possibly-temporary implementation of float functions
@@ -80,6 +95,104 @@ fn sqrt(self) -> Self {
         unsafe { simd_fsqrt(self) }
     }
 
+    /// Produces a vector where every lane has the sine of the value
+    /// in the equivalently-indexed lane in `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use std::simd;
+    /// # #[cfg(feature = "as_crate")] use std_float::StdFloat;
+    /// # #[cfg(not(feature = "as_crate"))] use simd::StdFloat;
+    /// # use simd::f32x4;
+    /// use std::f32::consts::{FRAC_PI_2, FRAC_PI_6, PI};
+    ///
+    /// let x = f32x4::from_array([0.0, FRAC_PI_6, FRAC_PI_2, -PI]);
+    /// for (sin, expected) in x.sin().to_array().iter().zip([0.0, 0.5, 1.0, 0.0]) {
+    ///     assert!((sin - expected).abs() < 1e-6);
+    /// }
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin(self) -> Self {
+        unsafe { simd_fsin(self) }
+    }
+
+    /// Produces a vector where every lane has the cosine of the value
+    /// in the equivalently-indexed lane in `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use std::simd;
+    /// # #[cfg(feature = "as_crate")] use std_float::StdFloat;
+    /// # #[cfg(not(feature = "as_crate"))] use simd::StdFloat;
+    /// # use simd::f64x4;
+    /// use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+    ///
+    /// let x = f64x4::from_array([0.0, FRAC_PI_3, FRAC_PI_2, PI]);
+    /// for (cos, expected) in x.cos().to_array().iter().zip([1.0, 0.5, 0.0, -1.0]) {
+    ///     assert!((cos - expected).abs() < 1e-15);
+    /// }
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cos(self) -> Self {
+        unsafe { simd_fcos(self) }
+    }
+
+    /// Produces a vector where every lane has the exponential (base e) of the value
+    /// in the equivalently-indexed lane in `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use std::simd;
+    /// # #[cfg(feature = "as_crate")] use std_float::StdFloat;
+    /// # #[cfg(not(feature = "as_crate"))] use simd::StdFloat;
+    /// # use simd::f32x4;
+    /// use std::f32::consts::E;
+    ///
+    /// let x = f32x4::from_array([0.0, 1.0, 2f32.ln(), f32::NEG_INFINITY]);
+    /// for (exp, expected) in x.exp().to_array().iter().zip([1.0, E, 2.0, 0.0]) {
+    ///     assert!((exp - expected).abs() < 1e-6);
+    /// }
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp(self) -> Self {
+        unsafe { simd_fexp(self) }
+    }
+
+    /// Produces a vector where every lane has the natural logarithm of the value
+    /// in the equivalently-indexed lane in `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use std::simd;
+    /// # #[cfg(feature = "as_crate")] use std_float::StdFloat;
+    /// # #[cfg(not(feature = "as_crate"))] use simd::StdFloat;
+    /// # use simd::f64x4;
+    /// let x = f64x4::from_array([1.0, std::f64::consts::E, 0.0, -1.0]);
+    /// let ln = x.ln();
+    /// assert_eq!(ln[0], 0.0);
+    /// assert!((ln[1] - 1.0).abs() < 1e-15);
+    /// assert_eq!(ln[2], f64::NEG_INFINITY);
+    ///
+    /// // The logarithm of a negative number is NaN
+    /// assert!(ln[3].is_nan());
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ln(self) -> Self {
+        unsafe { simd_flog(self) }
+    }
+
     /// Returns the smallest integer greater than or equal to each lane.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     #[inline]
@@ -111,6 +224,22 @@ fn trunc(self) -> Self {
     /// Returns the floating point's fractional value, with its integer part removed.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn fract(self) -> Self;
+
+    /// Error-free addition.  Returns `(sum, error)`, where `sum` is `self + other` rounded as
+    /// usual and `error` is the rounding error, such that `sum + error` is exactly `self + other`.
+    ///
+    /// The error is exact as long as the sum doesn't overflow.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn two_sum(self, other: Self) -> (Self, Self);
+
+    /// Error-free multiplication.  Returns `(product, error)`, where `product` is `self * other`
+    /// rounded as usual and `error` is the rounding error, such that `product + error` is exactly
+    /// `self * other`.
+    ///
+    /// The error is computed with [`mul_add`](Self::mul_add), and is exact as long as the
+    /// product neither overflows nor underflows.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn two_product(self, other: Self) -> (Self, Self);
 }
 
 impl<const N: usize> Sealed for Simd<f32, N> where LaneCount<N>: SupportedLaneCount {}
@@ -127,6 +256,20 @@ impl<const N: usize> StdFloat for Simd<f32, N>
     fn fract(self) -> Self {
         self - self.trunc()
     }
+
+    #[inline]
+    fn two_sum(self, other: Self) -> (Self, Self) {
+        let sum = self + other;
+        let other_rounded = sum - self;
+        let self_rounded = sum - other_rounded;
+        (sum, (self - self_rounded) + (other - other_rounded))
+    }
+
+    #[inline]
+    fn two_product(self, other: Self) -> (Self, Self) {
+        let product = self * other;
+        (product, self.mul_add(other, -product))
+    }
 }
 
 impl<const N: usize> StdFloat for Simd<f64, N>
@@ -139,6 +282,20 @@ impl<const N: usize> StdFloat for Simd<f64, N>
     fn fract(self) -> Self {
         self - self.trunc()
     }
+
+    #[inline]
+    fn two_sum(self, other: Self) -> (Self, Self) {
+        let sum = self + other;
+        let other_rounded = sum - self;
+        let self_rounded = sum - other_rounded;
+        (sum, (self - self_rounded) + (other - other_rounded))
+    }
+
+    #[inline]
+    fn two_product(self, other: Self) -> (Self, Self) {
+        let product = self * other;
+        (product, self.mul_add(other, -product))
+    }
 }
 
 #[cfg(test)]
@@ -158,4 +315,98 @@ fn everything_works() {
         let _xsqrt = x.sqrt();
         let _ = x2.abs() * x2;
     }
+
+    /// Returns how many representable values apart `a` and `b` are.
+    fn ulps_f64(a: f64, b: f64) -> u64 {
+        // Order the bits of negative floats below the positive ones
+        let ordered = |x: f64| {
+            let bits = x.to_bits() as i64;
+            if bits < 0 { i64::MIN - bits } else { bits }
+        };
+        ordered(a).abs_diff(ordered(b))
+    }
+
+    fn ulps_f32(a: f32, b: f32) -> u64 {
+        let ordered = |x: f32| {
+            let bits = x.to_bits() as i32;
+            if bits < 0 { i32::MIN - bits } else { bits }
+        };
+        ordered(a).abs_diff(ordered(b)) as u64
+    }
+
+    /// Vector math libraries may be a little less accurate than the scalar functions.
+    const MAX_ULPS: u64 = 2;
+
+    #[test]
+    fn transcendentals() {
+        let x = f64x4::from_array([0.0, 0.5, -1.25, 3.0]);
+        let (sin, cos, exp) = (x.sin(), x.cos(), x.exp());
+        for (lane, x) in x.to_array().into_iter().enumerate() {
+            assert!(ulps_f64(sin[lane], x.sin()) <= MAX_ULPS, "sin({x}) = {}", sin[lane]);
+            assert!(ulps_f64(cos[lane], x.cos()) <= MAX_ULPS, "cos({x}) = {}", cos[lane]);
+            assert!(ulps_f64(exp[lane], x.exp()) <= MAX_ULPS, "exp({x}) = {}", exp[lane]);
+        }
+        let x = f32x4::from_array([0.5, 1.0, 2.0, 1e10]);
+        for (ln, x) in x.ln().to_array().into_iter().zip(x.to_array()) {
+            assert!(ulps_f32(ln, x.ln()) <= MAX_ULPS, "ln({x}) = {ln}");
+        }
+        assert!(f32x4::splat(-1.0).ln().is_nan().all());
+        assert_eq!(f32x4::splat(0.0).ln(), f32x4::splat(f32::NEG_INFINITY));
+        assert_eq!(f32x4::splat(1.0).ln(), f32x4::splat(0.0));
+        assert_eq!(f64x2::splat(0.0).exp(), f64x2::splat(1.0));
+    }
+
+    #[test]
+    fn ulps() {
+        assert_eq!(ulps_f64(1.0, 1.0), 0);
+        assert_eq!(ulps_f64(0.0, -0.0), 0);
+        assert_eq!(ulps_f64(1.0, 1.0 + f64::EPSILON), 1);
+        assert_eq!(ulps_f64(-f64::from_bits(1), f64::from_bits(1)), 2);
+        assert_eq!(ulps_f32(2.0, 2.0 - f32::EPSILON), 1);
+    }
+
+    #[test]
+    fn error_free_transformations() {
+        let tiny = f64x2::splat(2f64.powi(-60));
+        let (sum, error) = f64x2::splat(1.0).two_sum(tiny);
+        assert_eq!(sum, f64x2::splat(1.0));
+        assert_eq!(error, tiny);
+        let (sum, error) = tiny.two_sum(f64x2::splat(-1.0));
+        assert_eq!(sum, f64x2::splat(-1.0));
+        assert_eq!(error, tiny);
+
+        // (1 + 2^-30)^2 = 1 + 2^-29 + 2^-60
+        let x = f64x2::splat(1.0 + 2f64.powi(-30));
+        let (product, error) = x.two_product(x);
+        assert_eq!(product, f64x2::splat(1.0 + 2f64.powi(-29)));
+        assert_eq!(error, tiny);
+    }
+
+    #[test]
+    fn double_double() {
+        // Cancellation that loses the small terms entirely in plain f64
+        let big = f64x4::from_array([1e16, 1e20, -1e30, 2f64.powi(60)]);
+        let small = f64x4::from_array([1.0, 3.0, -0.5, 2f64.powi(-10)]);
+        let sum = DoubleDouble::from(big) + DoubleDouble::from(small) - DoubleDouble::from(big);
+        assert_eq!(sum.to_f64(), small);
+
+        // Compensated dot product
+        let a = [f64x4::splat(1e10), f64x4::splat(1.0 + 2f64.powi(-40))];
+        let b = [f64x4::splat(1e10), f64x4::splat(1.0 + 2f64.powi(-40))];
+        let mut dot = DoubleDouble::from(f64x4::splat(-1e20));
+        for (a, b) in a.iter().zip(&b) {
+            dot = dot + DoubleDouble::from_product(*a, *b);
+        }
+        let expected = 1.0 + 2f64.powi(-39) + 2f64.powi(-80);
+        assert_eq!(dot.hi(), f64x4::splat(1.0 + 2f64.powi(-39)));
+        assert_eq!(dot.lo(), f64x4::splat(2f64.powi(-80)));
+        assert_eq!(dot.to_f64(), f64x4::splat(expected));
+
+        let x = DoubleDouble::from(f64x4::splat(1.0))
+            + DoubleDouble::from(f64x4::splat(2f64.powi(-70)));
+        let square = x * x;
+        assert_eq!(square.hi(), f64x4::splat(1.0));
+        assert_eq!(square.lo(), f64x4::splat(2f64.powi(-69)));
+        assert_eq!((-square).hi(), f64x4::splat(-1.0));
+    }
 }
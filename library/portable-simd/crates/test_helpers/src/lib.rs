@@ -1,4 +1,5 @@
 pub mod array;
+pub mod rng;
 
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
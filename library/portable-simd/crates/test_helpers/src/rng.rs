@@ -0,0 +1,50 @@
+//! Deterministic random values.
+
+/// A xorshift64 generator, for tests that need more random values than proptest generates
+/// comfortably, such as long slices or many vectors.
+///
+/// The sequence is the same on every run, so failures are reproducible.
+#[derive(Clone, Debug)]
+pub struct Xorshift(u64);
+
+impl Xorshift {
+    const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+    /// Creates a generator with the default seed.
+    pub const fn new() -> Self {
+        Self(Self::SEED)
+    }
+
+    /// Creates a generator with the default seed mixed with `seed`, for a different sequence.
+    pub const fn with_seed(seed: u64) -> Self {
+        // A state of zero would only ever produce zero
+        assert!(seed != Self::SEED, "seed must differ from the default seed");
+        Self(Self::SEED ^ seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a float in `0.0..1.0`, with 24 random bits.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1 << 24) as f32
+    }
+}
+
+impl Default for Xorshift {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Xorshift {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        Some(self.next_u64())
+    }
+}
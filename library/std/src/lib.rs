@@ -541,6 +541,8 @@
 #[doc = include_str!("../../portable-simd/crates/core_simd/src/core_simd_docs.md")]
 #[unstable(feature = "portable_simd", issue = "86656")]
 pub mod simd {
+    #[doc(inline)]
+    pub use crate::std_float::DoubleDouble;
     #[doc(inline)]
     pub use crate::std_float::StdFloat;
     #[doc(inline)]
@@ -222,8 +222,6 @@ fn emulate_intrinsic_by_name(
             | "frem_fast"
             => {
                 let [a, b] = check_arg_count(args)?;
-                let a = this.read_immediate(a)?;
-                let b = this.read_immediate(b)?;
                 let op = match intrinsic_name {
                     "fadd_fast" => mir::BinOp::Add,
                     "fsub_fast" => mir::BinOp::Sub,
@@ -242,19 +240,41 @@ fn emulate_intrinsic_by_name(
                         ),
                     })
                 };
-                match (float_finite(&a)?, float_finite(&b)?) {
-                    (false, false) => throw_ub_format!(
-                        "`{intrinsic_name}` intrinsic called with non-finite value as both parameters",
-                    ),
-                    (false, _) => throw_ub_format!(
-                        "`{intrinsic_name}` intrinsic called with non-finite value as first parameter",
-                    ),
-                    (_, false) => throw_ub_format!(
-                        "`{intrinsic_name}` intrinsic called with non-finite value as second parameter",
-                    ),
-                    _ => {}
+                // SIMD vectors of floats are operated on lane by lane.
+                let lanes: Vec<(OpTy<'tcx, Provenance>, OpTy<'tcx, Provenance>, PlaceTy<'tcx, Provenance>)> =
+                    if a.layout.ty.is_simd() {
+                        let (a, len) = this.operand_to_simd(a)?;
+                        let (b, _) = this.operand_to_simd(b)?;
+                        let (dest, _) = this.place_to_simd(dest)?;
+                        let mut lanes = Vec::new();
+                        for i in 0..len {
+                            lanes.push((
+                                this.mplace_index(&a, i)?.into(),
+                                this.mplace_index(&b, i)?.into(),
+                                this.mplace_index(&dest, i)?.into(),
+                            ));
+                        }
+                        lanes
+                    } else {
+                        vec![(a.clone(), b.clone(), dest.clone())]
+                    };
+                for (a, b, dest) in lanes {
+                    let a = this.read_immediate(&a)?;
+                    let b = this.read_immediate(&b)?;
+                    match (float_finite(&a)?, float_finite(&b)?) {
+                        (false, false) => throw_ub_format!(
+                            "`{intrinsic_name}` intrinsic called with non-finite value as both parameters",
+                        ),
+                        (false, _) => throw_ub_format!(
+                            "`{intrinsic_name}` intrinsic called with non-finite value as first parameter",
+                        ),
+                        (_, false) => throw_ub_format!(
+                            "`{intrinsic_name}` intrinsic called with non-finite value as second parameter",
+                        ),
+                        _ => {}
+                    }
+                    this.binop_ignore_overflow(op, &a, &b, &dest)?;
                 }
-                this.binop_ignore_overflow(op, &a, &b, dest)?;
             }
 
             #[rustfmt::skip]
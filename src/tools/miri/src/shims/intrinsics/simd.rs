@@ -360,6 +360,28 @@ enum Op {
                 }
                 this.write_immediate(*res, dest)?;
             }
+            #[rustfmt::skip]
+            | "reduce_add_unordered"
+            | "reduce_mul_unordered" => {
+                use mir::BinOp;
+
+                let [op] = check_arg_count(args)?;
+                let (op, op_len) = this.operand_to_simd(op)?;
+
+                let mir_op = match intrinsic_name {
+                    "reduce_add_unordered" => BinOp::Add,
+                    "reduce_mul_unordered" => BinOp::Mul,
+                    _ => unreachable!(),
+                };
+
+                // Any order is allowed, so initialize with the first lane and proceed in order.
+                let mut res = this.read_immediate(&this.mplace_index(&op, 0)?.into())?;
+                for i in 1..op_len {
+                    let op = this.read_immediate(&this.mplace_index(&op, i)?.into())?;
+                    res = this.binary_op(mir_op, &res, &op)?;
+                }
+                this.write_immediate(*res, dest)?;
+            }
             "select" => {
                 let [mask, yes, no] = check_arg_count(args)?;
                 let (mask, mask_len) = this.operand_to_simd(mask)?;
@@ -41,6 +41,14 @@ fn simd_ops_f32() {
     assert_eq!(b.reduce_max(), 3.0);
     assert_eq!(a.reduce_min(), 10.0);
     assert_eq!(b.reduce_min(), -4.0);
+    assert_eq!(a.reduce_sum_fast(), 40.0);
+    assert_eq!(b.reduce_product_fast(), -24.0);
+    unsafe {
+        assert_eq!(a.add_fast(b), a + b);
+        assert_eq!(a.sub_fast(b), a - b);
+        assert_eq!(a.mul_fast(b), a * b);
+        assert_eq!(b.div_fast(a), b / a);
+    }
 
     assert_eq!(
         f32x2::from_array([0.0, f32::NAN]).simd_max(f32x2::from_array([f32::NAN, 0.0])),
@@ -0,0 +1,53 @@
+// `Mask::select_branchless` and the selects of `simd::ct` are for constant-time code, so check
+// that they never emit a branch or conditional select on AArch64 either. See
+// `x86_64-simd-select-branchless.rs`.
+
+// assembly-output: emit-asm
+// compile-flags: --crate-type=lib -O
+// only-aarch64
+
+#![feature(portable_simd)]
+
+use std::simd::{ct, Mask, Simd, SimdPartialOrd};
+
+// CHECK-LABEL: cond_swap_splat:
+#[no_mangle]
+pub fn cond_swap_splat(secret: bool, a: &mut Simd<u64, 4>, b: &mut Simd<u64, 4>) {
+    // CHECK-NOT: {{^[[:space:]]+(b\.|cbn?z|tbn?z)}}
+    // CHECK-NOT: csel
+    // CHECK: ret
+    ct::cond_swap(Mask::splat(secret), a, b)
+}
+
+// CHECK-LABEL: select_branchless_f64:
+#[no_mangle]
+pub fn select_branchless_f64(mask: Mask<i64, 2>, a: Simd<f64, 2>, b: Simd<f64, 2>) -> Simd<f64, 2> {
+    // CHECK-NOT: {{^[[:space:]]+(b\.|cbn?z|tbn?z)}}
+    // CHECK: ret
+    mask.select_branchless(a, b)
+}
+
+// CHECK-LABEL: select_branchless_lt:
+#[no_mangle]
+pub fn select_branchless_lt(a: Simd<u32, 4>, b: Simd<u32, 4>) -> Simd<u32, 4> {
+    // CHECK-NOT: {{^[[:space:]]+(b\.|cbn?z|tbn?z)}}
+    // CHECK: ret
+    a.simd_lt(b).select_branchless(a, b)
+}
+
+// CHECK-LABEL: select_branchless_splat:
+#[no_mangle]
+pub fn select_branchless_splat(secret: bool, a: Simd<u8, 16>, b: Simd<u8, 16>) -> Simd<u8, 16> {
+    // CHECK-NOT: {{^[[:space:]]+(b\.|cbn?z|tbn?z)}}
+    // CHECK-NOT: csel
+    // CHECK: ret
+    Mask::splat(secret).select_branchless(a, b)
+}
+
+// CHECK-LABEL: select_lt:
+#[no_mangle]
+pub fn select_lt(a: Simd<u32, 4>, b: Simd<u32, 4>) -> Simd<u32, 4> {
+    // CHECK-NOT: {{^[[:space:]]+(b\.|cbn?z|tbn?z)}}
+    // CHECK: ret
+    a.simd_lt(b).select(a, b)
+}
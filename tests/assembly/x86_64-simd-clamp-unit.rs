@@ -0,0 +1,58 @@
+// `clamp_unit` and `saturate` are meant for the epilogues of audio and graphics kernels, so check
+// that they compile to a single maximum and minimum, without comparisons or blends.
+
+// assembly-output: emit-asm
+// compile-flags: --crate-type=lib -O -C llvm-args=-x86-asm-syntax=intel
+// only-x86_64
+
+#![feature(portable_simd)]
+
+use std::simd::{Simd, SimdFloat};
+
+// CHECK-LABEL: clamp_unit_f32:
+#[no_mangle]
+pub fn clamp_unit_f32(x: Simd<f32, 4>) -> Simd<f32, 4> {
+    // CHECK-NOT: cmp
+    // CHECK: maxps
+    // CHECK-NOT: cmp
+    // CHECK: minps
+    // CHECK-NOT: cmp
+    // CHECK: ret
+    x.clamp_unit()
+}
+
+// CHECK-LABEL: saturate_f32:
+#[no_mangle]
+pub fn saturate_f32(x: Simd<f32, 4>) -> Simd<f32, 4> {
+    // CHECK-NOT: cmp
+    // CHECK: maxps
+    // CHECK-NOT: cmp
+    // CHECK: minps
+    // CHECK-NOT: cmp
+    // CHECK: ret
+    x.saturate()
+}
+
+// CHECK-LABEL: saturate_nan_to_zero_f32:
+#[no_mangle]
+pub fn saturate_nan_to_zero_f32(x: Simd<f32, 4>) -> Simd<f32, 4> {
+    // CHECK-NOT: cmp
+    // CHECK: maxps
+    // CHECK-NOT: cmp
+    // CHECK: minps
+    // CHECK-NOT: cmp
+    // CHECK: ret
+    x.saturate_nan_to_zero()
+}
+
+// CHECK-LABEL: saturate_f64:
+#[no_mangle]
+pub fn saturate_f64(x: Simd<f64, 2>) -> Simd<f64, 2> {
+    // CHECK-NOT: cmp
+    // CHECK: maxpd
+    // CHECK-NOT: cmp
+    // CHECK: minpd
+    // CHECK-NOT: cmp
+    // CHECK: ret
+    x.saturate()
+}
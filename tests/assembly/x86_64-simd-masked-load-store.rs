@@ -0,0 +1,67 @@
+// `load_select` and `store_select` should compile to masked loads and stores of the whole
+// vector: `vmaskmov` and `vpmaskmov` with AVX2, which only has them for 32-bit and 64-bit
+// elements, and masked moves of any element size with AVX-512.
+
+// revisions: avx2 avx512
+// assembly-output: emit-asm
+// compile-flags: --crate-type=lib -O -C llvm-args=-x86-asm-syntax=intel
+//[avx2] compile-flags: -C target-feature=+avx2
+//[avx512] compile-flags: -C target-feature=+avx512f,+avx512bw,+avx512vl
+// only-x86_64
+
+#![feature(portable_simd)]
+
+use std::simd::{Mask, Simd};
+
+// CHECK-LABEL: load_or_default_i32x8:
+#[no_mangle]
+pub fn load_or_default_i32x8(slice: &[i32]) -> Simd<i32, 8> {
+    // CHECK-NOT: call
+    // avx2: vpmaskmovd ymm{{[0-9]+}}, ymm{{[0-9]+}}, ymmword ptr [rsi]
+    // avx512: vmovdqu32 ymm{{[0-9]+}} {k{{[0-7]}}} {z}, ymmword ptr [rsi]
+    // CHECK-NOT: call
+    // CHECK: ret
+    Simd::load_or_default(slice)
+}
+
+// avx512-LABEL: load_or_default_u8x64:
+#[no_mangle]
+pub fn load_or_default_u8x64(slice: &[u8]) -> Simd<u8, 64> {
+    // avx512-NOT: call
+    // avx512: vmovdqu8 zmm{{[0-9]+}} {k{{[0-7]}}} {z}, zmmword ptr [rsi]
+    // avx512-NOT: call
+    // avx512: ret
+    Simd::load_or_default(slice)
+}
+
+// CHECK-LABEL: load_select_f32x8:
+#[no_mangle]
+pub fn load_select_f32x8(slice: &[f32], enable: Mask<i32, 8>, or: Simd<f32, 8>) -> Simd<f32, 8> {
+    // CHECK-NOT: call
+    // avx2: vmaskmovps ymm{{[0-9]+}}, ymm{{[0-9]+}}, ymmword ptr [rsi]
+    // avx512: vmovups ymm{{[0-9]+}} {k{{[0-7]}}}, ymmword ptr [rsi]
+    // CHECK-NOT: call
+    // CHECK: ret
+    Simd::load_select(slice, enable, or)
+}
+
+// CHECK-LABEL: store_select_i64x4:
+#[no_mangle]
+pub fn store_select_i64x4(values: Simd<i64, 4>, slice: &mut [i64], enable: Mask<i64, 4>) {
+    // CHECK-NOT: call
+    // avx2: vpmaskmovq ymmword ptr [rsi], ymm{{[0-9]+}}, ymm{{[0-9]+}}
+    // avx512: vmovdqu64 ymmword ptr [rsi] {k{{[0-7]}}}, ymm{{[0-9]+}}
+    // CHECK-NOT: call
+    // CHECK: ret
+    values.store_select(slice, enable)
+}
+
+// avx512-LABEL: store_select_u8x64:
+#[no_mangle]
+pub fn store_select_u8x64(values: Simd<u8, 64>, slice: &mut [u8], enable: Mask<i8, 64>) {
+    // avx512-NOT: call
+    // avx512: vmovdqu8 zmmword ptr [rsi] {k{{[0-7]}}}, zmm{{[0-9]+}}
+    // avx512-NOT: call
+    // avx512: ret
+    values.store_select(slice, enable)
+}
@@ -0,0 +1,54 @@
+// `Mask::select_branchless` and the selects of `simd::ct` are for constant-time code, so check
+// that they never emit a branch, even for masks the optimizer could otherwise branch on.
+// `Mask::select` makes no such promise, but selecting with a lane-wise mask should still be a
+// blend. `aarch64-simd-select-branchless.rs` checks the same on AArch64.
+
+// assembly-output: emit-asm
+// compile-flags: --crate-type=lib -O -C llvm-args=-x86-asm-syntax=intel
+// only-x86_64
+
+#![feature(portable_simd)]
+
+use std::simd::{ct, Mask, Simd, SimdPartialOrd};
+
+// CHECK-LABEL: cond_swap_splat:
+#[no_mangle]
+pub fn cond_swap_splat(secret: bool, a: &mut Simd<u64, 4>, b: &mut Simd<u64, 4>) {
+    // CHECK-NOT: {{^[[:space:]]+j[a-z]+[[:space:]]}}
+    // CHECK-NOT: cmov
+    // CHECK: ret
+    ct::cond_swap(Mask::splat(secret), a, b)
+}
+
+// CHECK-LABEL: select_branchless_f64:
+#[no_mangle]
+pub fn select_branchless_f64(mask: Mask<i64, 4>, a: Simd<f64, 4>, b: Simd<f64, 4>) -> Simd<f64, 4> {
+    // CHECK-NOT: {{^[[:space:]]+j[a-z]+[[:space:]]}}
+    // CHECK: ret
+    mask.select_branchless(a, b)
+}
+
+// CHECK-LABEL: select_branchless_lt:
+#[no_mangle]
+pub fn select_branchless_lt(a: Simd<u32, 4>, b: Simd<u32, 4>) -> Simd<u32, 4> {
+    // CHECK-NOT: {{^[[:space:]]+j[a-z]+[[:space:]]}}
+    // CHECK: ret
+    a.simd_lt(b).select_branchless(a, b)
+}
+
+// CHECK-LABEL: select_branchless_splat:
+#[no_mangle]
+pub fn select_branchless_splat(secret: bool, a: Simd<u8, 16>, b: Simd<u8, 16>) -> Simd<u8, 16> {
+    // CHECK-NOT: {{^[[:space:]]+j[a-z]+[[:space:]]}}
+    // CHECK-NOT: cmov
+    // CHECK: ret
+    Mask::splat(secret).select_branchless(a, b)
+}
+
+// CHECK-LABEL: select_lt:
+#[no_mangle]
+pub fn select_lt(a: Simd<u32, 4>, b: Simd<u32, 4>) -> Simd<u32, 4> {
+    // CHECK-NOT: {{^[[:space:]]+j[a-z]+[[:space:]]}}
+    // CHECK: ret
+    a.simd_lt(b).select(a, b)
+}
@@ -0,0 +1,48 @@
+// `splat` and `Default` should compile to a single broadcast, with no calls, even in unoptimized
+// builds. With optimizations, `Default` should be a zeroing idiom, and `splat_ref` should
+// broadcast straight from memory.
+
+// revisions: opt0 opt1
+// assembly-output: emit-asm
+// compile-flags: --crate-type=lib -C target-feature=+avx2
+// compile-flags: -C llvm-args=-x86-asm-syntax=intel
+//[opt0] compile-flags: -C opt-level=0
+//[opt1] compile-flags: -C opt-level=1
+// only-x86_64
+
+#![feature(portable_simd)]
+
+use std::simd::Simd;
+
+// CHECK-LABEL: default_i32x8:
+#[no_mangle]
+pub fn default_i32x8() -> Simd<i32, 8> {
+    // CHECK-NOT: call
+    // opt0: vpbroadcastd ymm{{[0-9]+}}, dword ptr
+    // opt1: vxorps
+    // CHECK-NOT: call
+    // CHECK: ret
+    Simd::default()
+}
+
+// CHECK-LABEL: splat_f32x8:
+#[no_mangle]
+pub fn splat_f32x8(x: f32) -> Simd<f32, 8> {
+    // CHECK-NOT: call
+    // opt0: vbroadcastss ymm{{[0-9]+}}, dword ptr
+    // opt1: vbroadcastss ymm{{[0-9]+}}, xmm0
+    // CHECK-NOT: call
+    // CHECK: ret
+    Simd::splat(x)
+}
+
+// CHECK-LABEL: splat_ref_f32x8:
+#[no_mangle]
+pub fn splat_ref_f32x8(x: &f32) -> Simd<f32, 8> {
+    // CHECK-NOT: call
+    // opt0: vbroadcastss ymm{{[0-9]+}}, dword ptr
+    // opt1: vbroadcastss ymm{{[0-9]+}}, dword ptr [rsi]
+    // CHECK-NOT: call
+    // CHECK: ret
+    Simd::splat_ref(x)
+}
@@ -0,0 +1,58 @@
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#![feature(repr_simd, core_intrinsics)]
+#![allow(non_camel_case_types)]
+
+use std::intrinsics::{fadd_fast, fdiv_fast, fmul_fast, frem_fast, fsub_fast};
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct f32x4(pub f32, pub f32, pub f32, pub f32);
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct f64x2(pub f64, pub f64);
+
+// CHECK-LABEL: @fadd_fast_32x4
+#[no_mangle]
+pub unsafe fn fadd_fast_32x4(a: f32x4, b: f32x4) -> f32x4 {
+    // CHECK: fadd fast <4 x float>
+    fadd_fast(a, b)
+}
+
+// CHECK-LABEL: @fadd_fast_64x2
+#[no_mangle]
+pub unsafe fn fadd_fast_64x2(a: f64x2, b: f64x2) -> f64x2 {
+    // CHECK: fadd fast <2 x double>
+    fadd_fast(a, b)
+}
+
+// CHECK-LABEL: @fdiv_fast_32x4
+#[no_mangle]
+pub unsafe fn fdiv_fast_32x4(a: f32x4, b: f32x4) -> f32x4 {
+    // CHECK: fdiv fast <4 x float>
+    fdiv_fast(a, b)
+}
+
+// CHECK-LABEL: @fmul_fast_32x4
+#[no_mangle]
+pub unsafe fn fmul_fast_32x4(a: f32x4, b: f32x4) -> f32x4 {
+    // CHECK: fmul fast <4 x float>
+    fmul_fast(a, b)
+}
+
+// CHECK-LABEL: @frem_fast_32x4
+#[no_mangle]
+pub unsafe fn frem_fast_32x4(a: f32x4, b: f32x4) -> f32x4 {
+    // CHECK: frem fast <4 x float>
+    frem_fast(a, b)
+}
+
+// CHECK-LABEL: @fsub_fast_32x4
+#[no_mangle]
+pub unsafe fn fsub_fast_32x4(a: f32x4, b: f32x4) -> f32x4 {
+    // CHECK: fsub fast <4 x float>
+    fsub_fast(a, b)
+}
@@ -0,0 +1,42 @@
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vec2<T>(pub T, pub T);
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vec4<T>(pub T, pub T, pub T, pub T);
+
+extern "platform-intrinsic" {
+    fn simd_masked_load<M, P, T>(mask: M, pointer: P, values: T) -> T;
+}
+
+// CHECK-LABEL: @load_f32x2
+#[no_mangle]
+pub unsafe fn load_f32x2(mask: Vec2<i32>, pointer: *const f32,
+                         values: Vec2<f32>) -> Vec2<f32> {
+    // CHECK: call <2 x float> @llvm.masked.load.v2f32.{{.+}}({{<2 x float>\*|ptr}} {{.*}}, i32 4, <2 x i1> {{.*}}, <2 x float> {{.*}})
+    simd_masked_load(mask, pointer, values)
+}
+
+// CHECK-LABEL: @load_u8x4
+#[no_mangle]
+pub unsafe fn load_u8x4(mask: Vec4<i8>, pointer: *const u8,
+                        values: Vec4<u8>) -> Vec4<u8> {
+    // CHECK: call <4 x i8> @llvm.masked.load.v4i8.{{.+}}({{<4 x i8>\*|ptr}} {{.*}}, i32 1, <4 x i1> {{.*}}, <4 x i8> {{.*}})
+    simd_masked_load(mask, pointer, values)
+}
+
+// CHECK-LABEL: @load_pf32x4
+#[no_mangle]
+pub unsafe fn load_pf32x4(mask: Vec4<i32>, pointer: *const *const f32,
+                          values: Vec4<*const f32>) -> Vec4<*const f32> {
+    // CHECK: call <4 x {{float\*|ptr}}> @llvm.masked.load.{{.+}}({{<4 x float\*>\*|ptr}} {{.*}}, i32 {{.*}}, <4 x i1> {{.*}}, <4 x {{float\*|ptr}}> {{.*}})
+    simd_masked_load(mask, pointer, values)
+}
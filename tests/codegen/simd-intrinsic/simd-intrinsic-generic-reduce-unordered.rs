@@ -0,0 +1,51 @@
+// ignore-tidy-linelength
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+// Unordered float reductions may be reassociated, but don't assume anything else about their
+// lanes, so NaNs, infinities and the signs of zeros are preserved.
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct f32x4(pub f32, pub f32, pub f32, pub f32);
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct f64x2(pub f64, pub f64);
+
+extern "platform-intrinsic" {
+    fn simd_reduce_add_unordered<T, U>(x: T) -> U;
+    fn simd_reduce_mul_unordered<T, U>(x: T) -> U;
+}
+
+// CHECK-LABEL: @reduce_add_32x4
+#[no_mangle]
+pub unsafe fn reduce_add_32x4(a: f32x4) -> f32 {
+    // CHECK: call reassoc float @llvm.vector.reduce.fadd.v4f32(float -0.000000e+00, <4 x float> %{{.+}})
+    simd_reduce_add_unordered(a)
+}
+
+// CHECK-LABEL: @reduce_add_64x2
+#[no_mangle]
+pub unsafe fn reduce_add_64x2(a: f64x2) -> f64 {
+    // CHECK: call reassoc double @llvm.vector.reduce.fadd.v2f64(double -0.000000e+00, <2 x double> %{{.+}})
+    simd_reduce_add_unordered(a)
+}
+
+// CHECK-LABEL: @reduce_mul_32x4
+#[no_mangle]
+pub unsafe fn reduce_mul_32x4(a: f32x4) -> f32 {
+    // CHECK: call reassoc float @llvm.vector.reduce.fmul.v4f32(float 1.000000e+00, <4 x float> %{{.+}})
+    simd_reduce_mul_unordered(a)
+}
+
+// CHECK-LABEL: @reduce_mul_64x2
+#[no_mangle]
+pub unsafe fn reduce_mul_64x2(a: f64x2) -> f64 {
+    // CHECK: call reassoc double @llvm.vector.reduce.fmul.v2f64(double 1.000000e+00, <2 x double> %{{.+}})
+    simd_reduce_mul_unordered(a)
+}
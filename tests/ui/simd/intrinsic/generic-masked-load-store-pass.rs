@@ -0,0 +1,51 @@
+// run-pass
+// ignore-emscripten
+
+// Test that the simd_masked_{load,store} intrinsics only access the enabled lanes.
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct x4<T>(pub T, pub T, pub T, pub T);
+
+extern "platform-intrinsic" {
+    fn simd_masked_load<M, P, T>(mask: M, pointer: P, values: T) -> T;
+    fn simd_masked_store<M, P, T>(mask: M, pointer: P, values: T) -> ();
+}
+
+fn main() {
+    let mut x = [0_u16, 1, 2, 3, 4, 5, 6, 7];
+    let default = x4(9_u16, 9, 9, 9);
+
+    // reading the middle of the array
+    unsafe {
+        let mask = x4(-1_i8, 0, -1, 0);
+        let loaded = simd_masked_load(mask, x.as_ptr().add(2), default);
+        assert_eq!(loaded, x4(2, 9, 4, 9));
+    }
+
+    // reading past the end of the array with those lanes disabled
+    unsafe {
+        let mask = x4(-1_i16, -1, 0, 0);
+        let loaded = simd_masked_load(mask, x.as_ptr().add(6), default);
+        assert_eq!(loaded, x4(6, 7, 9, 9));
+    }
+
+    // writing past the end of the array with those lanes disabled
+    unsafe {
+        let mask = x4(0_i32, -1, 0, 0);
+        simd_masked_store(mask, x.as_mut_ptr().add(6), x4(10_u16, 11, 12, 13));
+        assert_eq!(x, [0, 1, 2, 3, 4, 5, 6, 11]);
+    }
+
+    // writing pointers
+    unsafe {
+        let mut pointers = [core::ptr::null::<u16>(); 4];
+        let p = x.as_ptr();
+        let mask = x4(-1_isize, 0, 0, -1);
+        simd_masked_store(mask, pointers.as_mut_ptr(), x4(p, p, p, p.add(1)));
+        assert_eq!(pointers, [p, core::ptr::null(), core::ptr::null(), p.add(1)]);
+    }
+}
@@ -0,0 +1,44 @@
+// build-fail
+
+// Test that the simd_masked_{load,store} intrinsics produce ok-ish error
+// messages when misused.
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct i32x4(pub i32, pub i32, pub i32, pub i32);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct u32x4(pub u32, pub u32, pub u32, pub u32);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct u8x8(pub u8, pub u8, pub u8, pub u8, pub u8, pub u8, pub u8, pub u8);
+
+extern "platform-intrinsic" {
+    fn simd_masked_load<M, P, T>(mask: M, pointer: P, values: T) -> T;
+    fn simd_masked_store<M, P, T>(mask: M, pointer: P, values: T) -> ();
+}
+
+fn main() {
+    let mask = i32x4(0, 0, 0, 0);
+    let mut x = [0u32; 8];
+
+    unsafe {
+        simd_masked_load(mask, x.as_ptr(), u32x4(0, 0, 0, 0));
+
+        simd_masked_load(mask, x.as_ptr(), u8x8(0, 0, 0, 0, 0, 0, 0, 0));
+        //~^ ERROR expected third argument with length 4
+
+        simd_masked_load(u32x4(0, 0, 0, 0), x.as_ptr(), u32x4(0, 0, 0, 0));
+        //~^ ERROR mask element type is `u32`, expected `i_`
+
+        simd_masked_store(mask, x.as_ptr(), u32x4(0, 0, 0, 0));
+        //~^ ERROR expected element type `*const u32` of second argument `*const u32`
+
+        simd_masked_store(mask, x.as_mut_ptr(), u32x4(0, 0, 0, 0));
+    }
+}